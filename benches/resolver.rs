@@ -0,0 +1,94 @@
+//! Compares resolving and classifying batches one value at a time with doing so through a
+//! [`Resolver`]. Run with `cargo bench --bench resolver`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Days, Utc};
+use reltime::{
+    Time, exact::ExactDate, language::Language, month::Month, offset::Unit, relative::Relative,
+    resolver::Resolver, weekday::Weekday,
+};
+
+const BATCH: usize = 10_000;
+
+/// The mean time per item of running `f` over a batch, after a warm-up run.
+fn per_item(mut f: impl FnMut()) -> Duration {
+    f();
+
+    let start = Instant::now();
+    f();
+    start.elapsed() / BATCH as u32
+}
+
+fn report(name: &str, unbatched: Duration, resolver: Duration) {
+    let speedup = unbatched.as_secs_f64() / resolver.as_secs_f64();
+    println!("{name:<10} {unbatched:>10.2?} {resolver:>10.2?} {speedup:>7.1}x");
+}
+
+fn main() {
+    let relative_to: DateTime<Utc> = "2025-07-29T10:30:05Z".parse().unwrap();
+    let language = Language::default();
+
+    let kinds = [
+        Time::Relative(Relative::today()),
+        Time::Relative(Relative::this_week()),
+        Time::Relative(Relative::next_month()),
+        Time::Relative(Relative::this_quarter()),
+        Time::Relative(Relative::offset(3, Unit::Days)),
+        Time::Relative(Relative::Date(ExactDate::new(None, 12, 24))),
+        Time::Weekday(Weekday::friday()),
+        Time::Month(Month::march()),
+    ];
+    let values: Vec<Time> = kinds.iter().cycle().take(BATCH).cloned().collect();
+    let timestamps: Vec<DateTime<Utc>> = (0..BATCH as u64)
+        .map(|days| relative_to + Days::new(days % 400))
+        .collect();
+
+    let resolver = Resolver::new(relative_to, language);
+
+    println!(
+        "{:<10} {:>10} {:>10} {:>8}",
+        "per item", "unbatched", "resolver", "speedup"
+    );
+
+    report(
+        "resolve",
+        per_item(|| {
+            for time in &values {
+                black_box(time.clone().to_chrono_min(relative_to));
+                black_box(time.clone().to_chrono_max(relative_to));
+            }
+        }),
+        per_item(|| {
+            for time in &values {
+                black_box(resolver.resolve_min(time));
+                black_box(resolver.resolve_max(time));
+            }
+        }),
+    );
+
+    report(
+        "classify",
+        per_item(|| {
+            for date_time in &timestamps {
+                black_box(Time::from_max_chrono(
+                    *date_time,
+                    Some(relative_to),
+                    language,
+                ));
+            }
+        }),
+        per_item(|| {
+            for date_time in &timestamps {
+                black_box(resolver.classify(*date_time));
+            }
+        }),
+    );
+
+    let setup = Instant::now();
+    black_box(Resolver::new(relative_to, language));
+    println!("setup      {:>10.2?}", setup.elapsed());
+}
@@ -0,0 +1,134 @@
+use std::{
+    io::{BufRead, Write},
+    process::ExitCode,
+};
+
+use chrono::{DateTime, Utc};
+use reltime::{Time, language::Language, resolver::Resolver};
+
+use crate::{TimeArgs, chrono_max, chrono_min, error::Error};
+
+/// The bounds printed for each value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bounds {
+    Min,
+    Max,
+    Range,
+}
+
+/// Resolves one JSON value per line from stdin, writing one bound or range per line.
+///
+/// Values are resolved against the same reference time, computing keyword windows once. See
+/// [`resolve_lines`] for how lines are split between jobs and failures reported.
+pub fn run(args: TimeArgs, bounds: Bounds) -> Result<ExitCode, Error> {
+    let relative_to = args.relative_to.unwrap_or(Utc::now());
+    let skip_self = !args.no_skip_self;
+    let resolver = Resolver::new(relative_to, Language::default());
+
+    let bound = |time: &Time, min: bool| -> DateTime<Utc> {
+        match (skip_self, min) {
+            (true, true) => resolver.resolve_min(time),
+            (true, false) => resolver.resolve_max(time),
+            (false, true) => chrono_min(time.clone(), relative_to, skip_self),
+            (false, false) => chrono_max(time.clone(), relative_to, skip_self),
+        }
+    };
+
+    let resolve = |line: &str| -> Result<String, Error> {
+        let time: Time = serde_json::from_str(line)?;
+
+        Ok(match bounds {
+            Bounds::Min => args.output.render(bound(&time, true))?,
+            Bounds::Max => args.output.render(bound(&time, false))?,
+            Bounds::Range => {
+                let range = args
+                    .output
+                    .range_json(bound(&time, true), bound(&time, false))?;
+                serde_json::to_string(&range)?
+            }
+        })
+    };
+
+    resolve_lines(std::io::stdin().lock().lines(), args.jobs, resolve)
+}
+
+/// Resolves every non-empty line, writing the results to stdout in input order.
+///
+/// With a single job lines are written as they are read, otherwise the input is read to the end
+/// and split between that many threads. Lines that fail are written to stderr as structured
+/// errors with their line number and input, e.g. `{"code": "invalid_json", "line": 3, ..}`, and
+/// the exit code is that of the first failure.
+pub fn resolve_lines(
+    lines: impl Iterator<Item = std::io::Result<String>>,
+    jobs: usize,
+    resolve: impl Fn(&str) -> Result<String, Error> + Sync,
+) -> Result<ExitCode, Error> {
+    let mut stdout = std::io::stdout().lock();
+    let mut exit_code = ExitCode::SUCCESS;
+
+    let mut write =
+        |index: usize, line: &str, result: Result<String, Error>| -> Result<(), Error> {
+            match result {
+                Ok(resolved) => writeln!(stdout, "{resolved}")?,
+                Err(error) => {
+                    let mut json = error.to_json();
+                    json["line"] = (index + 1).into();
+                    if json["input"].is_null() {
+                        json["input"] = line.into();
+                    }
+                    eprintln!("{json}");
+
+                    if exit_code == ExitCode::SUCCESS {
+                        exit_code = error.exit_code();
+                    }
+                }
+            }
+
+            Ok(())
+        };
+
+    if jobs <= 1 {
+        for (index, line) in lines.enumerate() {
+            let line = line?;
+
+            if !line.trim().is_empty() {
+                write(index, &line, resolve(&line))?;
+            }
+        }
+
+        return Ok(exit_code);
+    }
+
+    let lines = lines.collect::<Result<Vec<_>, _>>()?;
+    let chunk_size = lines.len().div_ceil(jobs).max(1);
+
+    let chunks: Vec<Vec<(usize, Result<String, Error>)>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = lines
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(chunk, lines)| {
+                let resolve = &resolve;
+
+                scope.spawn(move || {
+                    lines
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, line)| !line.trim().is_empty())
+                        .map(|(index, line)| (chunk * chunk_size + index, resolve(line)))
+                        .collect()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("resolving thread panicked"))
+            .collect()
+    });
+
+    for (index, result) in chunks.into_iter().flatten() {
+        write(index, &lines[index], result)?;
+    }
+
+    Ok(exit_code)
+}
@@ -0,0 +1,142 @@
+use chrono::{DateTime, Datelike, Days, NaiveTime, Utc};
+use clap::Args;
+use reltime::{
+    Time,
+    calendar::month_grid,
+    language::Language,
+    month::Month,
+    options::Options,
+    traits::{FromLanguage, WithLanguage},
+    weekday::{Friday, Monday, Saturday, Sunday, Thursday, Tuesday, Wednesday, Weekday},
+};
+
+use crate::{
+    error::Error,
+    language::languages,
+    nl::{parse_input, parse_relative_to},
+};
+
+/// Arguments for rendering a month calendar
+#[derive(Debug, Clone, Args)]
+pub struct CalArgs {
+    /// Reference timestamp, or an expression resolved to its latest timestamp (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
+    relative_to: Option<DateTime<Utc>>,
+    /// Value whose resolved window is highlighted, e.g. "next week"
+    #[clap(long = "highlight", short = 'H')]
+    highlight: Option<String>,
+    /// Year to show (defaults to the next occurrence of the month)
+    #[clap(long, short)]
+    year: Option<i32>,
+    /// First day of the week, e.g. monday or sunday
+    #[clap(long, short, env = "RELTIME_WEEK_START", default_value = "monday")]
+    week_start: chrono::Weekday,
+    /// Month to show, e.g. "july" or "juli" (defaults to the highlighted or current month)
+    month: Option<String>,
+}
+
+/// Prints a month grid, with days overlapping the highlighted window in brackets.
+///
+/// Headers are in `language` when given, or the language the month is written in.
+pub fn run(args: CalArgs, language: Option<Language>) -> Result<(), Error> {
+    let relative_to = args.relative_to.unwrap_or(Utc::now());
+
+    let window = args
+        .highlight
+        .as_deref()
+        .map(parse_input)
+        .transpose()?
+        .map(|time| {
+            let options = Options::default().with_week_start(args.week_start);
+
+            (
+                time.clone().to_chrono_min_with(relative_to, options),
+                time.to_chrono_max_with(relative_to, options),
+            )
+        });
+
+    let (month, first) = match args.month.as_deref().map(parse_input).transpose()? {
+        Some(Time::Month(month)) => (month, month.to_chrono_min(relative_to, false)),
+        Some(time) => return Err(Error::Unsupported(format!("'{time}' is not a month"))),
+        None => {
+            let first = window.map_or(relative_to, |(min, _)| min);
+            (Month::from_chrono(first, false, Language::default()), first)
+        }
+    };
+
+    let language = match language {
+        Some(language) => language,
+        None => languages(&Time::Month(month))
+            .first()
+            .copied()
+            .unwrap_or_default(),
+    };
+    let month = month.with_language(language);
+    let year = args.year.unwrap_or(first.year());
+
+    let title = format!("{:^28}", format!("{month} {year}"));
+    let headers: String = headers(language, args.week_start)
+        .iter()
+        .map(|header| format!(" {header} "))
+        .collect();
+
+    println!("{}", title.trim_end());
+    println!("{}", headers.trim_end());
+
+    for week in month_grid(month, year, args.week_start) {
+        let line = week
+            .iter()
+            .map(|day| match day {
+                None => "    ".to_string(),
+                Some(day) => {
+                    let start = day.and_time(NaiveTime::MIN).and_utc();
+                    let end = start.checked_add_days(Days::new(1)).unwrap();
+
+                    match window {
+                        Some((min, max)) if overlaps(min, max, start, end) => {
+                            format!("[{:>2}]", day.day())
+                        }
+                        _ => format!(" {:>2} ", day.day()),
+                    }
+                }
+            })
+            .collect::<String>();
+
+        println!("{}", line.trim_end());
+    }
+
+    Ok(())
+}
+
+/// Whether the window overlaps the day, treating an empty window as the instant it marks.
+fn overlaps(
+    min: DateTime<Utc>,
+    max: DateTime<Utc>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> bool {
+    if min == max {
+        start <= min && min < end
+    } else {
+        start < max && min < end
+    }
+}
+
+/// Two-letter weekday headers in the given language, starting on `week_start`.
+fn headers(language: Language, week_start: chrono::Weekday) -> Vec<String> {
+    let mut weekdays = [
+        Weekday::Monday(Monday::from_language(language)),
+        Weekday::Tuesday(Tuesday::from_language(language)),
+        Weekday::Wednesday(Wednesday::from_language(language)),
+        Weekday::Thursday(Thursday::from_language(language)),
+        Weekday::Friday(Friday::from_language(language)),
+        Weekday::Saturday(Saturday::from_language(language)),
+        Weekday::Sunday(Sunday::from_language(language)),
+    ];
+
+    weekdays.rotate_left(week_start.num_days_from_monday() as usize);
+    weekdays
+        .iter()
+        .map(|weekday| weekday.to_string().chars().take(2).collect())
+        .collect()
+}
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use clap::Args;
+use reltime::{Time, language::Language};
+
+use crate::{error::Error, nl::parse_relative_to};
+
+/// Arguments for expressing a timestamp as a value
+#[derive(Debug, Clone, Args)]
+pub struct ClassifyArgs {
+    /// Reference timestamp, or an expression resolved to its latest timestamp (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
+    relative_to: Option<DateTime<Utc>>,
+    /// RFC 3339 timestamp to express, read as the end of a window
+    instant: DateTime<Utc>,
+}
+
+/// Prints the most natural value ending at the instant as JSON, falling back to the timestamp.
+pub fn run(args: ClassifyArgs, language: Option<Language>) -> Result<(), Error> {
+    let relative_to = args.relative_to.unwrap_or(Utc::now());
+    let language = language.unwrap_or_default();
+    let time = Time::from_max_chrono(args.instant, Some(relative_to), language);
+
+    println!("{}", serde_json::to_string_pretty(&time)?);
+
+    Ok(())
+}
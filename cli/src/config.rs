@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use clap::{Arg, Command};
+use serde::Deserialize;
+
+use crate::error::Error;
+
+/// Environment variable overriding the location of the configuration file
+const CONFIG_VAR: &str = "RELTIME_CONFIG";
+
+/// Defaults read from `~/.config/reltime/config.toml`
+///
+/// Every value is the default of the matching command line flag, so flags and their
+/// environment variables take precedence.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Language for help text and values, `en` or `sv`
+    pub language: Option<String>,
+    /// UTC offset timestamps are displayed in, e.g. `+02:00`
+    pub timezone: Option<String>,
+    /// First day of the week in calendars, e.g. `monday` or `sunday`
+    pub week_start: Option<String>,
+    /// Output format, e.g. `rfc3339` or `strftime:%Y-%m-%d`
+    pub output: Option<String>,
+}
+
+impl Config {
+    /// Reads the configuration file, falling back to the defaults when there is none.
+    ///
+    /// The file is looked up at `$RELTIME_CONFIG`, then `$XDG_CONFIG_HOME/reltime/config.toml`,
+    /// then `~/.config/reltime/config.toml`.
+    pub fn load() -> Result<Self, Error> {
+        let Some(path) = path() else {
+            return Ok(Self::default());
+        };
+
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| Error::Config(format!("{}: {}", path.display(), e.message()))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Makes the configured values the defaults of the matching arguments, throughout the
+    /// command and its subcommands.
+    pub fn apply(&self, command: Command) -> Command {
+        command
+            .mut_args(|arg| self.with_default(arg))
+            .mut_subcommands(|subcommand| self.apply(subcommand))
+    }
+
+    fn with_default(&self, arg: Arg) -> Arg {
+        let value = match arg.get_id().as_str() {
+            "language" => &self.language,
+            "timezone" => &self.timezone,
+            "week_start" => &self.week_start,
+            "output" => &self.output,
+            _ => &None,
+        };
+
+        match value {
+            Some(value) => arg.default_value(value.clone()),
+            None => arg,
+        }
+    }
+}
+
+fn path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_VAR) {
+        return Some(path.into());
+    }
+
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config.join("reltime").join("config.toml"))
+}
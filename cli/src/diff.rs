@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use clap::Args;
+use serde_json::json;
+
+use crate::{
+    error::Error,
+    nl::{parse_input, parse_relative_to},
+    output::OutputFormat,
+};
+
+/// Arguments for comparing two values
+#[derive(Debug, Clone, Args)]
+pub struct DiffArgs {
+    /// Reference timestamp, or an expression resolved to its latest timestamp (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
+    relative_to: Option<DateTime<Utc>>,
+    /// Output format: rfc3339, rfc2822, unix, unix-ms, strftime:<fmt> or json
+    #[clap(long, short, env = "RELTIME_OUTPUT", default_value = "json")]
+    output: OutputFormat,
+    /// First value, as natural language or JSON
+    a: String,
+    /// Second value, as natural language or JSON
+    b: String,
+}
+
+/// Prints the differences between the bounds of two values, and whether their windows overlap.
+///
+/// Durations are in seconds and positive when the second value's bound is later. `gap` is the
+/// time from the end of the first window to the start of the second.
+pub fn run(args: DiffArgs) -> Result<(), Error> {
+    let relative_to = args.relative_to.unwrap_or(Utc::now());
+    let a = parse_input(&args.a)?;
+    let b = parse_input(&args.b)?;
+
+    let (a_min, a_max) = (
+        a.clone().to_chrono_min(relative_to),
+        a.to_chrono_max(relative_to),
+    );
+    let (b_min, b_max) = (
+        b.clone().to_chrono_min(relative_to),
+        b.to_chrono_max(relative_to),
+    );
+
+    let start = a_min.max(b_min);
+    let end = a_max.min(b_max);
+    let overlap = start < end || (start == end && (a_min == a_max || b_min == b_max));
+
+    let json = json!({
+        "a": { "min": args.output.to_json(a_min)?, "max": args.output.to_json(a_max)? },
+        "b": { "min": args.output.to_json(b_min)?, "max": args.output.to_json(b_max)? },
+        "min_difference": (b_min - a_min).num_seconds(),
+        "max_difference": (b_max - a_max).num_seconds(),
+        "gap": (b_min - a_max).num_seconds(),
+        "overlap": overlap,
+    });
+
+    println!("{}", serde_json::to_string_pretty(&json)?);
+
+    Ok(())
+}
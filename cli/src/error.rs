@@ -0,0 +1,164 @@
+use std::{fmt::Display, process::ExitCode};
+
+use reltime::parse::{ParseError, suggest};
+use serde_json::{Value as Json, json};
+
+/// Exit code when an expression or value cannot be understood
+const PARSE: u8 = 3;
+/// Exit code when input that should be JSON is not
+const INVALID_JSON: u8 = 4;
+/// Exit code when a value is understood but cannot be used for the request
+const UNSUPPORTED: u8 = 5;
+/// Exit code when reading or writing fails
+const IO: u8 = 6;
+/// Exit code when the configuration file is invalid
+const CONFIG: u8 = 7;
+
+/// Errors surfaced by the CLI
+#[derive(Debug)]
+pub enum Error {
+    Json(serde_json::Error),
+    Io(std::io::Error),
+    Parse(ParseError),
+    InvalidValue(String),
+    NotRecurring(String),
+    Unsupported(String),
+    Config(String),
+}
+
+impl Error {
+    /// Machine-readable identifier of the failure.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::Json(e) if e.is_data() => "invalid_value",
+            Error::Json(_) => "invalid_json",
+            Error::Io(_) => "io",
+            Error::Parse(ParseError::Empty) => "empty_expression",
+            Error::Parse(ParseError::Unrecognised(_)) => "unrecognised_expression",
+            Error::Parse(ParseError::Unsupported(_)) => "unsupported_combination",
+            Error::InvalidValue(_) => "invalid_value",
+            Error::NotRecurring(_) => "not_recurring",
+            Error::Unsupported(_) => "unsupported",
+            Error::Config(_) => "invalid_config",
+        }
+    }
+
+    /// Exit code of the failure class, distinct from clap's 2 for usage errors.
+    pub fn exit_code(&self) -> ExitCode {
+        ExitCode::from(match self {
+            Error::Parse(_) | Error::InvalidValue(_) => PARSE,
+            Error::Json(e) if e.is_data() => PARSE,
+            Error::Json(_) => INVALID_JSON,
+            Error::NotRecurring(_) | Error::Unsupported(_) => UNSUPPORTED,
+            Error::Io(_) => IO,
+            Error::Config(_) => CONFIG,
+        })
+    }
+
+    /// The input responsible for the failure, where known.
+    fn input(&self) -> Option<&str> {
+        match self {
+            Error::Parse(ParseError::Unrecognised(input) | ParseError::Unsupported(input))
+            | Error::InvalidValue(input)
+            | Error::NotRecurring(input) => Some(input),
+            _ => None,
+        }
+    }
+
+    /// Hints for correcting the input.
+    fn suggestions(&self) -> Vec<String> {
+        match self {
+            Error::Parse(ParseError::Unrecognised(input)) | Error::InvalidValue(input) => {
+                suggest(input).iter().map(ToString::to_string).collect()
+            }
+            Error::Parse(ParseError::Empty) => {
+                vec![
+                    "tomorrow".into(),
+                    "next friday".into(),
+                    "25/12 18:00".into(),
+                ]
+            }
+            Error::Parse(ParseError::Unsupported(_)) => vec![
+                "give the time of day on its own, e.g. \"15:00\"".into(),
+                "or with a date, e.g. \"25/12 15:00\"".into(),
+            ],
+            Error::NotRecurring(_) => {
+                vec!["use a weekday, a month, a time of day or a date without a year".into()]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// The error as a JSON object with its code, message, input and suggestions.
+    pub fn to_json(&self) -> Json {
+        json!({
+            "code": self.code(),
+            "message": self.to_string(),
+            "input": self.input(),
+            "suggestions": self.suggestions(),
+        })
+    }
+
+    /// Writes the error to stderr as a JSON object, returning the exit code to end with.
+    pub fn report(&self) -> ExitCode {
+        eprintln!("{}", self.to_json());
+
+        self.exit_code()
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Json(e) => e.fmt(f),
+            Error::Io(e) => e.fmt(f),
+            Error::Parse(e) => e.fmt(f),
+            Error::InvalidValue(value) => write!(f, "'{value}' is not a valid time value"),
+            Error::NotRecurring(value) => write!(f, "'{value}' does not recur"),
+            Error::Unsupported(message) => f.write_str(message),
+            Error::Config(message) => write!(f, "invalid configuration in {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<serde_json::Error> for Error {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Self::Parse(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reltime::Time;
+
+    use super::*;
+
+    fn json_error(json: &str) -> Error {
+        serde_json::from_str::<Time>(json).unwrap_err().into()
+    }
+
+    #[test]
+    fn classifies_json_errors() {
+        let invalid_value = json_error(r#"{"WithYear": [2025, 2, 30]}"#);
+        assert_eq!(invalid_value.code(), "invalid_value");
+        assert_eq!(invalid_value.exit_code(), ExitCode::from(PARSE));
+
+        let invalid_json = json_error("not json");
+        assert_eq!(invalid_json.code(), "invalid_json");
+        assert_eq!(invalid_json.exit_code(), ExitCode::from(INVALID_JSON));
+    }
+}
@@ -0,0 +1,209 @@
+use chrono::{DateTime, Days, NaiveTime, Utc};
+use clap::Args;
+use reltime::{
+    Boundary, Time,
+    exact::{ExactDate, Zone},
+    language::Language,
+    relative::Relative,
+    traits::WithLanguage,
+};
+use serde_json::json;
+
+use crate::{
+    error::Error,
+    language::{languages, localize},
+    nl::{parse_input, parse_relative_to},
+    output::OutputFormat,
+};
+
+/// Arguments for explaining how a value resolves
+#[derive(Debug, Clone, Args)]
+pub struct ExplainArgs {
+    /// Reference timestamp, or an expression resolved to its latest timestamp (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
+    relative_to: Option<DateTime<Utc>>,
+    /// Output format: rfc3339, rfc2822, unix, unix-ms, strftime:<fmt> or json
+    #[clap(long, short, env = "RELTIME_OUTPUT", default_value = "json")]
+    output: OutputFormat,
+    /// Value to explain, as natural language or JSON
+    value: String,
+}
+
+/// Prints the parsed variant, its language, its bounds and how they were resolved.
+///
+/// The value is re-expressed in `language` when given.
+pub fn run(args: ExplainArgs, language: Option<Language>) -> Result<(), Error> {
+    let relative_to = args.relative_to.unwrap_or(Utc::now());
+    let time = localize(parse_input(&args.value)?, language);
+    let (min, max) = (
+        time.clone().to_chrono_min(relative_to),
+        time.clone().to_chrono_max(relative_to),
+    );
+
+    let json = json!({
+        "value": time,
+        "variant": variant(&time),
+        "languages": languages(&time)
+            .iter()
+            .map(Language::to_string)
+            .collect::<Vec<_>>(),
+        "relative_to": args.output.to_json(relative_to)?,
+        "min": args.output.to_json(min)?,
+        "max": args.output.to_json(max)?,
+        "rules": rules(&time, relative_to, min, max),
+    });
+
+    println!("{}", serde_json::to_string_pretty(&json)?);
+
+    Ok(())
+}
+
+/// The path of the variant holding the value, e.g. `Weekday::Monday`.
+pub fn variant(time: &Time) -> String {
+    match time {
+        Time::Relative(relative) => format!(
+            "Relative::{}",
+            match relative {
+                Relative::Time(_) => "Time",
+                Relative::Date(ExactDate::WithYear(..)) => "Date::WithYear",
+                Relative::Date(ExactDate::WithoutYear(..)) => "Date::WithoutYear",
+                Relative::DateTime(_) => "DateTime",
+                Relative::Today(_) => "Today",
+                Relative::Tomorrow(_) => "Tomorrow",
+                Relative::ThisWeek(_) => "ThisWeek",
+                Relative::NextWeek(_) => "NextWeek",
+                Relative::ThisMonth(_) => "ThisMonth",
+                Relative::Yesterday(_) => "Yesterday",
+                Relative::Older(_) => "Older",
+                Relative::EvenWeek(_) => "EvenWeek",
+                Relative::OddWeek(_) => "OddWeek",
+                Relative::NextMonth(_) => "NextMonth",
+                Relative::ThisYear(_) => "ThisYear",
+                Relative::NextYear(_) => "NextYear",
+                Relative::ThisWeekend(_) => "ThisWeekend",
+                Relative::NextWeekend(_) => "NextWeekend",
+                Relative::ThisQuarter(_) => "ThisQuarter",
+                Relative::NextQuarter(_) => "NextQuarter",
+                Relative::NextBusinessDay(_) => "NextBusinessDay",
+                Relative::ThisHour(_) => "ThisHour",
+                Relative::NextHour(_) => "NextHour",
+                Relative::Next15Minutes(_) => "Next15Minutes",
+                Relative::LastWeek(_) => "LastWeek",
+                Relative::LastMonth(_) => "LastMonth",
+                Relative::LastYear(_) => "LastYear",
+                Relative::In(_) => "In",
+                Relative::DayPart(_) => "DayPart",
+                Relative::Week(_) => "Week",
+                Relative::Quarter(_) => "Quarter",
+                Relative::PeriodEdge(_) => "PeriodEdge",
+            }
+        ),
+        Time::Weekday(weekday) => {
+            format!("Weekday::{}", weekday.with_language(Language::default()))
+        }
+        Time::WeekdayTime(weekday_time) => format!(
+            "WeekdayTime::{}",
+            weekday_time.weekday().with_language(Language::default())
+        ),
+        Time::Month(month) => format!("Month::{}", month.with_language(Language::default())),
+        Time::Exact(_) => "Exact".to_string(),
+        Time::DateTime(_) => "DateTime".to_string(),
+        Time::Named(named) => format!("Named::{}", named.date().name()),
+        Time::OrdinalWeekday(ordinal_weekday) => format!(
+            "OrdinalWeekday::{}",
+            ordinal_weekday.weekday().with_language(Language::default())
+        ),
+        Time::LastWeekday(last_weekday) => format!(
+            "LastWeekday::{}",
+            last_weekday.weekday().with_language(Language::default())
+        ),
+        Time::Boundary(Boundary::StartOf(time)) => format!("Boundary::StartOf({})", variant(time)),
+        Time::Boundary(Boundary::EndOf(time)) => format!("Boundary::EndOf({})", variant(time)),
+        Time::At(at) => format!("At({})", variant(at.day())),
+    }
+}
+
+/// Describes how the value resolved, read off its bounds rather than restating the rules, so the
+/// description always matches what `min` and `max` do.
+fn rules(
+    time: &Time,
+    relative_to: DateTime<Utc>,
+    min: DateTime<Utc>,
+    max: DateTime<Utc>,
+) -> Vec<String> {
+    let mut rules = Vec::new();
+
+    match zone(time) {
+        Some(Zone::Local) => rules.push("times of day are read on the wall clock, here UTC".into()),
+        Some(zone) => rules.push(format!("times of day are read at {zone}")),
+        None => {}
+    }
+
+    if min == max {
+        rules.push(format!(
+            "min and max are the same instant, {}",
+            describe(min)
+        ));
+    } else {
+        if min == relative_to {
+            rules.push("min is the reference time, as the window has already started".into());
+        } else {
+            rules.push(format!("min is {}", describe(min)));
+        }
+
+        if max.time() == NaiveTime::MIN {
+            rules.push(format!(
+                "max is the exclusive midnight after {}",
+                (max - Days::new(1)).date_naive()
+            ));
+        } else if max == relative_to {
+            rules.push("max is the reference time, as the window has not ended".into());
+        } else {
+            rules.push(format!("max is {}", describe(max)));
+        }
+    }
+
+    if relative_to < min {
+        rules.push("the window starts after the reference time".into());
+    } else if relative_to < max {
+        rules.push("the window contains the reference time".into());
+    } else if min != max {
+        rules.push("the window ended before the reference time".into());
+    }
+
+    let unit = match time {
+        Time::Weekday(_) => "day",
+        Time::Month(_) => "month",
+        _ => return rules,
+    };
+
+    if crate::chrono_min(time.clone(), relative_to, false) != min {
+        rules.push(format!(
+            "skip_self: the reference {unit} matches but is skipped, so the next occurrence is used"
+        ));
+    }
+
+    rules
+}
+
+/// The timestamp as midnight starting its date, or its time of day on the date.
+fn describe(date_time: DateTime<Utc>) -> String {
+    match date_time.time() {
+        NaiveTime::MIN => format!("midnight starting {}", date_time.date_naive()),
+        time => format!("{time} on {}", date_time.date_naive()),
+    }
+}
+
+/// The zone of the value's time of day, if it has one.
+fn zone(time: &Time) -> Option<Zone> {
+    match time {
+        Time::Relative(Relative::Time(time)) => time.zone(),
+        Time::Relative(Relative::DateTime(date_time)) | Time::Exact(date_time) => {
+            date_time.time().zone()
+        }
+        Time::WeekdayTime(weekday_time) => weekday_time.time().zone(),
+        Time::At(at) => at.time().zone(),
+        Time::Boundary(boundary) => zone(boundary.time()),
+        _ => None,
+    }
+}
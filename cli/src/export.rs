@@ -0,0 +1,173 @@
+use chrono::{DateTime, Datelike, Days, NaiveTime, Timelike, Utc};
+use clap::ValueEnum;
+use reltime::{Time, relative::Relative};
+
+use crate::{
+    error::Error,
+    occurrences::{Frequency, next_occurrence},
+};
+
+/// Calendar formats values can be exported to
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// A crontab schedule firing at the start of each occurrence
+    Cron,
+    /// An iCalendar event, recurring where the value recurs
+    Ics,
+}
+
+/// Words announcing a recurrence, e.g. "every monday 09:00"
+const EVERY: &[&str] = &[
+    "every",
+    "varje",
+    #[cfg(any(feature = "norwegian", feature = "danish"))]
+    "hver",
+    #[cfg(feature = "spanish")]
+    "cada",
+    #[cfg(feature = "french")]
+    "chaque",
+];
+
+/// Strips a leading "every" from an expression to export, since recurring values already repeat.
+pub fn strip_every(text: &str) -> &str {
+    let trimmed = text.trim_start();
+
+    trimmed
+        .split_once(char::is_whitespace)
+        .filter(|(word, _)| EVERY.iter().any(|every| word.eq_ignore_ascii_case(every)))
+        .map_or(text, |(_, rest)| rest.trim_start())
+}
+
+impl ExportFormat {
+    /// Renders the value in this format, relative to the given time.
+    pub fn export(self, time: &Time, relative_to: DateTime<Utc>) -> Result<String, Error> {
+        match self {
+            ExportFormat::Cron => cron(time, relative_to),
+            ExportFormat::Ics => Ok(ics(time, relative_to)),
+        }
+    }
+}
+
+/// Renders a recurring value as the five fields of a crontab schedule.
+fn cron(time: &Time, relative_to: DateTime<Utc>) -> Result<String, Error> {
+    let frequency = Frequency::of(time).ok_or_else(|| Error::NotRecurring(time.to_string()))?;
+    let start = next_occurrence(time, relative_to);
+
+    if start.second() != 0 {
+        return Err(Error::Unsupported(format!(
+            "cron cannot fire at second {} of '{time}'",
+            start.second()
+        )));
+    }
+
+    let (minute, hour) = (start.minute(), start.hour());
+
+    Ok(match (frequency, time) {
+        (Frequency::Daily, _) => format!("{minute} {hour} * * *"),
+        (Frequency::Weekly, _) => format!(
+            "{minute} {hour} * * {}",
+            start.weekday().num_days_from_sunday()
+        ),
+        (Frequency::Yearly, Time::Month(_)) => format!("{minute} {hour} 1 {} *", start.month()),
+        (Frequency::Yearly, _) => {
+            format!("{minute} {hour} {} {} *", start.day(), start.month())
+        }
+    })
+}
+
+/// Renders a value as an iCalendar event covering its next occurrence.
+///
+/// Dates and windows bounded by midnight become all-day events, anything else is timed.
+fn ics(time: &Time, relative_to: DateTime<Utc>) -> String {
+    let start = next_occurrence(time, relative_to);
+    let end = match time {
+        Time::Relative(Relative::Date(_)) => start.checked_add_days(Days::new(1)).unwrap(),
+        _ => time.clone().to_chrono_max(relative_to),
+    };
+
+    let all_day = start.time() == NaiveTime::MIN && end.time() == NaiveTime::MIN && start < end;
+    let stamp = |date_time: DateTime<Utc>| date_time.format("%Y%m%dT%H%M%SZ").to_string();
+    let uid: String = time
+        .to_string()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//reltime//reltime-cli//EN".to_string(),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}-{uid}@reltime", stamp(start)),
+        format!("DTSTAMP:{}", stamp(relative_to)),
+        format!("SUMMARY:{time}"),
+    ];
+
+    if all_day {
+        lines.push(format!("DTSTART;VALUE=DATE:{}", start.format("%Y%m%d")));
+        lines.push(format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+    } else {
+        lines.push(format!("DTSTART:{}", stamp(start)));
+
+        if end > start {
+            lines.push(format!("DTEND:{}", stamp(end)));
+        }
+    }
+
+    match Frequency::of(time) {
+        Some(Frequency::Daily) => lines.push("RRULE:FREQ=DAILY".to_string()),
+        Some(Frequency::Weekly) => lines.push("RRULE:FREQ=WEEKLY".to_string()),
+        Some(Frequency::Yearly) => lines.push("RRULE:FREQ=YEARLY".to_string()),
+        None => {}
+    }
+
+    lines.push("END:VEVENT".to_string());
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.join("\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use reltime::parse::parse;
+
+    use super::*;
+
+    fn relative_to() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-07-29T10:00:00Z")
+            .unwrap()
+            .to_utc()
+    }
+
+    #[test]
+    fn ics_spans_the_value() {
+        let ics = ExportFormat::Ics
+            .export(&parse("tomorrow").unwrap(), relative_to())
+            .unwrap();
+
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250730\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20250731\r\n"));
+
+        let ics = ExportFormat::Ics
+            .export(&parse("next week").unwrap(), relative_to())
+            .unwrap();
+
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250804\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20250811\r\n"));
+    }
+
+    #[test]
+    fn accepts_every() {
+        assert_eq!(strip_every("every monday 09:00"), "monday 09:00");
+        assert_eq!(strip_every("Varje måndag"), "måndag");
+        assert_eq!(strip_every("monday"), "monday");
+        assert_eq!(strip_every("everyday"), "everyday");
+
+        let time = parse(strip_every("every monday 09:00")).unwrap();
+
+        assert_eq!(
+            ExportFormat::Cron.export(&time, relative_to()).unwrap(),
+            "0 9 * * 1"
+        );
+    }
+}
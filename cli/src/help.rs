@@ -0,0 +1,270 @@
+use std::ffi::OsString;
+
+use clap::{Arg, ArgAction, Command, ValueEnum};
+use reltime::{
+    language::Language, month::Month, relative::Relative, traits::WithLanguage, weekday::Weekday,
+};
+
+use crate::language::LanguageArg;
+
+/// Environment variable selecting the language when `--language` is not given
+pub const LANGUAGE_VAR: &str = "RELTIME_LANGUAGE";
+
+/// Picks the language for help text from `--language` on the command line, then the
+/// `RELTIME_LANGUAGE` environment variable, then the configured language.
+///
+/// Help is rendered before arguments are parsed, so the flag is looked up by hand.
+pub fn language(args: &[OsString], configured: Option<&str>) -> Language {
+    let flag = args.windows(2).find_map(|pair| {
+        let (flag, value) = (pair[0].to_str()?, pair[1].to_str()?);
+
+        match flag.strip_prefix("--language=") {
+            Some(value) => Some(value.to_string()),
+            None if flag == "--language" || flag == "-l" => Some(value.to_string()),
+            None => None,
+        }
+    });
+
+    flag.or_else(|| std::env::var(LANGUAGE_VAR).ok())
+        .or_else(|| configured.map(str::to_string))
+        .and_then(|value| LanguageArg::from_str(&value, true).ok())
+        .map(Into::into)
+        .unwrap_or_default()
+}
+
+/// Rewrites the help text of the command and its subcommands in the given language.
+///
+/// Text without a translation is left in English.
+pub fn localize(command: Command, language: Language) -> Command {
+    match language {
+        Language::Swedish(_) => swedish(command, language),
+        _ => command,
+    }
+}
+
+fn swedish(command: Command, language: Language) -> Command {
+    let name = command.get_name().to_string();
+
+    let command = match about(&name, language) {
+        Some(about) => command.about(about),
+        None => command,
+    };
+
+    command
+        .help_template(
+            "{before-help}{about-with-newline}\nAnvändning: {usage}\n\n{all-args}{after-help}",
+        )
+        .subcommand_help_heading("Kommandon")
+        .disable_help_flag(true)
+        .arg(
+            Arg::new("help")
+                .short('h')
+                .long("help")
+                .action(ArgAction::Help)
+                .help("Visa hjälp"),
+        )
+        .mut_args(|arg| {
+            let heading = if arg.is_positional() {
+                "Argument"
+            } else {
+                "Flaggor"
+            };
+
+            match help(&name, arg.get_id().as_str()) {
+                Some(help) => arg.help(help).help_heading(heading),
+                None => arg.help_heading(heading),
+            }
+        })
+        .mut_subcommands(move |subcommand| swedish(subcommand, language))
+}
+
+/// The description of a subcommand, spelling keywords with the crate's own vocabulary.
+fn about(name: &str, language: Language) -> Option<String> {
+    let relative = |relative: Relative| relative.with_language(language).to_string();
+    let weekday = |weekday: Weekday| weekday.with_language(language).to_string();
+    let month = |month: Month| month.with_language(language).to_string();
+
+    Some(match name {
+        "reltime" => "Konvertera relativa tidsuttryck till tidpunkter".to_string(),
+        "min" => "Konvertera till tidigast möjliga tidpunkt".to_string(),
+        "max" => "Konvertera till senast möjliga tidpunkt".to_string(),
+        "range" => "Konvertera till både tidigast och senast möjliga tidpunkt".to_string(),
+        "resolve" => "Beräkna tidigaste och senaste tidpunkt för en mängd JSON-värden".to_string(),
+        "nl" => "Tolka ett uttryck i naturligt språk och visa dess gränser".to_string(),
+        "diff" => "Visa tiden mellan två värdens gränser och om de överlappar".to_string(),
+        "explain" => "Visa hur ett värde tolkas och beräknas".to_string(),
+        "next" => "Visa nästa start eller slut för ett värde, och följ dem valfritt".to_string(),
+        "occurrences" => "Lista nästa förekomster av ett återkommande värde".to_string(),
+        "repl" => "Tolka tidsuttryck interaktivt".to_string(),
+        "cal" => "Visa en månadskalender med ett värdes fönster markerat".to_string(),
+        "table" => "Visa alla representationer av en tidpunkt".to_string(),
+        "classify" => format!(
+            "Uttryck en tidpunkt som det naturligaste värdet som slutar då, t.ex. \"{}\"",
+            relative(Relative::tomorrow())
+        ),
+        "translate" => "Översätt ett värde till ett annat språk".to_string(),
+        "validate" => "Validera tidsvärden strikt och avsluta med felkod vid fel".to_string(),
+        "schema" => "Generera JSON Schema för Time eller någon av dess delar".to_string(),
+        "completions" => "Generera skalkomplettering".to_string(),
+        "now" => "Aktuell tidpunkt".to_string(),
+        "today" => format!("{} (aktuell dag)", relative(Relative::today())),
+        "date" => "Specifikt datum (valfritt utan år för återkommande datum)".to_string(),
+        "time" => "Tid på dygnet".to_string(),
+        "date-time" => "Specifikt datum och tid".to_string(),
+        "tomorrow" => relative(Relative::tomorrow()),
+        "this-week" => format!(
+            "{} (slutar på {})",
+            relative(Relative::this_week()),
+            weekday(Weekday::sunday()).to_lowercase()
+        ),
+        "next-week" => relative(Relative::next_week()),
+        "this-month" => relative(Relative::this_month()),
+        "next-month" => relative(Relative::next_month()),
+        "this-year" => relative(Relative::this_year()),
+        "next-year" => relative(Relative::next_year()),
+        "this-weekend" => format!(
+            "{} (eller den kommande)",
+            relative(Relative::this_weekend())
+        ),
+        "next-weekend" => relative(Relative::next_weekend()),
+        "this-quarter" => relative(Relative::this_quarter()),
+        "next-quarter" => relative(Relative::next_quarter()),
+        "next-business-day" => format!(
+            "{} (hoppar över helger)",
+            relative(Relative::next_business_day())
+        ),
+        "this-hour" => relative(Relative::this_hour()),
+        "next-hour" => relative(Relative::next_hour()),
+        "next-15-minutes" => format!(
+            "{} (från början av aktuell minut)",
+            relative(Relative::next_15_minutes())
+        ),
+        "yesterday" => relative(Relative::yesterday()),
+        "last-week" => relative(Relative::last_week()),
+        "last-month" => relative(Relative::last_month()),
+        "last-year" => relative(Relative::last_year()),
+        "older" => format!("{} (allt före denna månad)", relative(Relative::older())),
+        "even-week" => format!(
+            "{} (nästa vecka med jämnt veckonummer)",
+            relative(Relative::even_week())
+        ),
+        "odd-week" => format!(
+            "{} (nästa vecka med udda veckonummer)",
+            relative(Relative::odd_week())
+        ),
+        "in" => {
+            "En hel dag, vecka, månad eller år ett antal enheter framåt, eller ett antal arbetsdagar"
+                .to_string()
+        }
+        "tonight" => relative(Relative::tonight()),
+        "day-part" => "En del av dygnet, valfritt en viss dag".to_string(),
+        "start-of" => "Början av innevarande dag, vecka, månad, kvartal eller år".to_string(),
+        "end-of" => "Slutet av innevarande dag, vecka, månad, kvartal eller år".to_string(),
+        "week" => "En ISO-vecka, valfritt ett visst år".to_string(),
+        "quarter" => "Ett kvartal, valfritt ett visst år".to_string(),
+        "monday" => weekday(Weekday::monday()),
+        "tuesday" => weekday(Weekday::tuesday()),
+        "wednesday" => weekday(Weekday::wednesday()),
+        "thursday" => weekday(Weekday::thursday()),
+        "friday" => weekday(Weekday::friday()),
+        "saturday" => weekday(Weekday::saturday()),
+        "sunday" => weekday(Weekday::sunday()),
+        "january" => month(Month::january()),
+        "february" => month(Month::february()),
+        "march" => month(Month::march()),
+        "april" => month(Month::april()),
+        "may" => month(Month::may()),
+        "june" => month(Month::june()),
+        "july" => month(Month::july()),
+        "august" => month(Month::august()),
+        "september" => month(Month::september()),
+        "october" => month(Month::october()),
+        "november" => month(Month::november()),
+        "december" => month(Month::december()),
+        "parse" => "Tolka naturligt språk på valfritt språk, eller ett JSON-värde".to_string(),
+        _ => return None,
+    })
+}
+
+/// The help text of an argument, by the name of its command and its id.
+fn help(command: &str, id: &str) -> Option<&'static str> {
+    Some(match (command, id) {
+        ("occurrences", "relative_to") => {
+            "Referenstidpunkt eller uttryck att lista förekomster efter (standard är aktuell tid)"
+        }
+        (_, "relative_to") => {
+            "Referenstidpunkt, eller ett uttryck beräknat till sin senaste tidpunkt (standard är aktuell tid)"
+        }
+        ("reltime", "language") => {
+            "Språk för hjälptext och värden: en eller sv (standard är engelska)"
+        }
+        ("reltime", "timezone") => {
+            "UTC-förskjutning att visa tidpunkter i, t.ex. +02:00 (standard är UTC)"
+        }
+        (_, "output") => {
+            "Utdataformat: rfc3339, rfc2822, unix, unix-ms, strftime:<format> eller json"
+        }
+        (_, "skip_self") => {
+            "Hoppa över aktuell dag eller månad för veckodagar och månader (standard)"
+        }
+        (_, "no_skip_self") => "Låt veckodagar och månader matcha aktuell dag eller månad",
+        ("min" | "max" | "range", "stdin") => {
+            "Läs ett JSON-värde per rad från stdin istället för att ta ett värde"
+        }
+        (_, "jobs") => "Antal trådar som beräknar rader från stdin parallellt",
+        ("repl", "language") => "Språk att visa värden på (standard är språket de skrevs på)",
+        ("cal", "language") => "Språk för rubrikerna (standard är språket månaden skrevs på)",
+        ("schema", "language") => "Ta bara med detta språks ordförråd",
+        ("schema", "schema_type") => "Typ att generera schemat för",
+        ("nl", "text") => "Uttryck att tolka, t.ex. \"nästa fredag\" eller \"25/12 18:00\"",
+        ("nl", "export") => "Exportera till ett kalenderformat istället för att skriva ut JSON",
+        ("diff", "a") => "Första värdet, som naturligt språk eller JSON",
+        ("diff", "b") => "Andra värdet, som naturligt språk eller JSON",
+        ("explain", "value") => "Värde att förklara, som naturligt språk eller JSON",
+        ("next", "value") => "Värde att bevaka, t.ex. \"fredag\", \"15:00\" eller \"denna vecka\"",
+        ("next", "follow") => "Fortsätt köra och skriv ut varje gräns när den passerar",
+        ("occurrences", "count") => "Antal förekomster att lista",
+        ("occurrences", "value") => {
+            "Återkommande värde, som en veckodag, en månad, en tid på dygnet eller ett datum utan år"
+        }
+        ("cal", "highlight") => "Värde vars beräknade fönster markeras, t.ex. \"nästa vecka\"",
+        ("cal", "week_start") => "Veckans första dag, t.ex. monday eller sunday",
+        ("cal", "year") => "År att visa (standard är månadens nästa förekomst)",
+        ("cal", "month") => {
+            "Månad att visa, t.ex. \"juli\" (standard är den markerade eller aktuella månaden)"
+        }
+        ("table", "instant") => "RFC 3339-tidpunkt att beskriva",
+        ("classify", "instant") => "RFC 3339-tidpunkt att uttrycka, läst som slutet av ett fönster",
+        ("translate", "to") => "Språk att översätta till",
+        ("translate", "value") => "Värde på valfritt språk, som naturligt språk eller JSON",
+        ("resolve", "field") => "Postfält som håller tidsvärdet när en rad är ett JSON-objekt",
+        ("resolve", "stdin") => "Läs ett JSON-värde eller en post per rad från stdin",
+        ("resolve", "values") => "JSON-värden att beräkna",
+        ("validate", "field") => {
+            "Fältnamn som håller tidsvärden, sökta på alla djup (standard är hela dokumentet)"
+        }
+        ("validate", "stdin") => "Läs JSON-dokumentet från stdin",
+        ("validate", "json") => "JSON-dokument att validera",
+        ("completions", "shell") => "Skal att generera komplettering för",
+        ("parse", "value") => {
+            "Uttryck eller JSON-värde att tolka, t.ex. \"nästa fredag\", \"om 3 dagar\" eller \"Imorgon\""
+        }
+        ("day-part", "part") => "Del av dygnet",
+        ("day-part", "day") => "Dag som delen infaller på (standard är aktuell eller nästa)",
+        ("start-of", "period") => "Period att börja",
+        ("end-of", "period") => "Period att avsluta",
+        ("week", "week") => "Veckonummer (1-53)",
+        ("week", "year") => "Veckoår (standard är aktuell eller nästa vecka med numret)",
+        ("quarter", "quarter") => "Kvartal (1-4)",
+        ("quarter", "year") => "År (standard är aktuellt eller nästa kvartal med numret)",
+        (_, "year") => "År (valfritt för återkommande datum)",
+        (_, "month") => "Månad (1-12)",
+        (_, "day") => "Dag (1-31)",
+        (_, "hour") => "Timme (0-23)",
+        (_, "minute") => "Minut (0-59)",
+        (_, "second") => "Sekund (0-59, valfri)",
+        ("in", "amount") => "Antal enheter framåt",
+        ("in", "unit") => "Enhet att räkna i",
+        _ => return None,
+    })
+}
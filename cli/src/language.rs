@@ -0,0 +1,82 @@
+use clap::ValueEnum;
+use reltime::{
+    Time,
+    language::{English, Language, Swedish},
+    relative::Relative,
+    traits::WithLanguage,
+};
+
+/// Language selection on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LanguageArg {
+    /// English
+    #[value(name = "en", alias = "english")]
+    English,
+    /// Swedish
+    #[value(name = "sv", alias = "swedish", alias = "svenska")]
+    Swedish,
+    /// Norwegian (Bokmål)
+    #[cfg(feature = "norwegian")]
+    #[value(name = "nb", alias = "no", alias = "norwegian", alias = "norsk")]
+    Norwegian,
+    /// Danish
+    #[cfg(feature = "danish")]
+    #[value(name = "da", alias = "danish", alias = "dansk")]
+    Danish,
+    /// Spanish
+    #[cfg(feature = "spanish")]
+    #[value(name = "es", alias = "spanish", alias = "español")]
+    Spanish,
+    /// French
+    #[cfg(feature = "french")]
+    #[value(name = "fr", alias = "french", alias = "français")]
+    French,
+}
+
+impl From<LanguageArg> for Language {
+    fn from(value: LanguageArg) -> Self {
+        match value {
+            LanguageArg::English => Language::English(English::default()),
+            LanguageArg::Swedish => Language::Swedish(Swedish::default()),
+            #[cfg(feature = "norwegian")]
+            LanguageArg::Norwegian => Language::Norwegian(Default::default()),
+            #[cfg(feature = "danish")]
+            LanguageArg::Danish => Language::Danish(Default::default()),
+            #[cfg(feature = "spanish")]
+            LanguageArg::Spanish => Language::Spanish(Default::default()),
+            #[cfg(feature = "french")]
+            LanguageArg::French => Language::French(Default::default()),
+        }
+    }
+}
+
+/// Every enabled language the value is spelled in, empty for language-neutral values.
+pub fn languages(time: &Time) -> Vec<Language> {
+    let in_language = |language: Language| match time {
+        Time::Relative(
+            Relative::Time(_)
+            | Relative::Date(_)
+            | Relative::DateTime(_)
+            | Relative::Week(_)
+            | Relative::Quarter(_),
+        )
+        | Time::Exact(_)
+        | Time::DateTime(_) => false,
+        Time::Boundary(boundary) => languages(boundary.time()).contains(&language),
+        Time::At(at) => languages(at.day()).contains(&language),
+        time => time.with_language(language) == *time,
+    };
+
+    Language::all()
+        .into_iter()
+        .filter(|language| in_language(*language))
+        .collect()
+}
+
+/// Re-expresses the value in the language given with `--language`, if any.
+pub fn localize(time: Time, language: Option<Language>) -> Time {
+    match language {
+        Some(language) => time.with_language(language),
+        None => time,
+    }
+}
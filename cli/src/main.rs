@@ -1,13 +1,61 @@
-use chrono::{DateTime, Utc};
-use clap::{Args, Parser};
+use std::{ffi::OsString, process::ExitCode};
+
+use chrono::{DateTime, FixedOffset, Utc};
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
 use reltime::{
     Time,
+    day_part::{Day, Part},
     exact::{ExactDate, ExactDateTime, ExactTime},
+    language::Language,
     month::Month,
+    offset::Unit,
+    period::Period,
     relative::Relative,
     weekday::Weekday,
 };
-use schemars::schema_for;
+
+use crate::{
+    batch::Bounds,
+    cal::CalArgs,
+    classify::ClassifyArgs,
+    config::Config,
+    diff::DiffArgs,
+    error::Error,
+    explain::ExplainArgs,
+    language::LanguageArg,
+    next::NextArgs,
+    nl::{NlArgs, parse_input, parse_relative_to},
+    occurrences::OccurrencesArgs,
+    output::OutputFormat,
+    repl::ReplArgs,
+    resolve::ResolveArgs,
+    schema::SchemaArgs,
+    table::TableArgs,
+    translate::TranslateArgs,
+    validate::ValidateArgs,
+};
+
+mod batch;
+mod cal;
+mod classify;
+mod config;
+mod diff;
+mod error;
+mod explain;
+mod export;
+mod help;
+mod language;
+mod next;
+mod nl;
+mod occurrences;
+mod output;
+mod repl;
+mod resolve;
+mod schema;
+mod table;
+mod translate;
+mod validate;
 
 /// Time value to convert
 #[derive(Debug, Clone, Parser)]
@@ -59,6 +107,90 @@ pub enum Value {
     NextWeek,
     /// This month
     ThisMonth,
+    /// Next month
+    NextMonth,
+    /// This year
+    ThisYear,
+    /// Next year
+    NextYear,
+    /// This weekend (or the upcoming one)
+    ThisWeekend,
+    /// Next weekend
+    NextWeekend,
+    /// This quarter
+    ThisQuarter,
+    /// Next quarter
+    NextQuarter,
+    /// The next business day (skipping weekends)
+    NextBusinessDay,
+    /// This clock hour
+    ThisHour,
+    /// Next clock hour
+    NextHour,
+    /// The 15 minutes from the start of the current minute
+    #[command(name = "next-15-minutes")]
+    Next15Minutes,
+    /// Yesterday
+    Yesterday,
+    /// Last week
+    LastWeek,
+    /// Last month
+    LastMonth,
+    /// Last year
+    LastYear,
+    /// Anything before this month
+    Older,
+    /// The next week with an even week number
+    EvenWeek,
+    /// The next week with an odd week number
+    OddWeek,
+    /// A whole day, week, month or year a number of units ahead, or a number of business days
+    In {
+        /// Number of units ahead
+        amount: u32,
+        /// Unit to count in
+        #[clap(value_enum)]
+        unit: OffsetUnit,
+    },
+    /// Tonight
+    Tonight,
+    /// A part of a day, optionally on a given day
+    DayPart {
+        /// Part of the day
+        #[clap(value_enum)]
+        part: DayPartArg,
+        /// Day the part falls on (defaults to the current or next one)
+        #[clap(long, value_enum)]
+        day: Option<DayArg>,
+    },
+    /// The start of the current day, week, month, quarter or year
+    StartOf {
+        /// Period to start
+        #[clap(value_enum)]
+        period: PeriodArg,
+    },
+    /// The end of the current day, week, month, quarter or year
+    EndOf {
+        /// Period to end
+        #[clap(value_enum)]
+        period: PeriodArg,
+    },
+    /// An ISO week, optionally in a given year
+    Week {
+        /// Week number (1-53)
+        week: u8,
+        /// Week-numbering year (defaults to the current or next week with the number)
+        #[clap(long)]
+        year: Option<i32>,
+    },
+    /// A quarter of the year, optionally in a given year
+    Quarter {
+        /// Quarter number (1-4)
+        quarter: u8,
+        /// Year (defaults to the current or next quarter with the number)
+        #[clap(long)]
+        year: Option<i32>,
+    },
     /// Monday
     Monday,
     /// Tuesday
@@ -97,15 +229,98 @@ pub enum Value {
     November,
     /// December
     December,
-    /// Parse a JSON value directly
+    /// Parse natural language in any language, or a JSON value
     Parse {
-        /// JSON string value to parse
-        value: String,
+        /// Expression or JSON value to parse, e.g. "next friday", "om 3 dagar" or "Tomorrow"
+        #[clap(required = true)]
+        value: Vec<String>,
     },
 }
 
+/// Units a relative offset can count in
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OffsetUnit {
+    Days,
+    BusinessDays,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl From<OffsetUnit> for Unit {
+    fn from(value: OffsetUnit) -> Self {
+        match value {
+            OffsetUnit::Days => Unit::Days,
+            OffsetUnit::BusinessDays => Unit::BusinessDays,
+            OffsetUnit::Weeks => Unit::Weeks,
+            OffsetUnit::Months => Unit::Months,
+            OffsetUnit::Years => Unit::Years,
+        }
+    }
+}
+
+/// Periods a value can start or end
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PeriodArg {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl From<PeriodArg> for Period {
+    fn from(value: PeriodArg) -> Self {
+        match value {
+            PeriodArg::Day => Period::Day,
+            PeriodArg::Week => Period::Week,
+            PeriodArg::Month => Period::Month,
+            PeriodArg::Quarter => Period::Quarter,
+            PeriodArg::Year => Period::Year,
+        }
+    }
+}
+
+/// Parts of a day
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DayPartArg {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+}
+
+impl From<DayPartArg> for Part {
+    fn from(value: DayPartArg) -> Self {
+        match value {
+            DayPartArg::Morning => Part::Morning,
+            DayPartArg::Afternoon => Part::Afternoon,
+            DayPartArg::Evening => Part::Evening,
+            DayPartArg::Night => Part::Night,
+        }
+    }
+}
+
+/// Days a part of a day can fall on
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DayArg {
+    Today,
+    Tomorrow,
+    Yesterday,
+}
+
+impl From<DayArg> for Day {
+    fn from(value: DayArg) -> Self {
+        match value {
+            DayArg::Today => Day::Today,
+            DayArg::Tomorrow => Day::Tomorrow,
+            DayArg::Yesterday => Day::Yesterday,
+        }
+    }
+}
+
 impl TryFrom<Value> for Time {
-    type Error = serde_json::Error;
+    type Error = Error;
 
     fn try_from(value: Value) -> Result<Self, Self::Error> {
         Ok(match value {
@@ -134,6 +349,33 @@ impl TryFrom<Value> for Time {
             Value::ThisWeek => Self::Relative(Relative::this_week()),
             Value::NextWeek => Self::Relative(Relative::next_week()),
             Value::ThisMonth => Self::Relative(Relative::this_month()),
+            Value::NextMonth => Self::Relative(Relative::next_month()),
+            Value::ThisYear => Self::Relative(Relative::this_year()),
+            Value::NextYear => Self::Relative(Relative::next_year()),
+            Value::ThisWeekend => Self::Relative(Relative::this_weekend()),
+            Value::NextWeekend => Self::Relative(Relative::next_weekend()),
+            Value::ThisQuarter => Self::Relative(Relative::this_quarter()),
+            Value::NextQuarter => Self::Relative(Relative::next_quarter()),
+            Value::NextBusinessDay => Self::Relative(Relative::next_business_day()),
+            Value::ThisHour => Self::Relative(Relative::this_hour()),
+            Value::NextHour => Self::Relative(Relative::next_hour()),
+            Value::Next15Minutes => Self::Relative(Relative::next_15_minutes()),
+            Value::Yesterday => Self::Relative(Relative::yesterday()),
+            Value::LastWeek => Self::Relative(Relative::last_week()),
+            Value::LastMonth => Self::Relative(Relative::last_month()),
+            Value::LastYear => Self::Relative(Relative::last_year()),
+            Value::Older => Self::Relative(Relative::older()),
+            Value::EvenWeek => Self::Relative(Relative::even_week()),
+            Value::OddWeek => Self::Relative(Relative::odd_week()),
+            Value::In { amount, unit } => Self::Relative(Relative::offset(amount, unit.into())),
+            Value::Tonight => Self::Relative(Relative::tonight()),
+            Value::DayPart { part, day } => {
+                Self::Relative(Relative::day_part(part.into(), day.map(Into::into)))
+            }
+            Value::StartOf { period } => Self::Relative(Relative::start_of(period.into())),
+            Value::EndOf { period } => Self::Relative(Relative::end_of(period.into())),
+            Value::Week { week, year } => Self::Relative(Relative::week(week, year)),
+            Value::Quarter { quarter, year } => Self::Relative(Relative::quarter(quarter, year)),
             Value::Monday => Self::Weekday(Weekday::monday()),
             Value::Tuesday => Self::Weekday(Weekday::tuesday()),
             Value::Wednesday => Self::Weekday(Weekday::wednesday()),
@@ -153,7 +395,13 @@ impl TryFrom<Value> for Time {
             Value::October => Self::Month(Month::october()),
             Value::November => Self::Month(Month::november()),
             Value::December => Self::Month(Month::december()),
-            Value::Parse { value } => serde_json::from_str(&format!("\"{}\"", value))?,
+            Value::Parse { value } => {
+                let value = value.join(" ");
+
+                parse_input(&value)
+                    .or_else(|_| serde_json::from_value(serde_json::Value::String(value.clone())))
+                    .map_err(|_| Error::InvalidValue(value))?
+            }
         })
     }
 }
@@ -161,41 +409,222 @@ impl TryFrom<Value> for Time {
 /// Arguments for time conversion commands
 #[derive(Debug, Clone, Args)]
 pub struct TimeArgs {
-    /// Reference timestamp for relative calculations (defaults to current time)
-    #[clap(long, short)]
+    /// Reference timestamp, or an expression resolved to its latest timestamp (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
     relative_to: Option<DateTime<Utc>>,
+    /// Output format: rfc3339, rfc2822, unix, unix-ms, strftime:<fmt> or json
+    #[clap(
+        long,
+        short,
+        alias = "format",
+        env = "RELTIME_OUTPUT",
+        default_value = "json"
+    )]
+    output: OutputFormat,
+    /// Skip the current day or month when resolving weekdays and months (the default)
+    #[clap(long, overrides_with = "no_skip_self")]
+    skip_self: bool,
+    /// Let weekdays and months match the current day or month
+    #[clap(long, overrides_with = "skip_self")]
+    no_skip_self: bool,
+    /// Read one JSON value per line from stdin instead of taking a value
+    #[clap(long)]
+    stdin: bool,
+    /// Number of threads resolving stdin lines in parallel
+    #[clap(long, default_value_t = 1, requires = "stdin")]
+    jobs: usize,
     #[command(subcommand)]
-    value: Value,
+    value: Option<Value>,
+}
+
+impl TimeArgs {
+    /// The value to convert, required unless reading from stdin.
+    fn time(value: Option<Value>) -> Result<Time, Error> {
+        let value = value.ok_or_else(|| {
+            Error::Unsupported("expected a value, or --stdin to read values from stdin".into())
+        })?;
+
+        Time::try_from(value)
+    }
+
+    /// Converts to the earliest possible timestamp, honouring the skip-self flags.
+    fn into_chrono_min(self) -> Result<DateTime<Utc>, Error> {
+        let relative_to = self.relative_to.unwrap_or(Utc::now());
+        let skip_self = !self.no_skip_self;
+
+        Ok(chrono_min(Self::time(self.value)?, relative_to, skip_self))
+    }
+
+    /// Converts to the latest possible timestamp, honouring the skip-self flags.
+    fn into_chrono_max(self) -> Result<DateTime<Utc>, Error> {
+        let relative_to = self.relative_to.unwrap_or(Utc::now());
+        let skip_self = !self.no_skip_self;
+
+        Ok(chrono_max(Self::time(self.value)?, relative_to, skip_self))
+    }
+
+    /// Converts to both the earliest and latest possible timestamps of the same value,
+    /// honouring the skip-self flags.
+    fn into_chrono_range(self) -> Result<(DateTime<Utc>, DateTime<Utc>), Error> {
+        let relative_to = self.relative_to.unwrap_or(Utc::now());
+        let skip_self = !self.no_skip_self;
+        let time = Self::time(self.value)?;
+
+        Ok((
+            chrono_min(time.clone(), relative_to, skip_self),
+            chrono_max(time, relative_to, skip_self),
+        ))
+    }
 }
 
+fn chrono_min(time: Time, relative_to: DateTime<Utc>, skip_self: bool) -> DateTime<Utc> {
+    match time {
+        Time::Weekday(weekday) => weekday.to_chrono_min(relative_to, skip_self),
+        Time::Month(month) => month.to_chrono_min(relative_to, skip_self),
+        time => time.to_chrono_min(relative_to),
+    }
+}
+
+fn chrono_max(time: Time, relative_to: DateTime<Utc>, skip_self: bool) -> DateTime<Utc> {
+    match time {
+        Time::Weekday(weekday) => weekday.to_chrono_max(relative_to, skip_self),
+        Time::Month(month) => month.to_chrono_max(relative_to, skip_self),
+        time => time.to_chrono_max(relative_to),
+    }
+}
+
+/// Convert relative time expressions to timestamps
 #[derive(Debug, Clone, Parser)]
-pub enum Cli {
+#[command(name = "reltime")]
+pub struct Cli {
+    /// Language for help text and values, e.g. en or sv (defaults to English)
+    #[clap(long, short, global = true, env = help::LANGUAGE_VAR)]
+    language: Option<LanguageArg>,
+    /// UTC offset to display timestamps in, e.g. +02:00 (defaults to UTC)
+    #[clap(long, global = true, env = "RELTIME_TIMEZONE", value_parser = output::parse_offset)]
+    timezone: Option<FixedOffset>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
     /// Convert to earliest possible timestamp
     Min(TimeArgs),
     /// Convert to latest possible timestamp
     Max(TimeArgs),
-    /// Generate JSON Schema for Time type
-    Schema,
+    /// Convert to both earliest and latest possible timestamps
+    Range(TimeArgs),
+    /// Resolve a batch of JSON values to their earliest and latest timestamps
+    Resolve(ResolveArgs),
+    /// Parse a natural language expression and show its resolved bounds
+    Nl(NlArgs),
+    /// Show the durations between two values' bounds and whether they overlap
+    Diff(DiffArgs),
+    /// Show how a value is interpreted and resolved
+    Explain(ExplainArgs),
+    /// Show the next start or end of a value, optionally following each as it passes
+    Next(NextArgs),
+    /// List the next occurrences of a recurring value
+    Occurrences(OccurrencesArgs),
+    /// Interactively interpret time expressions
+    Repl(ReplArgs),
+    /// Show a month calendar, highlighting the window of a value
+    Cal(CalArgs),
+    /// Show every representation of a timestamp
+    Table(TableArgs),
+    /// Express a timestamp as the most natural value ending at it, e.g. "Tomorrow"
+    Classify(ClassifyArgs),
+    /// Translate a value to another language
+    Translate(TranslateArgs),
+    /// Validate time values strictly, exiting with a non-zero code on failure
+    Validate(ValidateArgs),
+    /// Generate JSON Schema for Time or one of its component types
+    Schema(SchemaArgs),
+    /// Generate shell completions
+    Completions {
+        /// Shell to generate completions for
+        shell: Shell,
+    },
 }
 
-fn main() -> Result<(), serde_json::Error> {
-    match Cli::parse() {
-        Cli::Min(TimeArgs { relative_to, value }) => {
-            let time = Time::try_from(value)?.to_chrono_min(relative_to.unwrap_or(Utc::now()));
-            let json = serde_json::to_string_pretty(&time)?;
-            println!("{json}");
+fn main() -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => return e.report(),
+    };
+
+    let args: Vec<OsString> = std::env::args_os().collect();
+    let language = help::language(&args, config.language.as_deref());
+    let command = help::localize(config.apply(Cli::command()), language);
+
+    let cli = match command
+        .try_get_matches_from(args)
+        .and_then(|matches| Cli::from_arg_matches(&matches))
+    {
+        Ok(cli) => cli,
+        Err(e) => match std::error::Error::source(&e).and_then(|e| e.downcast_ref::<Error>()) {
+            Some(error) => return error.report(),
+            None => e.exit(),
+        },
+    };
+
+    run(cli).unwrap_or_else(|e| e.report())
+}
+
+/// Runs the command, leaving failures for `main` to report.
+fn run(cli: Cli) -> Result<ExitCode, Error> {
+    let language = cli.language.map(Language::from);
+
+    if let Some(offset) = cli.timezone {
+        output::set_offset(offset);
+    }
+
+    if let Some(language) = language {
+        output::set_language(language);
+    }
+
+    match cli.command {
+        Command::Min(args) if args.stdin => return batch::run(args, Bounds::Min),
+        Command::Max(args) if args.stdin => return batch::run(args, Bounds::Max),
+        Command::Range(args) if args.stdin => return batch::run(args, Bounds::Range),
+        Command::Min(args) => {
+            let output = args.output.clone();
+            println!("{}", output.render(args.into_chrono_min()?)?);
         }
-        Cli::Max(TimeArgs { value, relative_to }) => {
-            let time = Time::try_from(value)?.to_chrono_max(relative_to.unwrap_or(Utc::now()));
-            let json = serde_json::to_string_pretty(&time)?;
-            println!("{json}");
+        Command::Max(args) => {
+            let output = args.output.clone();
+            println!("{}", output.render(args.into_chrono_max()?)?);
+        }
+        Command::Range(args) => {
+            let output = args.output.clone();
+            let (min, max) = args.into_chrono_range()?;
+            let range = output.range_json(min, max)?;
+
+            println!("{}", serde_json::to_string_pretty(&range)?);
         }
-        Cli::Schema => {
-            let schema = schema_for!(Time);
-            let json = serde_json::to_string_pretty(&schema)?;
-            println!("{json}");
+        Command::Resolve(args) => return resolve::run(args),
+        Command::Nl(args) => nl::run(args, language)?,
+        Command::Diff(args) => diff::run(args)?,
+        Command::Explain(args) => explain::run(args, language)?,
+        Command::Next(args) => next::run(args)?,
+        Command::Occurrences(args) => occurrences::run(args)?,
+        Command::Repl(args) => repl::run(args, language)?,
+        Command::Cal(args) => cal::run(args, language)?,
+        Command::Table(args) => table::run(args)?,
+        Command::Classify(args) => classify::run(args, language)?,
+        Command::Translate(args) => translate::run(args)?,
+        Command::Validate(args) => return validate::run(args),
+        Command::Schema(args) => schema::run(args, language)?,
+        Command::Completions { shell } => {
+            clap_complete::generate(
+                shell,
+                &mut Cli::command(),
+                "reltime",
+                &mut std::io::stdout(),
+            );
         }
     };
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
 }
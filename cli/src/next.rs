@@ -0,0 +1,121 @@
+use std::{fmt::Display, io::Write};
+
+use chrono::{DateTime, Utc};
+use clap::Args;
+use reltime::{Time, relative::Relative};
+
+use crate::{
+    error::Error,
+    nl::{parse_input, parse_relative_to},
+    output::OutputFormat,
+};
+
+/// Arguments for finding the next boundary of a value
+#[derive(Debug, Clone, Args)]
+pub struct NextArgs {
+    /// Reference timestamp, or an expression resolved to its latest timestamp (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to, conflicts_with = "follow")]
+    relative_to: Option<DateTime<Utc>>,
+    /// Output format: rfc3339, rfc2822, unix, unix-ms, strftime:<fmt> or json
+    #[clap(long, short, env = "RELTIME_OUTPUT", default_value = "rfc3339")]
+    output: OutputFormat,
+    /// Keep running, printing each boundary as it passes
+    #[clap(long, short)]
+    follow: bool,
+    /// Value to watch, e.g. "friday", "15:00" or "this week"
+    value: String,
+}
+
+/// The kind of boundary passed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    /// A point in time, such as a time of day or a date
+    At,
+    /// The start of a window, such as a weekday or a month
+    Start,
+    /// The exclusive end of a window
+    End,
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Event::At => "at",
+            Event::Start => "start",
+            Event::End => "end",
+        })
+    }
+}
+
+/// Prints the next boundary of the value as `<event> <timestamp>`.
+///
+/// With `--follow`, sleeps until each boundary passes, prints it and recomputes the next one
+/// against the current time, until the value has no boundaries left.
+pub fn run(args: NextArgs) -> Result<(), Error> {
+    let time = parse_input(&args.value)?;
+
+    if !args.follow {
+        let relative_to = args.relative_to.unwrap_or(Utc::now());
+        let (event, at) = boundary(&time, relative_to)
+            .ok_or_else(|| Error::Unsupported(format!("'{time}' has no upcoming boundary")))?;
+
+        println!("{event} {}", args.output.render(at)?);
+
+        return Ok(());
+    }
+
+    let mut stdout = std::io::stdout().lock();
+
+    while let Some((event, at)) = boundary(&time, Utc::now()) {
+        if let Ok(duration) = (at - Utc::now()).to_std() {
+            std::thread::sleep(duration);
+        }
+
+        writeln!(stdout, "{event} {}", args.output.render(at)?)?;
+        stdout.flush()?;
+    }
+
+    Ok(())
+}
+
+/// The earliest boundary of the value after the reference time.
+///
+/// Points in time have a single boundary, the earliest of their bounds still ahead. Windows
+/// start at their earliest and end at their latest timestamp, with weekdays and months
+/// allowed to match the current day or month so an ongoing window reports its end.
+fn boundary(time: &Time, relative_to: DateTime<Utc>) -> Option<(Event, DateTime<Utc>)> {
+    let (min, max) = match time {
+        Time::Weekday(weekday) => (
+            weekday.to_chrono_min(relative_to, false),
+            weekday.to_chrono_max(relative_to, false),
+        ),
+        Time::Month(month) => (
+            month.to_chrono_min(relative_to, false),
+            month.to_chrono_max(relative_to, false),
+        ),
+        time => (
+            time.clone().to_chrono_min(relative_to),
+            time.clone().to_chrono_max(relative_to),
+        ),
+    };
+
+    let point = matches!(
+        time,
+        Time::Relative(Relative::Time(_) | Relative::Date(_) | Relative::DateTime(_))
+            | Time::WeekdayTime(_)
+            | Time::Exact(_)
+            | Time::DateTime(_)
+            | Time::Boundary(_)
+    );
+
+    let (start, end) = if point {
+        (Event::At, Event::At)
+    } else {
+        (Event::Start, Event::End)
+    };
+
+    [(start, min), (end, max)]
+        .into_iter()
+        .filter(|(_, at)| *at > relative_to)
+        .min_by_key(|(_, at)| *at)
+}
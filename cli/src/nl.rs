@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use clap::Args;
+use reltime::{Time, language::Language, parse::parse};
+use serde_json::json;
+
+use crate::{
+    error::Error,
+    export::{ExportFormat, strip_every},
+    language::localize,
+    output::OutputFormat,
+};
+
+/// Arguments for natural language parsing
+#[derive(Debug, Clone, Args)]
+pub struct NlArgs {
+    /// Reference timestamp, or an expression resolved to its latest timestamp (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
+    relative_to: Option<DateTime<Utc>>,
+    /// Output format: rfc3339, rfc2822, unix, unix-ms, strftime:<fmt> or json
+    #[clap(long, short, env = "RELTIME_OUTPUT", default_value = "json")]
+    output: OutputFormat,
+    /// Export to a calendar format instead of printing JSON
+    #[clap(long = "as", value_enum)]
+    export: Option<ExportFormat>,
+    /// Expression to parse, e.g. "next friday" or "25/12 18:00"
+    #[clap(required = true)]
+    text: Vec<String>,
+}
+
+/// Prints the structured value parsed from the text, along with its resolved bounds.
+///
+/// The value is re-expressed in `language` when given.
+pub fn run(args: NlArgs, language: Option<Language>) -> Result<(), Error> {
+    let relative_to = args.relative_to.unwrap_or(Utc::now());
+    let text = args.text.join(" ");
+
+    if let Some(export) = args.export {
+        let time = localize(parse(strip_every(&text))?, language);
+        println!("{}", export.export(&time, relative_to)?);
+        return Ok(());
+    }
+
+    let time = localize(parse(&text)?, language);
+
+    let json = json!({
+        "value": time,
+        "min": args.output.to_json(time.clone().to_chrono_min(relative_to))?,
+        "max": args.output.to_json(time.clone().to_chrono_max(relative_to))?,
+    });
+
+    println!("{}", serde_json::to_string_pretty(&json)?);
+
+    Ok(())
+}
+
+/// Parses a command line value as natural language, falling back to JSON.
+pub fn parse_input(input: &str) -> Result<Time, Error> {
+    parse(input).or_else(|e| serde_json::from_str(input).map_err(|_| e.into()))
+}
+
+/// Parses a reference time as RFC 3339 or any expression, resolved to its latest timestamp.
+pub fn parse_relative_to(input: &str) -> Result<DateTime<Utc>, Error> {
+    Ok(parse_input(input)?.to_chrono_max(Utc::now()))
+}
@@ -0,0 +1,102 @@
+use chrono::{DateTime, Days, Months, Utc};
+use clap::Args;
+use reltime::{Time, exact::ExactDate, named::Recurrence, relative::Relative};
+
+use crate::{
+    error::Error,
+    nl::{parse_input, parse_relative_to},
+    output::OutputFormat,
+};
+
+/// Arguments for listing occurrences
+#[derive(Debug, Clone, Args)]
+pub struct OccurrencesArgs {
+    /// Reference timestamp or expression to list occurrences after (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
+    relative_to: Option<DateTime<Utc>>,
+    /// Output format: rfc3339, rfc2822, unix, unix-ms, strftime:<fmt> or json
+    #[clap(long, short, env = "RELTIME_OUTPUT", default_value = "rfc3339")]
+    output: OutputFormat,
+    /// Number of occurrences to list
+    #[clap(long, short, default_value_t = 5)]
+    count: usize,
+    /// Recurring value, such as a weekday, a month, a time of day or a date without a year
+    value: String,
+}
+
+/// Prints the start of each of the next occurrences of a recurring value, one per line.
+pub fn run(args: OccurrencesArgs) -> Result<(), Error> {
+    let relative_to = args.relative_to.unwrap_or(Utc::now());
+    let time = parse_input(&args.value)?;
+
+    for occurrence in occurrences(time, relative_to)?.take(args.count) {
+        println!("{}", args.output.render(occurrence)?);
+    }
+
+    Ok(())
+}
+
+/// How often a recurring value repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Yearly,
+}
+
+impl Frequency {
+    /// The frequency a value recurs at, or `None` for values that happen once.
+    pub fn of(time: &Time) -> Option<Self> {
+        match time {
+            Time::Weekday(_) | Time::WeekdayTime(_) => Some(Self::Weekly),
+            Time::Month(_) => Some(Self::Yearly),
+            Time::Relative(Relative::Time(_)) => Some(Self::Daily),
+            Time::Relative(Relative::Date(ExactDate::WithoutYear(..))) => Some(Self::Yearly),
+            Time::Relative(Relative::DateTime(date_time))
+                if matches!(date_time.date(), ExactDate::WithoutYear(..)) =>
+            {
+                Some(Self::Yearly)
+            }
+            Time::Named(named) => match named.date().recurrence() {
+                Recurrence::Weekday(_) => Some(Self::Weekly),
+                Recurrence::Date { .. } => Some(Self::Yearly),
+                Recurrence::DayOfMonth(_) | Recurrence::Yearly(_) => None,
+            },
+            Time::Boundary(boundary) => Self::of(boundary.time()),
+            Time::At(at) => Self::of(at.day()),
+            _ => None,
+        }
+    }
+
+    fn advance(self, date_time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Frequency::Daily => date_time.checked_add_days(Days::new(1)),
+            Frequency::Weekly => date_time.checked_add_days(Days::new(7)),
+            Frequency::Yearly => date_time.checked_add_months(Months::new(12)),
+        }
+    }
+}
+
+/// The start of the next occurrence of a value, which for windows is their earliest timestamp
+/// and for dates and times of day their next occurrence.
+pub fn next_occurrence(time: &Time, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+    match time {
+        Time::Relative(Relative::Time(_) | Relative::Date(_) | Relative::DateTime(_)) => {
+            time.clone().to_chrono_max(relative_to)
+        }
+        _ => time.clone().to_chrono_min(relative_to),
+    }
+}
+
+/// Successive starts of a recurring value, beginning with its next occurrence.
+fn occurrences(
+    time: Time,
+    relative_to: DateTime<Utc>,
+) -> Result<impl Iterator<Item = DateTime<Utc>>, Error> {
+    let frequency = Frequency::of(&time).ok_or_else(|| Error::NotRecurring(time.to_string()))?;
+    let first = next_occurrence(&time, relative_to);
+
+    Ok(std::iter::successors(Some(first), move |previous| {
+        frequency.advance(*previous)
+    }))
+}
@@ -0,0 +1,146 @@
+use std::{str::FromStr, sync::OnceLock};
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, Utc,
+    format::{Fixed, Item, StrftimeItems},
+};
+use reltime::{language::Language, month::Month, weekday::Weekday};
+
+/// UTC offset timestamps are displayed in, set once from `--timezone`
+static OFFSET: OnceLock<FixedOffset> = OnceLock::new();
+
+/// Language weekday and month names are displayed in, set once from `--language`
+static LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+/// Displays every rendered timestamp at the given UTC offset.
+pub fn set_offset(offset: FixedOffset) {
+    let _ = OFFSET.set(offset);
+}
+
+/// Spells weekday and month names in strftime patterns in the given language.
+pub fn set_language(language: Language) {
+    let _ = LANGUAGE.set(language);
+}
+
+/// Parses a UTC offset such as `+02:00`, or `UTC`.
+pub fn parse_offset(input: &str) -> Result<FixedOffset, String> {
+    match input {
+        "UTC" | "utc" | "Z" => Ok(FixedOffset::east_opt(0).unwrap()),
+        _ => input
+            .parse()
+            .map_err(|_| format!("invalid UTC offset '{input}', expected e.g. +02:00 or UTC")),
+    }
+}
+
+/// Output format for resolved timestamps
+#[derive(Debug, Clone, Default)]
+pub enum OutputFormat {
+    /// RFC 3339 timestamp, e.g. `2025-07-29T10:30:05+00:00`
+    Rfc3339,
+    /// RFC 2822 timestamp, e.g. `Tue, 29 Jul 2025 10:30:05 +0000`
+    Rfc2822,
+    /// Seconds since the unix epoch
+    Unix,
+    /// Milliseconds since the unix epoch
+    UnixMs,
+    /// Custom chrono strftime pattern
+    Strftime(Vec<Item<'static>>),
+    /// JSON string (the default)
+    #[default]
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rfc3339" => Ok(Self::Rfc3339),
+            "rfc2822" => Ok(Self::Rfc2822),
+            "unix" => Ok(Self::Unix),
+            "unix-ms" => Ok(Self::UnixMs),
+            "json" => Ok(Self::Json),
+            _ => match s.strip_prefix("strftime:") {
+                Some(fmt) => StrftimeItems::new(fmt)
+                    .parse_to_owned()
+                    .map(Self::Strftime)
+                    .map_err(|e| format!("invalid strftime pattern '{fmt}': {e}")),
+                None => Err(format!(
+                    "unknown output format '{s}', expected one of rfc3339, rfc2822, unix, unix-ms, strftime:<fmt>, json"
+                )),
+            },
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Renders a timestamp in this format.
+    pub fn render(&self, date_time: DateTime<Utc>) -> Result<String, serde_json::Error> {
+        let date_time = date_time.with_timezone(&offset());
+
+        Ok(match self {
+            OutputFormat::Rfc3339 => date_time.to_rfc3339(),
+            OutputFormat::Rfc2822 => date_time.to_rfc2822(),
+            OutputFormat::Unix => date_time.timestamp().to_string(),
+            OutputFormat::UnixMs => date_time.timestamp_millis().to_string(),
+            OutputFormat::Strftime(items) => {
+                let items = items.iter().map(|item| localize(item, date_time));
+                date_time.format_with_items(items).to_string()
+            }
+            OutputFormat::Json => serde_json::to_string_pretty(&date_time)?,
+        })
+    }
+
+    /// Converts both bounds of a value to `{"min": .., "max": ..}` in this format.
+    pub fn range_json(
+        &self,
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+    ) -> Result<serde_json::Value, serde_json::Error> {
+        Ok(serde_json::json!({ "min": self.to_json(min)?, "max": self.to_json(max)? }))
+    }
+
+    /// Converts a timestamp to a JSON value in this format, keeping unix timestamps numeric.
+    pub fn to_json(
+        &self,
+        date_time: DateTime<Utc>,
+    ) -> Result<serde_json::Value, serde_json::Error> {
+        Ok(match self {
+            OutputFormat::Unix => date_time.timestamp().into(),
+            OutputFormat::UnixMs => date_time.timestamp_millis().into(),
+            OutputFormat::Json => serde_json::to_value(date_time.with_timezone(&offset()))?,
+            OutputFormat::Rfc3339 | OutputFormat::Rfc2822 | OutputFormat::Strftime(_) => {
+                self.render(date_time)?.into()
+            }
+        })
+    }
+}
+
+/// Replaces full weekday and month names with their spelling in the configured language,
+/// lowercase as in running text in every language but English.
+fn localize(item: &Item<'static>, date_time: DateTime<FixedOffset>) -> Item<'static> {
+    let name = |name: String| Item::OwnedLiteral(name.to_lowercase().into_boxed_str());
+
+    let language = LANGUAGE
+        .get()
+        .copied()
+        .filter(|language| !matches!(language, Language::English(_)));
+
+    match (language, item) {
+        (Some(language), Item::Fixed(Fixed::LongWeekdayName)) => {
+            name(Weekday::from_chrono_weekday(date_time.weekday(), language).to_string())
+        }
+        (Some(language), Item::Fixed(Fixed::LongMonthName)) => {
+            let month = Month::from_chrono(date_time.naive_local().and_utc(), false, language);
+            name(month.to_string())
+        }
+        _ => item.clone(),
+    }
+}
+
+fn offset() -> FixedOffset {
+    OFFSET
+        .get()
+        .copied()
+        .unwrap_or(FixedOffset::east_opt(0).unwrap())
+}
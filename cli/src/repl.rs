@@ -0,0 +1,98 @@
+use std::io::{BufRead, Write};
+
+use chrono::{DateTime, Utc};
+use clap::{Args, ValueEnum};
+use reltime::{language::Language, traits::WithLanguage};
+
+use crate::{
+    error::Error,
+    explain::variant,
+    language::LanguageArg,
+    nl::{parse_input, parse_relative_to},
+    output::OutputFormat,
+};
+
+const HELP: &str = "\
+Enter a time expression, e.g. \"next friday\" or \"25/12 18:00\", or a command:
+  :relative-to <value|now>    Resolve against a time or expression, or the current time
+  :language <en|sv>           Display values in a language
+  :help                       Show this help
+  :quit                       Exit";
+
+/// Arguments for the interactive session
+#[derive(Debug, Clone, Args)]
+pub struct ReplArgs {
+    /// Reference timestamp, or an expression resolved to its latest timestamp (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
+    relative_to: Option<DateTime<Utc>>,
+    /// Output format: rfc3339, rfc2822, unix, unix-ms, strftime:<fmt> or json
+    #[clap(long, short, env = "RELTIME_OUTPUT", default_value = "rfc3339")]
+    output: OutputFormat,
+}
+
+/// Runs an interactive session, interpreting each line as a time expression.
+///
+/// Values are displayed in `language` when given, or the language they were written in.
+pub fn run(args: ReplArgs, language: Option<Language>) -> Result<(), Error> {
+    let mut relative_to = args.relative_to;
+    let mut language = language;
+
+    let mut stdout = std::io::stdout().lock();
+    let mut lines = std::io::stdin().lock().lines();
+
+    loop {
+        write!(stdout, "reltime> ")?;
+        stdout.flush()?;
+
+        let Some(line) = lines.next().transpose()? else {
+            writeln!(stdout)?;
+            return Ok(());
+        };
+
+        let line = line.trim();
+        let (command, argument) = line.split_once(' ').unwrap_or((line, ""));
+        let argument = argument.trim();
+
+        match command {
+            "" => {}
+            ":quit" | ":q" | ":exit" => return Ok(()),
+            ":help" | ":h" => writeln!(stdout, "{HELP}")?,
+            ":relative-to" => match argument {
+                "now" | "" => relative_to = None,
+                _ => match parse_relative_to(argument) {
+                    Ok(date_time) => relative_to = Some(date_time),
+                    Err(e) => writeln!(stdout, "error: {e}")?,
+                },
+            },
+            ":language" => match LanguageArg::from_str(argument, true) {
+                Ok(selected) => language = Some(selected.into()),
+                Err(e) => writeln!(stdout, "error: {e}")?,
+            },
+            _ if command.starts_with(':') => {
+                writeln!(stdout, "error: unknown command '{command}', try :help")?
+            }
+            _ => match parse_input(line) {
+                Ok(time) => {
+                    let now = relative_to.unwrap_or(Utc::now());
+                    let time = match language {
+                        Some(language) => time.with_language(language),
+                        None => time,
+                    };
+
+                    writeln!(stdout, "{time} ({})", variant(&time))?;
+                    writeln!(
+                        stdout,
+                        "  min: {}",
+                        args.output.render(time.clone().to_chrono_min(now))?
+                    )?;
+                    writeln!(
+                        stdout,
+                        "  max: {}",
+                        args.output.render(time.to_chrono_max(now))?
+                    )?;
+                }
+                Err(e) => writeln!(stdout, "error: {e}")?,
+            },
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::{io::BufRead, process::ExitCode};
+
+use chrono::{DateTime, Utc};
+use clap::Args;
+use reltime::Time;
+use serde_json::{Map, Value as Json};
+
+use crate::{batch::resolve_lines, error::Error, nl::parse_relative_to, output::OutputFormat};
+
+/// Arguments for batch resolution
+#[derive(Debug, Clone, Args)]
+pub struct ResolveArgs {
+    /// Reference timestamp or expression shared by every value (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
+    relative_to: Option<DateTime<Utc>>,
+    /// Output format: rfc3339, rfc2822, unix, unix-ms, strftime:<fmt> or json
+    #[clap(long, short, env = "RELTIME_OUTPUT", default_value = "json")]
+    output: OutputFormat,
+    /// Record field holding the time value when a line is a JSON object
+    #[clap(long, default_value = "time")]
+    field: String,
+    /// Read one JSON value or record per line from stdin
+    #[clap(long)]
+    stdin: bool,
+    /// JSON values to resolve
+    #[clap(required_unless_present = "stdin")]
+    values: Vec<String>,
+}
+
+/// Resolves every input line against the same reference time, writing one JSON line per input.
+///
+/// Plain values produce `{"min": .., "max": ..}`, records get `min` and `max` added alongside
+/// their existing fields. Failures are reported as with [`resolve_lines`].
+pub fn run(args: ResolveArgs) -> Result<ExitCode, Error> {
+    let relative_to = args.relative_to.unwrap_or(Utc::now());
+
+    let lines: Box<dyn Iterator<Item = std::io::Result<String>>> = if args.stdin {
+        Box::new(std::io::stdin().lock().lines())
+    } else {
+        Box::new(args.values.into_iter().map(Ok))
+    };
+
+    resolve_lines(lines, 1, |line| {
+        let record = resolve_line(line, &args.field, relative_to, &args.output)?;
+        Ok(serde_json::to_string(&record)?)
+    })
+}
+
+fn resolve_line(
+    line: &str,
+    field: &str,
+    relative_to: DateTime<Utc>,
+    output: &OutputFormat,
+) -> Result<Map<String, Json>, serde_json::Error> {
+    let (mut record, value) = match serde_json::from_str(line)? {
+        Json::Object(record) if record.contains_key(field) => {
+            let value = record[field].clone();
+            (record, value)
+        }
+        value => (Map::new(), value),
+    };
+
+    let time: Time = serde_json::from_value(value)?;
+
+    record.insert(
+        "min".into(),
+        output.to_json(time.clone().to_chrono_min(relative_to))?,
+    );
+    record.insert(
+        "max".into(),
+        output.to_json(time.to_chrono_max(relative_to))?,
+    );
+
+    Ok(record)
+}
@@ -0,0 +1,78 @@
+use clap::{Args, ValueEnum};
+use reltime::{
+    Time, exact::ExactDateTime, language::Language, month::Month, relative::Relative,
+    weekday::Weekday,
+};
+use schemars::{Schema, schema_for};
+use serde_json::Value as Json;
+
+use crate::{error::Error, language::languages};
+
+/// Type to generate a schema for
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SchemaType {
+    Time,
+    Relative,
+    Weekday,
+    Month,
+    Exact,
+}
+
+/// Arguments for schema generation
+#[derive(Debug, Clone, Args)]
+pub struct SchemaArgs {
+    /// Type to generate the schema for
+    #[clap(long = "type", value_enum, default_value = "time")]
+    schema_type: SchemaType,
+}
+
+/// Prints the JSON Schema for the selected type, restricted to the vocabulary of `language`
+/// when given.
+pub fn run(args: SchemaArgs, language: Option<Language>) -> Result<(), Error> {
+    let schema = match args.schema_type {
+        SchemaType::Time => schema_for!(Time),
+        SchemaType::Relative => schema_for!(Relative),
+        SchemaType::Weekday => schema_for!(Weekday),
+        SchemaType::Month => schema_for!(Month),
+        SchemaType::Exact => schema_for!(ExactDateTime),
+    };
+
+    let schema = match language {
+        Some(language) => {
+            let mut json = schema.to_value();
+            retain_language(&mut json, language);
+            Schema::try_from(json)?
+        }
+        None => schema,
+    };
+
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+
+    Ok(())
+}
+
+/// Removes keywords spelled in other languages from every `enum` in the schema.
+fn retain_language(json: &mut Json, language: Language) {
+    match json {
+        Json::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match value {
+                    Json::Array(words) if key == "enum" => words.retain(|word| {
+                        serde_json::from_value::<Time>(word.clone())
+                            .map(|time| languages(&time))
+                            .map_or(true, |languages| {
+                                languages.is_empty() || languages.contains(&language)
+                            })
+                    }),
+                    value => retain_language(value, language),
+                }
+            }
+        }
+        Json::Array(array) => {
+            for value in array {
+                retain_language(value, language);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,111 @@
+use chrono::{DateTime, Datelike, Utc};
+use clap::Args;
+use reltime::{
+    Time,
+    exact::{ExactDate, ExactTime},
+    language::Language,
+    month::Month,
+    relative::Relative,
+    traits::WithLanguage,
+    weekday::Weekday,
+};
+
+use crate::{error::Error, nl::parse_relative_to};
+
+/// Arguments for describing a timestamp
+#[derive(Debug, Clone, Args)]
+pub struct TableArgs {
+    /// Reference timestamp or expression the relative candidates are resolved against (defaults to current time)
+    #[clap(long, short, value_parser = parse_relative_to)]
+    relative_to: Option<DateTime<Utc>>,
+    /// RFC 3339 timestamp to describe
+    instant: DateTime<Utc>,
+}
+
+/// Prints every representation of the instant as a two-column table.
+pub fn run(args: TableArgs) -> Result<(), Error> {
+    let relative_to = args.relative_to.unwrap_or(Utc::now());
+    let instant = args.instant;
+
+    let mut rows = vec![
+        ("rfc3339".to_string(), instant.to_rfc3339()),
+        ("unix".to_string(), instant.timestamp().to_string()),
+        (
+            "date".to_string(),
+            ExactDate::from_chrono(instant.date_naive()).to_string(),
+        ),
+        (
+            "time".to_string(),
+            ExactTime::from_chrono(instant.time()).to_string(),
+        ),
+        ("iso week".to_string(), instant.format("%G-W%V").to_string()),
+        ("day of year".to_string(), instant.ordinal().to_string()),
+    ];
+
+    let candidates = candidates(instant, relative_to);
+
+    for language in Language::all() {
+        let weekday = Weekday::from_chrono(instant, false, language);
+        let month = Month::from_chrono(instant, false, language);
+        let relative = candidates
+            .iter()
+            .map(|time| time.with_language(language).to_string())
+            .collect::<Vec<_>>();
+
+        rows.push((format!("weekday ({language})"), weekday.to_string()));
+        rows.push((format!("month ({language})"), month.to_string()));
+        rows.push((
+            format!("relative ({language})"),
+            if relative.is_empty() {
+                "-".to_string()
+            } else {
+                relative.join(", ")
+            },
+        ));
+    }
+
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+
+    for (label, value) in rows {
+        println!("{label:<width$}  {value}");
+    }
+
+    Ok(())
+}
+
+/// Every keyword whose window, resolved against `relative_to`, contains the instant.
+fn candidates(instant: DateTime<Utc>, relative_to: DateTime<Utc>) -> Vec<Time> {
+    let language = Language::default();
+
+    [
+        Time::Relative(Relative::today()),
+        Time::Relative(Relative::tomorrow()),
+        Time::Relative(Relative::this_week()),
+        Time::Relative(Relative::next_week()),
+        Time::Relative(Relative::this_month()),
+        Time::Relative(Relative::next_month()),
+        Time::Relative(Relative::this_year()),
+        Time::Relative(Relative::next_year()),
+        Time::Relative(Relative::this_weekend()),
+        Time::Relative(Relative::next_weekend()),
+        Time::Relative(Relative::this_quarter()),
+        Time::Relative(Relative::next_quarter()),
+        Time::Relative(Relative::this_hour()),
+        Time::Relative(Relative::next_hour()),
+        Time::Relative(Relative::yesterday()),
+        Time::Relative(Relative::last_week()),
+        Time::Relative(Relative::last_month()),
+        Time::Relative(Relative::last_year()),
+        Time::Relative(Relative::older()),
+        Time::Relative(Relative::even_week()),
+        Time::Relative(Relative::odd_week()),
+        Time::Weekday(Weekday::from_chrono(instant, false, language)),
+        Time::Month(Month::from_chrono(instant, false, language)),
+    ]
+    .into_iter()
+    .filter(|time| {
+        time.clone().to_chrono_min(relative_to) <= instant
+            && instant < time.clone().to_chrono_max(relative_to)
+    })
+    .collect()
+}
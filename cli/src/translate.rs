@@ -0,0 +1,23 @@
+use clap::Args;
+use reltime::traits::WithLanguage;
+
+use crate::{error::Error, language::LanguageArg, nl::parse_input};
+
+/// Arguments for translating a value
+#[derive(Debug, Clone, Args)]
+pub struct TranslateArgs {
+    /// Language to translate to
+    #[clap(long, short)]
+    to: LanguageArg,
+    /// Value in any enabled language, as natural language or JSON
+    value: String,
+}
+
+/// Prints the value re-expressed in the target language as JSON.
+pub fn run(args: TranslateArgs) -> Result<(), Error> {
+    let time = parse_input(&args.value)?.with_language(args.to.into());
+
+    println!("{}", serde_json::to_string_pretty(&time)?);
+
+    Ok(())
+}
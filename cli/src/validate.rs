@@ -0,0 +1,92 @@
+use std::{io::Read, process::ExitCode};
+
+use clap::Args;
+use reltime::Time;
+use serde_json::Value as Json;
+
+use crate::error::Error;
+
+/// Exit code when any value fails validation
+const INVALID: u8 = 1;
+
+/// Arguments for validating time values
+#[derive(Debug, Clone, Args)]
+pub struct ValidateArgs {
+    /// Field names holding time values, searched for at any depth (defaults to the whole document)
+    #[clap(long, short)]
+    field: Vec<String>,
+    /// Read the JSON document from stdin
+    #[clap(long)]
+    stdin: bool,
+    /// JSON document to validate
+    #[clap(required_unless_present = "stdin")]
+    json: Option<String>,
+}
+
+/// Validates time values strictly, printing one line per invalid value.
+///
+/// Values must match the schema and must not rely on clamping or fall on dates that do not
+/// exist. Exits with 0 when everything is valid and 1 when any value is invalid. Input that is
+/// not JSON is reported as an error.
+pub fn run(args: ValidateArgs) -> Result<ExitCode, Error> {
+    let input = match args.json {
+        Some(json) if !args.stdin => json,
+        _ => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            input
+        }
+    };
+
+    let document: Json = serde_json::from_str(&input)?;
+
+    let mut errors = Vec::new();
+
+    if args.field.is_empty() {
+        validate(&document, String::new(), &mut errors);
+    } else {
+        collect(&document, String::new(), &args.field, &mut errors);
+    }
+
+    for (path, error) in &errors {
+        let path = if path.is_empty() { "/" } else { path };
+        println!("{path}: {error}");
+    }
+
+    Ok(if errors.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(INVALID)
+    })
+}
+
+/// Walks the document, validating the values of every matching field.
+fn collect(json: &Json, path: String, fields: &[String], errors: &mut Vec<(String, String)>) {
+    match json {
+        Json::Object(object) => {
+            for (key, value) in object {
+                let path = format!("{path}/{key}");
+
+                if fields.contains(key) {
+                    validate(value, path, errors);
+                } else {
+                    collect(value, path, fields, errors);
+                }
+            }
+        }
+        Json::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                collect(value, format!("{path}/{index}"), fields, errors);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Validates a single value. Deserialising rejects out-of-range components and dates that do not
+/// exist, naming the offending part.
+fn validate(json: &Json, path: String, errors: &mut Vec<(String, String)>) {
+    if let Err(e) = serde_json::from_value::<Time>(json.clone()) {
+        errors.push((path, e.to_string()));
+    }
+}
@@ -0,0 +1,201 @@
+//! Classifying timestamps into the buckets of grouped list views.
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::iter::Peekable;
+
+use chrono::{DateTime, Datelike, Utc};
+
+use crate::{
+    Time,
+    exact::ExactDate,
+    language::Language,
+    month::Month,
+    relative::{Older, Relative, ThisMonth, ThisWeek, Today, Yesterday},
+    traits::FromLanguage,
+};
+
+/// The bucket a timestamp falls in: `Today`, `Yesterday`, `ThisWeek`, `ThisMonth` or `Older`,
+/// checked in that order and named in the given language.
+///
+/// Timestamps after this month have no bucket and are returned as they are.
+pub fn bucket(instant: DateTime<Utc>, relative_to: DateTime<Utc>, language: Language) -> Time {
+    [
+        Relative::Today(Today::from_language(language)),
+        Relative::Yesterday(Yesterday::from_language(language)),
+        Relative::ThisWeek(ThisWeek::from_language(language)),
+        Relative::ThisMonth(ThisMonth::from_language(language)),
+        Relative::Older(Older::from_language(language)),
+    ]
+    .into_iter()
+    .find(|relative| {
+        (*relative).to_chrono_min(relative_to) <= instant
+            && instant < (*relative).to_chrono_max(relative_to)
+    })
+    .map_or(Time::DateTime(instant), Time::Relative)
+}
+
+/// The bucket of each timestamp, in order. See [`bucket`].
+pub fn bucket_many(
+    instants: impl IntoIterator<Item = DateTime<Utc>>,
+    relative_to: DateTime<Utc>,
+    language: Language,
+) -> Vec<Time> {
+    instants
+        .into_iter()
+        .map(|instant| bucket(instant, relative_to, language))
+        .collect()
+}
+
+/// Splits items into labelled sections the way email clients group messages.
+///
+/// Items are expected newest first, each paired with its timestamp. Consecutive items sharing a
+/// label form one section. Labels are, in the given language, `"Today"`, `"Yesterday"`,
+/// `"This week"` and `"This month"`, then the name of each earlier month of the current year,
+/// then `"Older"`. Timestamps after this month are labelled with their date.
+pub fn sections<T, I: IntoIterator<Item = (DateTime<Utc>, T)>>(
+    items: I,
+    relative_to: DateTime<Utc>,
+    language: Language,
+) -> Sections<I::IntoIter, T> {
+    Sections {
+        items: items.into_iter().peekable(),
+        relative_to,
+        language,
+    }
+}
+
+/// Iterator over `(label, items)` sections. See [`sections`].
+pub struct Sections<I: Iterator<Item = (DateTime<Utc>, T)>, T> {
+    items: Peekable<I>,
+    relative_to: DateTime<Utc>,
+    language: Language,
+}
+
+impl<I: Iterator<Item = (DateTime<Utc>, T)>, T> Iterator for Sections<I, T> {
+    type Item = (String, Vec<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (instant, item) = self.items.next()?;
+        let section = label(instant, self.relative_to, self.language);
+        let mut items = vec![item];
+
+        while let Some((instant, _)) = self.items.peek() {
+            if label(*instant, self.relative_to, self.language) != section {
+                break;
+            }
+
+            items.push(self.items.next()?.1);
+        }
+
+        Some((section, items))
+    }
+}
+
+/// The section label of a timestamp. See [`sections`].
+fn label(instant: DateTime<Utc>, relative_to: DateTime<Utc>, language: Language) -> String {
+    match bucket(instant, relative_to, language) {
+        Time::Relative(Relative::Older(_)) if instant.year() == relative_to.year() => {
+            Month::from_chrono(instant, false, language).to_string()
+        }
+        Time::DateTime(instant) => ExactDate::from_chrono(instant.date_naive()).to_string(),
+        time => words(&time.to_string()),
+    }
+}
+
+/// Splits a keyword into words, e.g. `"ThisWeek"` into `"This week"`.
+fn words(keyword: &str) -> String {
+    keyword
+        .char_indices()
+        .flat_map(|(index, c)| match index {
+            0 => vec![c],
+            _ if c.is_uppercase() => core::iter::once(' ').chain(c.to_lowercase()).collect(),
+            _ => vec![c],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn buckets_timestamps() {
+        // Tuesday July 29th, 2025
+        let now = at("2025-07-29T10:30:05Z");
+
+        assert_eq!(
+            bucket_many(
+                [
+                    at("2025-07-29T08:00:00Z"),
+                    at("2025-07-28T23:59:59Z"),
+                    at("2025-07-31T12:00:00Z"),
+                    at("2025-07-02T12:00:00Z"),
+                    at("2025-06-30T12:00:00Z"),
+                ],
+                now,
+                Language::default()
+            ),
+            vec![
+                Time::Relative(Relative::today()),
+                Time::Relative(Relative::yesterday()),
+                Time::Relative(Relative::this_week()),
+                Time::Relative(Relative::this_month()),
+                Time::Relative(Relative::older()),
+            ]
+        );
+
+        let later = at("2025-08-10T00:00:00Z");
+        assert_eq!(
+            bucket(later, now, Language::default()),
+            Time::DateTime(later)
+        );
+    }
+
+    #[cfg(feature = "swedish")]
+    #[test]
+    fn buckets_in_language() {
+        use crate::language::Swedish;
+
+        let now = at("2025-07-29T10:30:05Z");
+        let time = bucket(
+            at("2025-07-28T12:00:00Z"),
+            now,
+            Language::Swedish(Swedish::default()),
+        );
+
+        assert_eq!(time.to_string(), "Igår");
+    }
+
+    #[test]
+    fn groups_into_sections() {
+        let now = at("2025-07-29T10:30:05Z");
+        let items = [
+            ("2025-07-29T09:00:00Z", "a"),
+            ("2025-07-29T08:00:00Z", "b"),
+            ("2025-07-28T12:00:00Z", "c"),
+            ("2025-07-02T12:00:00Z", "d"),
+            ("2025-05-20T12:00:00Z", "e"),
+            ("2024-12-24T12:00:00Z", "f"),
+        ]
+        .map(|(instant, item)| (at(instant), item));
+
+        assert_eq!(
+            sections(items, now, Language::default()).collect::<Vec<_>>(),
+            vec![
+                ("Today".to_string(), vec!["a", "b"]),
+                ("Yesterday".to_string(), vec!["c"]),
+                ("This month".to_string(), vec!["d"]),
+                ("May".to_string(), vec!["e"]),
+                ("Older".to_string(), vec!["f"]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Fluent construction of time values, such as "tomorrow at 14:30".
+
+use crate::{
+    Time,
+    exact::{ExactDate, ExactDateTime, ExactTime, Zone},
+    month::Month,
+    relative::Relative,
+    weekday::{Weekday, WeekdayTime},
+};
+
+/// Builds a [`Time`] from a day and an optional time of day, choosing the variant that holds
+/// them.
+///
+/// ```
+/// use reltime::Time;
+///
+/// let meeting = Time::builder().tomorrow().at(14, 30).build();
+/// let launch = Time::on(7, 29).year(2025).at_time(10, 30, Some(5)).build();
+///
+/// assert_eq!(meeting.to_string(), "Tomorrow 14:30");
+/// assert_eq!(launch.to_string(), "29/7/2025 10:30:05");
+/// ```
+///
+/// A date and a time of day become [`Relative::DateTime`], a weekday and a time of day
+/// [`WeekdayTime`], and any other value with a time of day [`Time::At`]. Without a day the time
+/// of day stands alone, and without either the value is today.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TimeBuilder {
+    day: Option<Time>,
+    time: Option<ExactTime>,
+}
+
+impl TimeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the day to any value, replacing the previous one.
+    pub fn day(self, day: Time) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    pub fn relative(self, relative: Relative) -> Self {
+        self.day(Time::Relative(relative))
+    }
+
+    pub fn weekday(self, weekday: Weekday) -> Self {
+        self.day(Time::Weekday(weekday))
+    }
+
+    pub fn month(self, month: Month) -> Self {
+        self.day(Time::Month(month))
+    }
+
+    pub fn today(self) -> Self {
+        self.relative(Relative::today())
+    }
+
+    pub fn tomorrow(self) -> Self {
+        self.relative(Relative::tomorrow())
+    }
+
+    pub fn yesterday(self) -> Self {
+        self.relative(Relative::yesterday())
+    }
+
+    pub fn this_week(self) -> Self {
+        self.relative(Relative::this_week())
+    }
+
+    pub fn next_week(self) -> Self {
+        self.relative(Relative::next_week())
+    }
+
+    pub fn this_month(self) -> Self {
+        self.relative(Relative::this_month())
+    }
+
+    pub fn next_month(self) -> Self {
+        self.relative(Relative::next_month())
+    }
+
+    /// Sets the day to a recurring date, e.g. the 29th of July every year.
+    pub fn on(self, month: u8, day: u8) -> Self {
+        self.relative(Relative::Date(ExactDate::new(None, month, day)))
+    }
+
+    /// Pins the date to a year. Only dates take a year, so other days are left as they are.
+    pub fn year(self, year: i16) -> Self {
+        match self.day {
+            Some(Time::Relative(Relative::Date(date))) => {
+                let date = ExactDate::new(Some(year), date.month(), date.day());
+                self.relative(Relative::Date(date))
+            }
+            _ => self,
+        }
+    }
+
+    /// Sets the time of day to the hour and minute.
+    pub fn at(self, hour: u8, minute: u8) -> Self {
+        self.at_time(hour, minute, None)
+    }
+
+    /// Sets the time of day, optionally with seconds.
+    pub fn at_time(self, hour: u8, minute: u8, second: Option<u8>) -> Self {
+        Self {
+            time: Some(ExactTime::new(hour, minute, second)),
+            ..self
+        }
+    }
+
+    /// Annotates the time of day with a zone. Without a time of day there is nothing to annotate.
+    pub fn zone(self, zone: Zone) -> Self {
+        Self {
+            time: self.time.map(|time| time.with_zone(zone)),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Time {
+        match (self.day, self.time) {
+            (None, None) => Time::Relative(Relative::today()),
+            (Some(day), None) => day,
+            (None, Some(time)) => Time::Relative(Relative::Time(time)),
+            (Some(Time::Relative(Relative::Date(date))), Some(time)) => {
+                Time::Relative(Relative::DateTime(ExactDateTime::new(date, time)))
+            }
+            (Some(Time::Weekday(weekday)), Some(time)) => {
+                Time::WeekdayTime(WeekdayTime::new(weekday, time))
+            }
+            (Some(day), Some(time)) => day.at(time),
+        }
+    }
+}
+
+impl From<TimeBuilder> for Time {
+    fn from(builder: TimeBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl Time {
+    /// Starts building a value, see [`TimeBuilder`].
+    pub fn builder() -> TimeBuilder {
+        TimeBuilder::new()
+    }
+
+    /// Starts building a value on a recurring date, e.g. `Time::on(7, 29).year(2025)`.
+    pub fn on(month: u8, day: u8) -> TimeBuilder {
+        TimeBuilder::new().on(month, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_variants() {
+        assert_eq!(
+            Time::builder().tomorrow().at(14, 30).build(),
+            Time::Relative(Relative::tomorrow()).at(ExactTime::new(14, 30, None))
+        );
+        assert_eq!(
+            Time::on(7, 29).year(2025).at_time(10, 30, Some(5)).build(),
+            Time::Relative(Relative::DateTime(ExactDateTime::new(
+                ExactDate::new(Some(2025), 7, 29),
+                ExactTime::new(10, 30, Some(5))
+            )))
+        );
+        assert_eq!(
+            Time::builder().weekday(Weekday::friday()).at(9, 0).build(),
+            Time::WeekdayTime(WeekdayTime::new(
+                Weekday::friday(),
+                ExactTime::new(9, 0, None)
+            ))
+        );
+        assert_eq!(
+            Time::builder().month(Month::march()).build(),
+            Time::Month(Month::march())
+        );
+        assert_eq!(
+            Time::builder().at(9, 0).zone(Zone::Utc).build(),
+            Time::Relative(Relative::Time(
+                ExactTime::new(9, 0, None).with_zone(Zone::Utc)
+            ))
+        );
+        assert_eq!(
+            Time::builder().next_week().year(2025).build(),
+            Time::Relative(Relative::next_week())
+        );
+        assert_eq!(Time::builder().build(), Time::Relative(Relative::today()));
+    }
+}
@@ -0,0 +1,126 @@
+//! Business days, skipping weekends and an optional calendar of holidays.
+
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use chrono::{Datelike, Days, NaiveDate};
+
+use crate::{holiday::Holiday, language::Language};
+
+/// The days business is not done on besides Saturdays and Sundays.
+///
+/// Set through [`Options::with_business_calendar`](crate::options::Options::with_business_calendar)
+/// for `NextBusinessDay` and offsets like "in 5 business days". The default calendar has no
+/// holidays.
+#[derive(Clone, Copy, Default)]
+pub struct BusinessCalendar {
+    holidays: &'static [&'static dyn Holiday],
+}
+
+impl BusinessCalendar {
+    /// A calendar closed on the given holidays, e.g.
+    /// `BusinessCalendar::new(&[&NamedDay::ChristmasEve, &NamedDay::ChristmasDay])`.
+    pub const fn new(holidays: &'static [&'static dyn Holiday]) -> Self {
+        Self { holidays }
+    }
+
+    pub fn holidays(&self) -> &'static [&'static dyn Holiday] {
+        self.holidays
+    }
+
+    /// Whether the date is neither a weekend day nor a holiday.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun)
+            && !self
+                .holidays
+                .iter()
+                .any(|holiday| holiday.date_in(date.year()) == Some(date))
+    }
+
+    /// The business day the given number of business days after the date.
+    ///
+    /// Zero days is the date itself when it is a business day, and the next one otherwise.
+    pub fn add(&self, date: NaiveDate, days: u32) -> NaiveDate {
+        let mut date = self.next(date);
+
+        for _ in 0..days {
+            date = self.next(date + Days::new(1));
+        }
+
+        date
+    }
+
+    /// The first business day on or after the date.
+    fn next(&self, mut date: NaiveDate) -> NaiveDate {
+        while !self.is_business_day(date) {
+            date = date + Days::new(1);
+        }
+
+        date
+    }
+}
+
+impl fmt::Debug for BusinessCalendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.holidays.iter().map(|x| x.name(Language::default())))
+            .finish()
+    }
+}
+
+impl PartialEq for BusinessCalendar {
+    fn eq(&self, other: &Self) -> bool {
+        let names = |calendar: &Self| {
+            calendar
+                .holidays
+                .iter()
+                .map(|x| x.name(Language::default()))
+        };
+
+        names(self).eq(names(other))
+    }
+}
+
+impl Eq for BusinessCalendar {}
+
+impl Hash for BusinessCalendar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for holiday in self.holidays {
+            holiday.name(Language::default()).hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holiday::NamedDay;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn skips_weekends_and_holidays() {
+        let calendar = BusinessCalendar::default();
+        let christmas = BusinessCalendar::new(&[
+            &NamedDay::ChristmasEve,
+            &NamedDay::ChristmasDay,
+            &NamedDay::BoxingDay,
+        ]);
+
+        // Friday August 1st, 2025
+        assert_eq!(calendar.add(date("2025-08-01"), 0), date("2025-08-01"));
+        assert_eq!(calendar.add(date("2025-08-01"), 1), date("2025-08-04"));
+        assert_eq!(calendar.add(date("2025-08-02"), 0), date("2025-08-04"));
+        assert_eq!(calendar.add(date("2025-07-29"), 5), date("2025-08-05"));
+
+        // Tuesday December 23rd, 2025
+        assert_eq!(calendar.add(date("2025-12-23"), 1), date("2025-12-24"));
+        assert_eq!(christmas.add(date("2025-12-23"), 1), date("2025-12-29"));
+        assert!(!christmas.is_business_day(date("2025-12-26")));
+        assert_ne!(calendar, christmas);
+    }
+}
@@ -0,0 +1,78 @@
+//! Calendar grids for laying out months week by week.
+
+use alloc::vec::Vec;
+use chrono::{Datelike, Days, NaiveDate};
+
+use crate::month::Month;
+
+/// A week of a calendar grid, with days outside the month left empty.
+pub type Week = [Option<NaiveDate>; 7];
+
+/// Lays out a month of the given year as the weeks it spans, each starting on `week_start`.
+///
+/// `ThisWeek` and `NextWeek` resolve to weeks starting on Monday, the usual `week_start`.
+pub fn month_grid(month: Month, year: i32, week_start: chrono::Weekday) -> Vec<Week> {
+    let month = month.to_chrono().number_from_month();
+    let first = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+    let offset = first.weekday().days_since(week_start) as u64;
+
+    let mut weeks = Vec::new();
+    let mut start = first.checked_sub_days(Days::new(offset)).unwrap();
+
+    while start.month() == month || start < first {
+        let mut week = [None; 7];
+
+        for (index, day) in start.iter_days().take(7).enumerate() {
+            if day.month() == month {
+                week[index] = Some(day);
+            }
+        }
+
+        weeks.push(week);
+        start = start.checked_add_days(Days::new(7)).unwrap();
+    }
+
+    weeks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_starts_on_monday() {
+        let weeks = month_grid(Month::july(), 2025, chrono::Weekday::Mon);
+        let date = |day| NaiveDate::from_ymd_opt(2025, 7, day);
+
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(
+            weeks[0],
+            [None, date(1), date(2), date(3), date(4), date(5), date(6)]
+        );
+        assert_eq!(
+            weeks[4],
+            [date(28), date(29), date(30), date(31), None, None, None]
+        );
+    }
+
+    #[test]
+    fn grid_starts_on_sunday() {
+        let weeks = month_grid(Month::july(), 2025, chrono::Weekday::Sun);
+        let date = |day| NaiveDate::from_ymd_opt(2025, 7, day);
+
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][..3], [None, None, date(1)]);
+        assert_eq!(
+            weeks[4][..5],
+            [date(27), date(28), date(29), date(30), date(31)]
+        );
+    }
+
+    #[test]
+    fn grid_fits_whole_weeks() {
+        let weeks = month_grid(Month::february(), 2021, chrono::Weekday::Mon);
+
+        assert_eq!(weeks.len(), 4);
+        assert!(weeks.iter().flatten().all(Option::is_some));
+    }
+}
@@ -0,0 +1,171 @@
+//! Dates in calendars other than the Gregorian one.
+//!
+//! Requires the `calendar-system` feature. Dates are converted to and from [`ExactDate`], so
+//! resolution still happens in the Gregorian calendar and returns UTC instants.
+
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use core::{fmt, str::FromStr};
+
+use chrono::{Datelike, NaiveDate, Weekday};
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{exact::ExactDate, parse::ParseError};
+
+/// A calendar system whose dates convert to and from the Gregorian calendar.
+///
+/// Implement this to construct and display dates in e.g. the Hebrew or Islamic calendar.
+pub trait CalendarSystem {
+    /// A date in the calendar.
+    type Date: fmt::Display;
+
+    /// The Gregorian date of a date in the calendar, or `None` when it doesn't exist.
+    fn to_gregorian(date: &Self::Date) -> Option<NaiveDate>;
+
+    /// The date in the calendar of a Gregorian date.
+    fn from_gregorian(date: NaiveDate) -> Self::Date;
+}
+
+impl ExactDate {
+    /// Constructs a date from a date in another calendar, or `None` when it doesn't exist.
+    pub fn from_calendar<C: CalendarSystem>(date: &C::Date) -> Option<Self> {
+        C::to_gregorian(date).map(Self::from_chrono)
+    }
+
+    /// Converts a date with a year to another calendar, or `None` for recurring dates.
+    pub fn to_calendar<C: CalendarSystem>(&self) -> Option<C::Date> {
+        match self {
+            ExactDate::WithYear(..) => {
+                Some(C::from_gregorian(self.to_chrono_min(Default::default())))
+            }
+            ExactDate::WithoutYear(..) => None,
+        }
+    }
+}
+
+/// The ISO 8601 week calendar, counting weeks from the one holding the year's first Thursday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsoWeek;
+
+impl CalendarSystem for IsoWeek {
+    type Date = IsoWeekDate;
+
+    fn to_gregorian(date: &IsoWeekDate) -> Option<NaiveDate> {
+        NaiveDate::from_isoywd_opt(date.year, date.week.into(), date.weekday)
+    }
+
+    fn from_gregorian(date: NaiveDate) -> IsoWeekDate {
+        let week = date.iso_week();
+
+        IsoWeekDate {
+            year: week.year(),
+            week: week.week() as u8,
+            weekday: date.weekday(),
+        }
+    }
+}
+
+/// A date in the ISO 8601 week calendar, written `2025-W31-2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsoWeekDate {
+    pub year: i32,
+    pub week: u8,
+    pub weekday: Weekday,
+}
+
+impl fmt::Display for IsoWeekDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-W{:02}-{}",
+            self.year,
+            self.week,
+            self.weekday.number_from_monday()
+        )
+    }
+}
+
+impl FromStr for IsoWeekDate {
+    type Err = ParseError;
+
+    /// Parses `2025-W31-2`, rejecting weeks the year doesn't have.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unrecognised = || ParseError::Unrecognised(s.to_string());
+
+        let (year, rest) = s.split_once("-W").ok_or_else(unrecognised)?;
+        let (week, weekday) = rest.split_once('-').ok_or_else(unrecognised)?;
+
+        let weekday: u8 = weekday.parse().map_err(|_| unrecognised())?;
+        let date = IsoWeekDate {
+            year: year.parse().map_err(|_| unrecognised())?,
+            week: week.parse().map_err(|_| unrecognised())?,
+            weekday: Weekday::try_from(weekday.wrapping_sub(1)).map_err(|_| unrecognised())?,
+        };
+
+        IsoWeek::to_gregorian(&date)
+            .map(|_| date)
+            .ok_or_else(unrecognised)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for IsoWeekDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for IsoWeekDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for IsoWeekDate {
+    fn schema_name() -> Cow<'static, str> {
+        "IsoWeekDate".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A date in the ISO 8601 week calendar, e.g. \"2025-W31-2\".",
+            "type": "string",
+            "pattern": "^-?[0-9]{4,}-W(0[1-9]|[1-4][0-9]|5[0-3])-[1-7]$",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_iso_week_dates() {
+        let date = ExactDate::new(Some(2025), 7, 29);
+        let week_date = date.to_calendar::<IsoWeek>().unwrap();
+
+        assert_eq!(week_date.to_string(), "2025-W31-2");
+        assert_eq!(ExactDate::from_calendar::<IsoWeek>(&week_date), Some(date));
+        assert_eq!(ExactDate::new(None, 7, 29).to_calendar::<IsoWeek>(), None);
+    }
+
+    #[test]
+    fn parses_iso_week_dates() {
+        // December 29th, 2025 is in the first week of 2026
+        let date: IsoWeekDate = "2026-W01-1".parse().unwrap();
+
+        assert_eq!(
+            ExactDate::from_calendar::<IsoWeek>(&date),
+            Some(ExactDate::new(Some(2025), 12, 29))
+        );
+        assert!("2025-W53-1".parse::<IsoWeekDate>().is_err());
+        assert!("2025-W31-8".parse::<IsoWeekDate>().is_err());
+    }
+}
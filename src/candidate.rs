@@ -0,0 +1,348 @@
+//! The keywords timestamps are expressed as when converted back from chrono.
+
+use chrono::{DateTime, NaiveTime, Utc};
+use derive_more::Display;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Time,
+    day_part::{Day, DayPart, Part},
+    language::Language,
+    month::Month,
+    relative::Relative,
+    traits::WithLanguage,
+    weekday::Weekday,
+};
+
+/// Builds a keyword in the given language.
+type Constructor = fn(Language) -> Time;
+
+/// A group of keywords [`Time::from_max_chrono`] and [`Time::from_min_chrono`] may express a
+/// timestamp as.
+///
+/// Groups are tried in order and the first keyword whose boundary matches wins, so the order
+/// decides between keywords sharing a boundary. At the end of a Sunday, for example, both
+/// "Sunday" and "ThisWeek" match, and [`Candidate::DEFAULT_ORDER`] prefers the weekday. Pass
+/// another order to [`Time::from_max_chrono_ordered`] to prefer the week instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Candidate {
+    /// "Today", "Yesterday" and "Tomorrow".
+    Day,
+    /// "Monday" through "Sunday".
+    Weekday,
+    /// "ThisMonth".
+    ThisMonth,
+    /// "ThisQuarter" and "NextQuarter".
+    Quarter,
+    /// "January" through "December".
+    Month,
+    /// "ThisWeek".
+    Week,
+    /// "ThisWeekend" and "NextWeekend".
+    Weekend,
+    /// "NextMonth".
+    NextMonth,
+    /// "ThisYear" and "NextYear".
+    Year,
+    /// "LastWeek", "LastMonth" and "LastYear".
+    Last,
+    /// The parts of today, tomorrow and yesterday, e.g. "ThisMorning".
+    DayPart,
+    /// "ThisHour" and "NextHour".
+    Hour,
+}
+
+impl Candidate {
+    /// The order keywords are tried in unless another is given.
+    ///
+    /// Shorter windows come first, except that quarters are preferred over the month they end
+    /// in and the current month over its name.
+    pub const DEFAULT_ORDER: [Candidate; 12] = [
+        Candidate::Day,
+        Candidate::Weekday,
+        Candidate::ThisMonth,
+        Candidate::Quarter,
+        Candidate::Month,
+        Candidate::Week,
+        Candidate::Weekend,
+        Candidate::NextMonth,
+        Candidate::Year,
+        Candidate::Last,
+        Candidate::DayPart,
+        Candidate::Hour,
+    ];
+
+    /// The constructors of the keywords in the group, in order of preference.
+    fn constructors(self) -> &'static [Constructor] {
+        match self {
+            Candidate::Day => &[
+                |language| Time::Relative(Relative::today().with_language(language)),
+                |language| Time::Relative(Relative::yesterday().with_language(language)),
+                |language| Time::Relative(Relative::tomorrow().with_language(language)),
+            ],
+            Candidate::Weekday => &[
+                |language| Time::Weekday(Weekday::monday().with_language(language)),
+                |language| Time::Weekday(Weekday::tuesday().with_language(language)),
+                |language| Time::Weekday(Weekday::wednesday().with_language(language)),
+                |language| Time::Weekday(Weekday::thursday().with_language(language)),
+                |language| Time::Weekday(Weekday::friday().with_language(language)),
+                |language| Time::Weekday(Weekday::saturday().with_language(language)),
+                |language| Time::Weekday(Weekday::sunday().with_language(language)),
+            ],
+            Candidate::ThisMonth => {
+                &[|language| Time::Relative(Relative::this_month().with_language(language))]
+            }
+            Candidate::Quarter => &[
+                |language| Time::Relative(Relative::this_quarter().with_language(language)),
+                |language| Time::Relative(Relative::next_quarter().with_language(language)),
+            ],
+            Candidate::Month => &[
+                |language| Time::Month(Month::january().with_language(language)),
+                |language| Time::Month(Month::february().with_language(language)),
+                |language| Time::Month(Month::march().with_language(language)),
+                |language| Time::Month(Month::april().with_language(language)),
+                |language| Time::Month(Month::may().with_language(language)),
+                |language| Time::Month(Month::june().with_language(language)),
+                |language| Time::Month(Month::july().with_language(language)),
+                |language| Time::Month(Month::august().with_language(language)),
+                |language| Time::Month(Month::september().with_language(language)),
+                |language| Time::Month(Month::october().with_language(language)),
+                |language| Time::Month(Month::november().with_language(language)),
+                |language| Time::Month(Month::december().with_language(language)),
+            ],
+            Candidate::Week => {
+                &[|language| Time::Relative(Relative::this_week().with_language(language))]
+            }
+            Candidate::Weekend => &[
+                |language| Time::Relative(Relative::this_weekend().with_language(language)),
+                |language| Time::Relative(Relative::next_weekend().with_language(language)),
+            ],
+            Candidate::NextMonth => {
+                &[|language| Time::Relative(Relative::next_month().with_language(language))]
+            }
+            Candidate::Year => &[
+                |language| Time::Relative(Relative::this_year().with_language(language)),
+                |language| Time::Relative(Relative::next_year().with_language(language)),
+            ],
+            Candidate::Last => &[
+                |language| Time::Relative(Relative::last_week().with_language(language)),
+                |language| Time::Relative(Relative::last_month().with_language(language)),
+                |language| Time::Relative(Relative::last_year().with_language(language)),
+            ],
+            Candidate::DayPart => &[
+                |language| day_part(Part::Morning, Day::Today, language),
+                |language| day_part(Part::Afternoon, Day::Today, language),
+                |language| day_part(Part::Evening, Day::Today, language),
+                |language| day_part(Part::Night, Day::Today, language),
+                |language| day_part(Part::Morning, Day::Tomorrow, language),
+                |language| day_part(Part::Afternoon, Day::Tomorrow, language),
+                |language| day_part(Part::Evening, Day::Tomorrow, language),
+                |language| day_part(Part::Night, Day::Tomorrow, language),
+                |language| day_part(Part::Morning, Day::Yesterday, language),
+                |language| day_part(Part::Afternoon, Day::Yesterday, language),
+                |language| day_part(Part::Evening, Day::Yesterday, language),
+                |language| day_part(Part::Night, Day::Yesterday, language),
+            ],
+            Candidate::Hour => &[
+                |language| Time::Relative(Relative::this_hour().with_language(language)),
+                |language| Time::Relative(Relative::next_hour().with_language(language)),
+            ],
+        }
+    }
+
+    /// Whether every keyword in the group starts and ends at midnight.
+    fn on_midnight(self) -> bool {
+        !matches!(self, Candidate::DayPart | Candidate::Hour)
+    }
+
+    /// The keywords in the given groups, in order and in the given language. They are only built
+    /// as they are consumed.
+    pub(crate) fn keywords(order: &[Candidate], language: Language) -> impl Iterator<Item = Time> {
+        order
+            .iter()
+            .flat_map(|candidate| candidate.constructors())
+            .map(move |constructor| constructor(language))
+    }
+
+    /// The first keyword in the given groups whose boundary, as given by `boundary`, is the
+    /// timestamp.
+    pub(crate) fn find(
+        order: &[Candidate],
+        date_time: DateTime<Utc>,
+        language: Language,
+        boundary: impl Fn(Time) -> DateTime<Utc>,
+    ) -> Option<Time> {
+        let midnight = date_time.time() == NaiveTime::MIN;
+
+        order
+            .iter()
+            .filter(|candidate| midnight || !candidate.on_midnight())
+            .flat_map(|candidate| candidate.constructors())
+            .map(|constructor| constructor(language))
+            .find(|time| boundary(time.clone()) == date_time)
+    }
+}
+
+fn day_part(part: Part, day: Day, language: Language) -> Time {
+    Time::Relative(Relative::DayPart(
+        DayPart::new(part, Some(day)).with_language(language),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::day_part::Part;
+
+    fn relative_to() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc()
+    }
+
+    fn on(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn order_decides_between_matches() {
+        let end_of_sunday = on("2025-08-04T00:00:00Z");
+        let from = |candidates: &[Candidate]| {
+            Time::from_max_chrono_ordered(
+                end_of_sunday,
+                Some(relative_to()),
+                Language::default(),
+                candidates,
+            )
+        };
+
+        assert_eq!(
+            from(&Candidate::DEFAULT_ORDER),
+            Time::Weekday(Weekday::sunday())
+        );
+        assert_eq!(
+            from(&[Candidate::Week, Candidate::Weekday]),
+            Time::Relative(Relative::this_week())
+        );
+        assert_eq!(
+            from(&[Candidate::Day, Candidate::Month]),
+            Time::DateTime(end_of_sunday)
+        );
+    }
+
+    #[test]
+    fn options_choose_between_matches() {
+        use crate::{month::Month, options::FromChronoOptions};
+
+        let from = |date_time, options| {
+            Time::from_max_chrono_with(date_time, Some(relative_to()), Language::default(), options)
+        };
+        let defaults = FromChronoOptions::default();
+        let end_of_tomorrow = on("2025-07-31T00:00:00Z");
+        let end_of_sunday = on("2025-08-04T00:00:00Z");
+        let end_of_august = on("2025-09-01T00:00:00Z");
+
+        assert_eq!(defaults.candidates(), Candidate::DEFAULT_ORDER);
+        assert_eq!(
+            from(end_of_tomorrow, defaults),
+            Time::Relative(Relative::tomorrow())
+        );
+        assert_eq!(
+            from(end_of_tomorrow, defaults.with_prefer_weekday(true)),
+            Time::Weekday(Weekday::wednesday())
+        );
+        assert_eq!(
+            from(end_of_sunday, defaults),
+            Time::Weekday(Weekday::sunday())
+        );
+        assert_eq!(
+            from(end_of_sunday, defaults.with_prefer_relative(true)),
+            Time::Relative(Relative::this_week())
+        );
+        assert_eq!(from(end_of_august, defaults), Time::Month(Month::august()));
+        assert_eq!(
+            from(end_of_august, defaults.with_allow_months(false)),
+            Time::Relative(Relative::next_month())
+        );
+        assert_eq!(
+            from(end_of_august, defaults.with_max_lookahead_days(Some(14))),
+            Time::DateTime(end_of_august)
+        );
+        assert_eq!(
+            from(end_of_sunday, defaults.with_max_lookahead_days(Some(14))),
+            Time::Weekday(Weekday::sunday())
+        );
+    }
+
+    #[test]
+    fn day_parts_start_windows() {
+        let morning = on("2025-07-29T06:00:00Z");
+        let time = Time::from_min_chrono(morning, Some(relative_to()), Language::default());
+
+        assert_eq!(
+            time,
+            Time::Relative(Relative::day_part(Part::Morning, Some(Day::Today)))
+        );
+    }
+
+    #[test]
+    fn hours_end_on_the_hour() {
+        let from = |s| Time::from_max_chrono(on(s), Some(relative_to()), Language::default());
+
+        assert_eq!(
+            from("2025-07-29T11:00:00Z"),
+            Time::Relative(Relative::this_hour())
+        );
+        assert_eq!(
+            from("2025-07-29T12:00:00Z"),
+            Time::Relative(Relative::day_part(Part::Morning, Some(Day::Today)))
+        );
+        assert_eq!(
+            from("2025-07-29T13:00:00Z"),
+            Time::DateTime(on("2025-07-29T13:00:00Z"))
+        );
+        assert_eq!(
+            Time::from_min_chrono(
+                on("2025-07-29T11:00:00Z"),
+                Some(relative_to()),
+                Language::default()
+            ),
+            Time::Relative(Relative::next_hour())
+        );
+    }
+
+    #[test]
+    fn past_periods_end_where_current_ones_start() {
+        let from = |s| Time::from_max_chrono(on(s), Some(relative_to()), Language::default());
+
+        assert_eq!(
+            from("2025-07-28T00:00:00Z"),
+            Time::Relative(Relative::last_week())
+        );
+        assert_eq!(
+            from("2025-07-01T00:00:00Z"),
+            Time::Relative(Relative::last_month())
+        );
+        assert_eq!(
+            from("2025-01-01T00:00:00Z"),
+            Time::Relative(Relative::last_year())
+        );
+    }
+
+    #[test]
+    fn keywords_follow_order() {
+        let keywords: Vec<_> =
+            Candidate::keywords(&[Candidate::Year, Candidate::Day], Language::default()).collect();
+
+        assert_eq!(keywords.len(), 5);
+        assert_eq!(keywords[0], Time::Relative(Relative::this_year()));
+        assert_eq!(keywords[2], Time::Relative(Relative::today()));
+    }
+}
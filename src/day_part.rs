@@ -0,0 +1,438 @@
+//! Parts of a day, such as "tomorrow morning" or "tonight".
+
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+#[cfg(feature = "schemars")]
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use chrono::{DateTime, NaiveDate, Utc};
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{
+    language::Language,
+    macros::fold,
+    options::{DayParts, Options},
+    parse::ParseError,
+    traits::WithLanguage,
+};
+
+/// A part of a day, with hours set by [`DayParts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+}
+
+impl Part {
+    pub(crate) const ALL: [Part; 4] = [Part::Morning, Part::Afternoon, Part::Evening, Part::Night];
+}
+
+/// The day a [`DayPart`] falls on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Day {
+    Today,
+    Tomorrow,
+    Yesterday,
+}
+
+impl Day {
+    const ALL: [Day; 3] = [Day::Today, Day::Tomorrow, Day::Yesterday];
+
+    /// The date of the day, relative to the given date.
+    fn date(self, today: NaiveDate) -> NaiveDate {
+        match self {
+            Day::Today => today,
+            Day::Tomorrow => today.succ_opt().unwrap(),
+            Day::Yesterday => today.pred_opt().unwrap(),
+        }
+    }
+}
+
+/// The name of a part of the day in the given language, e.g. "TomorrowMorning" or "Ikväll".
+fn name(part: Part, day: Option<Day>, language: Language) -> &'static str {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => match (day, part) {
+            (None, Part::Morning) => "Morgon",
+            (None, Part::Afternoon) => "Eftermiddag",
+            (None, Part::Evening) => "Kväll",
+            (None, Part::Night) => "Natt",
+            (Some(Day::Today), Part::Morning) => "Imorse",
+            (Some(Day::Today), Part::Afternoon) => "IEftermiddag",
+            (Some(Day::Today), Part::Evening) => "Ikväll",
+            (Some(Day::Today), Part::Night) => "Inatt",
+            (Some(Day::Tomorrow), Part::Morning) => "ImorgonBitti",
+            (Some(Day::Tomorrow), Part::Afternoon) => "ImorgonEftermiddag",
+            (Some(Day::Tomorrow), Part::Evening) => "ImorgonKväll",
+            (Some(Day::Tomorrow), Part::Night) => "ImorgonNatt",
+            (Some(Day::Yesterday), Part::Morning) => "IgårMorse",
+            (Some(Day::Yesterday), Part::Afternoon) => "IgårEftermiddag",
+            (Some(Day::Yesterday), Part::Evening) => "IgårKväll",
+            (Some(Day::Yesterday), Part::Night) => "IgårNatt",
+        },
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => match (day, part) {
+            (None, Part::Morning) => "Morgen",
+            (None, Part::Afternoon) => "Ettermiddag",
+            (None, Part::Evening) => "Kveld",
+            (None, Part::Night) => "Natt",
+            (Some(Day::Today), Part::Morning) => "IMorges",
+            (Some(Day::Today), Part::Afternoon) => "IEttermiddag",
+            (Some(Day::Today), Part::Evening) => "IKveld",
+            (Some(Day::Today), Part::Night) => "INatt",
+            (Some(Day::Tomorrow), Part::Morning) => "IMorgenTidlig",
+            (Some(Day::Tomorrow), Part::Afternoon) => "IMorgenEttermiddag",
+            (Some(Day::Tomorrow), Part::Evening) => "IMorgenKveld",
+            (Some(Day::Tomorrow), Part::Night) => "IMorgenNatt",
+            (Some(Day::Yesterday), Part::Morning) => "IGårMorges",
+            (Some(Day::Yesterday), Part::Afternoon) => "IGårEttermiddag",
+            (Some(Day::Yesterday), Part::Evening) => "IGårKveld",
+            (Some(Day::Yesterday), Part::Night) => "IGårNatt",
+        },
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => match (day, part) {
+            (None, Part::Morning) => "Morgen",
+            (None, Part::Afternoon) => "Eftermiddag",
+            (None, Part::Evening) => "Aften",
+            (None, Part::Night) => "Nat",
+            (Some(Day::Today), Part::Morning) => "IMorges",
+            (Some(Day::Today), Part::Afternoon) => "IEftermiddag",
+            (Some(Day::Today), Part::Evening) => "IAften",
+            (Some(Day::Today), Part::Night) => "INat",
+            (Some(Day::Tomorrow), Part::Morning) => "IMorgenTidlig",
+            (Some(Day::Tomorrow), Part::Afternoon) => "IMorgenEftermiddag",
+            (Some(Day::Tomorrow), Part::Evening) => "IMorgenAften",
+            (Some(Day::Tomorrow), Part::Night) => "IMorgenNat",
+            (Some(Day::Yesterday), Part::Morning) => "IGårMorges",
+            (Some(Day::Yesterday), Part::Afternoon) => "IGårEftermiddag",
+            (Some(Day::Yesterday), Part::Evening) => "IGårAftes",
+            (Some(Day::Yesterday), Part::Night) => "IGårNat",
+        },
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => match (day, part) {
+            (None, Part::Morning) => "PorLaMañana",
+            (None, Part::Afternoon) => "PorLaTarde",
+            (None, Part::Evening) => "PorLaNoche",
+            (None, Part::Night) => "DeMadrugada",
+            (Some(Day::Today), Part::Morning) => "EstaMañana",
+            (Some(Day::Today), Part::Afternoon) => "EstaTarde",
+            (Some(Day::Today), Part::Evening) => "EstaNoche",
+            (Some(Day::Today), Part::Night) => "EstaMadrugada",
+            (Some(Day::Tomorrow), Part::Morning) => "MañanaPorLaMañana",
+            (Some(Day::Tomorrow), Part::Afternoon) => "MañanaPorLaTarde",
+            (Some(Day::Tomorrow), Part::Evening) => "MañanaPorLaNoche",
+            (Some(Day::Tomorrow), Part::Night) => "MañanaDeMadrugada",
+            (Some(Day::Yesterday), Part::Morning) => "AyerPorLaMañana",
+            (Some(Day::Yesterday), Part::Afternoon) => "AyerPorLaTarde",
+            (Some(Day::Yesterday), Part::Evening) => "Anoche",
+            (Some(Day::Yesterday), Part::Night) => "AyerDeMadrugada",
+        },
+        #[cfg(feature = "french")]
+        Language::French(_) => match (day, part) {
+            (None, Part::Morning) => "Matin",
+            (None, Part::Afternoon) => "AprèsMidi",
+            (None, Part::Evening) => "Soir",
+            (None, Part::Night) => "Nuit",
+            (Some(Day::Today), Part::Morning) => "CeMatin",
+            (Some(Day::Today), Part::Afternoon) => "CetAprèsMidi",
+            (Some(Day::Today), Part::Evening) => "CeSoir",
+            (Some(Day::Today), Part::Night) => "CetteNuit",
+            (Some(Day::Tomorrow), Part::Morning) => "DemainMatin",
+            (Some(Day::Tomorrow), Part::Afternoon) => "DemainAprèsMidi",
+            (Some(Day::Tomorrow), Part::Evening) => "DemainSoir",
+            (Some(Day::Tomorrow), Part::Night) => "DemainNuit",
+            (Some(Day::Yesterday), Part::Morning) => "HierMatin",
+            (Some(Day::Yesterday), Part::Afternoon) => "HierAprèsMidi",
+            (Some(Day::Yesterday), Part::Evening) => "HierSoir",
+            (Some(Day::Yesterday), Part::Night) => "NuitDernière",
+        },
+        Language::English(_) => match (day, part) {
+            (None, Part::Morning) => "Morning",
+            (None, Part::Afternoon) => "Afternoon",
+            (None, Part::Evening) => "Evening",
+            (None, Part::Night) => "Night",
+            (Some(Day::Today), Part::Morning) => "ThisMorning",
+            (Some(Day::Today), Part::Afternoon) => "ThisAfternoon",
+            (Some(Day::Today), Part::Evening) => "ThisEvening",
+            (Some(Day::Today), Part::Night) => "Tonight",
+            (Some(Day::Tomorrow), Part::Morning) => "TomorrowMorning",
+            (Some(Day::Tomorrow), Part::Afternoon) => "TomorrowAfternoon",
+            (Some(Day::Tomorrow), Part::Evening) => "TomorrowEvening",
+            (Some(Day::Tomorrow), Part::Night) => "TomorrowNight",
+            (Some(Day::Yesterday), Part::Morning) => "YesterdayMorning",
+            (Some(Day::Yesterday), Part::Afternoon) => "YesterdayAfternoon",
+            (Some(Day::Yesterday), Part::Evening) => "YesterdayEvening",
+            (Some(Day::Yesterday), Part::Night) => "LastNight",
+        },
+    }
+}
+
+/// A part of a day, optionally on a given day, e.g. `"Morning"`, `"TomorrowEvening"` or
+/// `"Tonight"`.
+///
+/// Without a day, resolves to the current part when the reference time falls within it, and the
+/// next one otherwise. Serialises as a single word in the language it was written in, and parses
+/// ignoring case, whitespace and accents, so `"tomorrow morning"` is accepted too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DayPart {
+    part: Part,
+    day: Option<Day>,
+    language: Language,
+}
+
+impl DayPart {
+    pub fn new(part: Part, day: Option<Day>) -> Self {
+        Self {
+            part,
+            day,
+            language: Language::default(),
+        }
+    }
+
+    /// The night of the current day.
+    pub fn tonight() -> Self {
+        Self::new(Part::Night, Some(Day::Today))
+    }
+
+    pub fn part(&self) -> Part {
+        self.part
+    }
+
+    pub fn day(&self) -> Option<Day> {
+        self.day
+    }
+
+    /// Every part of every day, and without a day, in the given language.
+    pub(crate) fn all(language: Language) -> impl Iterator<Item = Self> {
+        [None]
+            .into_iter()
+            .chain(Day::ALL.map(Some))
+            .flat_map(move |day| {
+                Part::ALL.map(|part| Self {
+                    part,
+                    day,
+                    language,
+                })
+            })
+    }
+
+    /// Parses a part of a day written in any of the languages, e.g. `"tomorrowmorning"`.
+    pub(crate) fn parse_in(input: &str, languages: &[Language]) -> Option<Self> {
+        let input = fold(input);
+
+        languages.iter().copied().find_map(|language| {
+            Self::all(language).find(|candidate| fold(&candidate.to_string()) == input)
+        })
+    }
+
+    /// Converts to the start of the part, relative to the given time and following the given
+    /// conventions.
+    pub fn to_chrono_min_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
+        self.window(relative_to, options.day_parts).0
+    }
+
+    /// Converts to the end of the part, relative to the given time and following the given
+    /// conventions.
+    pub fn to_chrono_max_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
+        self.window(relative_to, options.day_parts).1
+    }
+
+    /// The start and end of the part, on its day or the current or next one.
+    fn window(self, relative_to: DateTime<Utc>, hours: DayParts) -> (DateTime<Utc>, DateTime<Utc>) {
+        let at = |date: NaiveDate, hour: u8| date.and_hms_opt(hour.into(), 0, 0).unwrap().and_utc();
+
+        let on = |date: NaiveDate| {
+            let end_date = match self.part {
+                Part::Night => date.succ_opt().unwrap(),
+                _ => date,
+            };
+
+            (
+                at(date, hours.start(self.part)),
+                at(end_date, hours.end(self.part)),
+            )
+        };
+
+        let today = relative_to.date_naive();
+
+        match self.day {
+            Some(day) => on(day.date(today)),
+            None => [Day::Yesterday, Day::Today, Day::Tomorrow]
+                .into_iter()
+                .map(|day| on(day.date(today)))
+                .find(|(_, end)| *end > relative_to)
+                .unwrap(),
+        }
+    }
+}
+
+impl WithLanguage for DayPart {
+    fn with_language(&self, language: Language) -> Self {
+        Self { language, ..*self }
+    }
+}
+
+impl fmt::Display for DayPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(name(self.part, self.day, self.language))
+    }
+}
+
+impl FromStr for DayPart {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_in(s, &Language::all()).ok_or_else(|| ParseError::Unrecognised(s.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for DayPart {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DayPart {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for DayPart {
+    fn schema_name() -> Cow<'static, str> {
+        "DayPart".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let names: Vec<String> = Language::all()
+            .into_iter()
+            .flat_map(Self::all)
+            .map(|day_part| day_part.to_string())
+            .collect();
+
+        json_schema!({
+            "description": "A part of a day, optionally on a given day, e.g. \"TomorrowMorning\" or \"Tonight\".",
+            "type": "string",
+            "enum": names,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn resolves_windows() {
+        // Tuesday July 29th, 2025
+        let relative_to = at("2025-07-29T10:30:05Z");
+        let window = |part, day| {
+            let day_part = DayPart::new(part, day);
+            let options = Options::default();
+
+            (
+                day_part.to_chrono_min_with(relative_to, options),
+                day_part.to_chrono_max_with(relative_to, options),
+            )
+        };
+
+        assert_eq!(
+            window(Part::Morning, None),
+            (at("2025-07-29T06:00:00Z"), at("2025-07-29T12:00:00Z"))
+        );
+        assert_eq!(
+            window(Part::Morning, Some(Day::Tomorrow)),
+            (at("2025-07-30T06:00:00Z"), at("2025-07-30T12:00:00Z"))
+        );
+        assert_eq!(
+            window(Part::Night, Some(Day::Today)),
+            (at("2025-07-29T22:00:00Z"), at("2025-07-30T06:00:00Z"))
+        );
+        assert_eq!(
+            window(Part::Evening, Some(Day::Yesterday)),
+            (at("2025-07-28T18:00:00Z"), at("2025-07-28T22:00:00Z"))
+        );
+
+        // Before dawn, the night that started yesterday is still ongoing
+        let night = DayPart::new(Part::Night, None);
+        let before_dawn = at("2025-07-29T02:00:00Z");
+        assert_eq!(
+            night.to_chrono_min_with(before_dawn, Options::default()),
+            at("2025-07-28T22:00:00Z")
+        );
+    }
+
+    #[test]
+    fn uses_configured_hours() {
+        let options = Options::default().with_day_parts(DayParts {
+            morning: 5,
+            afternoon: 11,
+            ..DayParts::default()
+        });
+        let relative_to = at("2025-07-29T11:30:00Z");
+        let morning = DayPart::new(Part::Morning, None);
+
+        assert_eq!(
+            morning.to_chrono_min_with(relative_to, options),
+            at("2025-07-30T05:00:00Z")
+        );
+        assert_eq!(
+            DayPart::tonight().to_chrono_max_with(relative_to, options),
+            at("2025-07-30T05:00:00Z")
+        );
+    }
+
+    #[test]
+    fn formats_and_parses() {
+        let tomorrow_morning = DayPart::new(Part::Morning, Some(Day::Tomorrow));
+
+        assert_eq!(tomorrow_morning.to_string(), "TomorrowMorning");
+        assert_eq!(DayPart::tonight().to_string(), "Tonight");
+        assert_eq!("tomorrow morning".parse(), Ok(tomorrow_morning));
+        assert_eq!("tonight".parse(), Ok(DayPart::tonight()));
+        assert_eq!(
+            "last night".parse(),
+            Ok(DayPart::new(Part::Night, Some(Day::Yesterday)))
+        );
+        assert!("tomorrow brunch".parse::<DayPart>().is_err());
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::to_string(&tomorrow_morning).unwrap(),
+            r#""TomorrowMorning""#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn formats_in_swedish() {
+        use crate::language::Swedish;
+
+        let swedish = Language::Swedish(Swedish::default());
+        let this_evening: DayPart = "i kväll".parse().unwrap();
+
+        assert_eq!(this_evening.part(), Part::Evening);
+        assert_eq!(this_evening.day(), Some(Day::Today));
+        assert_eq!(this_evening.to_string(), "Ikväll");
+        assert_eq!(
+            DayPart::new(Part::Morning, Some(Day::Tomorrow))
+                .with_language(swedish)
+                .to_string(),
+            "ImorgonBitti"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Deadlines built on top of time resolution.
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Time, duration::Duration};
+
+/// A time something is due, with an optional grace period before it counts as overdue.
+///
+/// Serialises as `{"due": "Friday", "grace": "PT1H"}`, with the grace period omitted when
+/// there is none. The grace period may also be given in natural language, e.g. `"1 hour"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Deadline {
+    pub due: Time,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub grace: Option<Duration>,
+}
+
+impl Deadline {
+    pub fn new(due: Time) -> Self {
+        Self { due, grace: None }
+    }
+
+    pub fn with_grace(self, grace: Duration) -> Self {
+        Self {
+            grace: Some(grace),
+            ..self
+        }
+    }
+
+    /// The instant the deadline passes: the latest timestamp of `due` plus the grace period.
+    ///
+    /// `relative_to` is when the deadline was set, so that e.g. `"Friday"` keeps meaning the
+    /// same Friday as time goes by.
+    pub fn to_chrono(&self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.due
+            .clone()
+            .to_chrono_max(relative_to)
+            .checked_add_signed(self.grace.unwrap_or_default().to_chrono())
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// Whether the deadline has passed at `at`, for a deadline set at `relative_to`.
+    pub fn is_overdue(&self, at: DateTime<Utc>, relative_to: DateTime<Utc>) -> bool {
+        at >= self.to_chrono(relative_to)
+    }
+
+    /// Time left until the deadline passes at `at`, negative once overdue.
+    pub fn time_remaining(
+        &self,
+        at: DateTime<Utc>,
+        relative_to: DateTime<Utc>,
+    ) -> chrono::Duration {
+        self.to_chrono(relative_to) - at
+    }
+}
+
+impl From<Time> for Deadline {
+    fn from(value: Time) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weekday::Weekday;
+    use chrono::Duration as ChronoDuration;
+
+    /// Tuesday July 29th, 2025 at 10:30:05.
+    fn base_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc()
+    }
+
+    #[test]
+    fn overdue_after_due() {
+        let set = base_time();
+        let deadline = Deadline::new(Time::Weekday(Weekday::friday()));
+
+        // Midnight after Friday August 1st
+        let end = DateTime::parse_from_rfc3339("2025-08-02T00:00:00Z")
+            .unwrap()
+            .to_utc();
+
+        assert!(!deadline.is_overdue(end - ChronoDuration::seconds(1), set));
+        assert!(deadline.is_overdue(end, set));
+        assert_eq!(deadline.time_remaining(set, set), end - set);
+    }
+
+    #[test]
+    fn grace_extends_deadline() {
+        let set = base_time();
+        let deadline = Deadline::new(Time::DateTime(set)).with_grace(Duration::from_hours(1));
+
+        assert!(!deadline.is_overdue(set + ChronoDuration::minutes(30), set));
+        assert!(deadline.is_overdue(set + ChronoDuration::hours(1), set));
+        assert_eq!(
+            deadline.time_remaining(set + ChronoDuration::hours(2), set),
+            -ChronoDuration::hours(1)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialises_grace_as_iso8601() {
+        let deadline =
+            Deadline::new(Time::Weekday(Weekday::friday())).with_grace(Duration::from_hours(1));
+        let json = serde_json::to_string(&deadline).unwrap();
+
+        assert_eq!(json, r#"{"due":"Friday","grace":"PT1H"}"#);
+        assert_eq!(serde_json::from_str::<Deadline>(&json).unwrap(), deadline);
+        assert_eq!(
+            serde_json::from_str::<Deadline>(r#"{"due":"Friday","grace":"1 hour"}"#).unwrap(),
+            deadline
+        );
+        assert_eq!(
+            serde_json::from_str::<Deadline>(r#"{"due":"Friday"}"#).unwrap(),
+            Deadline::new(Time::Weekday(Weekday::friday()))
+        );
+    }
+}
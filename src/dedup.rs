@@ -0,0 +1,154 @@
+//! Removing values that resolve to the same window.
+
+use alloc::vec::Vec;
+use chrono::{DateTime, Days, Utc};
+
+use crate::{Time, relative::Relative};
+
+/// The earliest and latest timestamps a value resolves to
+type Window = (DateTime<Utc>, DateTime<Utc>);
+
+/// Which of several values resolving to the same window [`dedup_semantic`] keeps.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DedupPolicy {
+    /// Keep the value appearing first.
+    #[default]
+    First,
+    /// Prefer keywords, weekdays and months, e.g. `Tomorrow` over `30/7/2025`.
+    Named,
+    /// Prefer dates, times and timestamps, e.g. `30/7/2025` over `Tomorrow`.
+    Exact,
+}
+
+impl DedupPolicy {
+    /// Whether `candidate` should replace `kept`.
+    fn prefers(self, candidate: &Time, kept: &Time) -> bool {
+        match self {
+            DedupPolicy::First => false,
+            DedupPolicy::Named => is_named(candidate) && !is_named(kept),
+            DedupPolicy::Exact => !is_named(candidate) && is_named(kept),
+        }
+    }
+}
+
+/// Removes values whose resolved windows are identical, e.g. `Tomorrow` and the matching date.
+///
+/// Dates cover their whole day, so `30/7/2025` and `Tomorrow` on July 29th are the same window.
+/// Each group of duplicates keeps the position of its first value, holding the representation
+/// chosen by `policy`.
+pub fn dedup_semantic(times: &mut Vec<Time>, relative_to: DateTime<Utc>, policy: DedupPolicy) {
+    let mut kept: Vec<(Window, Time)> = Vec::with_capacity(times.len());
+
+    for time in times.drain(..) {
+        let window = window(&time, relative_to);
+
+        match kept.iter_mut().find(|(x, _)| *x == window) {
+            Some((_, existing)) if policy.prefers(&time, existing) => *existing = time,
+            Some(_) => {}
+            None => kept.push((window, time)),
+        }
+    }
+
+    times.extend(kept.into_iter().map(|(_, time)| time));
+}
+
+/// The earliest and latest timestamps of the value, with dates spanning until the next midnight.
+fn window(time: &Time, relative_to: DateTime<Utc>) -> Window {
+    match time {
+        Time::Relative(Relative::Date(_)) => {
+            let start = time.clone().to_chrono_max(relative_to);
+            (start, start.checked_add_days(Days::new(1)).unwrap())
+        }
+        time => (
+            time.clone().to_chrono_min(relative_to),
+            time.clone().to_chrono_max(relative_to),
+        ),
+    }
+}
+
+/// Whether the value is spelled with a keyword rather than numbers.
+fn is_named(time: &Time) -> bool {
+    match time {
+        Time::Relative(
+            Relative::Time(_)
+            | Relative::Date(_)
+            | Relative::DateTime(_)
+            | Relative::Week(_)
+            | Relative::Quarter(_),
+        ) => false,
+        Time::Relative(_)
+        | Time::Weekday(_)
+        | Time::WeekdayTime(_)
+        | Time::Month(_)
+        | Time::Named(_)
+        | Time::OrdinalWeekday(_)
+        | Time::LastWeekday(_) => true,
+        Time::Exact(_) | Time::DateTime(_) => false,
+        Time::Boundary(boundary) => is_named(boundary.time()),
+        Time::At(at) => is_named(at.day()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::{exact::ExactDate, weekday::Weekday};
+
+    /// Tuesday July 29th, 2025 at 10:30:05.
+    fn base_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc()
+    }
+
+    fn values() -> Vec<Time> {
+        vec![
+            Time::Relative(Relative::Date(ExactDate::new(Some(2025), 7, 30))),
+            Time::Relative(Relative::today()),
+            Time::Relative(Relative::tomorrow()),
+            Time::Weekday(Weekday::wednesday()),
+        ]
+    }
+
+    #[test]
+    fn keeps_first_by_default() {
+        let mut times = values();
+        dedup_semantic(&mut times, base_time(), DedupPolicy::default());
+
+        assert_eq!(
+            times,
+            vec![
+                Time::Relative(Relative::Date(ExactDate::new(Some(2025), 7, 30))),
+                Time::Relative(Relative::today()),
+            ]
+        );
+    }
+
+    #[test]
+    fn prefers_named_or_exact() {
+        let mut named = values();
+        dedup_semantic(&mut named, base_time(), DedupPolicy::Named);
+
+        assert_eq!(
+            named,
+            vec![
+                Time::Relative(Relative::tomorrow()),
+                Time::Relative(Relative::today()),
+            ]
+        );
+
+        let mut exact = values();
+        exact.reverse();
+        dedup_semantic(&mut exact, base_time(), DedupPolicy::Exact);
+
+        assert_eq!(
+            exact,
+            vec![
+                Time::Relative(Relative::Date(ExactDate::new(Some(2025), 7, 30))),
+                Time::Relative(Relative::today()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,427 @@
+//! Lengths of time in natural language and ISO 8601 forms.
+
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, ops::Add, str::FromStr};
+
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{language::Language, macros::fold, parse::ParseError};
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+const WEEK: u64 = 7 * DAY;
+
+/// Seconds in each unit [`unit_names`] names, in the same order.
+const UNITS: [u64; 5] = [1, MINUTE, HOUR, DAY, WEEK];
+
+/// A non-negative length of time with second precision.
+///
+/// Parses natural language in any enabled language (`"2 hours 30 minutes"`, `"90 min"`,
+/// `"3 dagar"`) as well as ISO 8601 durations (`"PT2H30M"`, `"P3D"`). Serialises as ISO 8601
+/// and displays as natural English. Years and months vary in length and are not supported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration(u64);
+
+impl Duration {
+    pub fn from_secs(seconds: u64) -> Self {
+        Self(seconds)
+    }
+
+    pub fn from_mins(minutes: u64) -> Self {
+        Self(minutes * MINUTE)
+    }
+
+    pub fn from_hours(hours: u64) -> Self {
+        Self(hours * HOUR)
+    }
+
+    pub fn from_days(days: u64) -> Self {
+        Self(days * DAY)
+    }
+
+    pub fn as_secs(&self) -> u64 {
+        self.0
+    }
+
+    /// Converts to a chrono duration, saturating at the longest one chrono represents.
+    pub fn to_chrono(&self) -> chrono::Duration {
+        i64::try_from(self.0)
+            .ok()
+            .and_then(chrono::Duration::try_seconds)
+            .unwrap_or(chrono::Duration::MAX)
+    }
+
+    /// Parses an ISO 8601 duration such as `"P3D"`, `"PT2H30M"` or `"P1W"`.
+    pub fn parse_iso8601(input: &str) -> Result<Self, ParseError> {
+        let unrecognised = || ParseError::Unrecognised(input.to_string());
+
+        let uppercase = input.to_uppercase();
+        let rest = uppercase
+            .strip_prefix('P')
+            .filter(|rest| !rest.is_empty())
+            .ok_or_else(unrecognised)?;
+        let (date, time) = match rest.split_once('T') {
+            Some((_, "")) => return Err(unrecognised()),
+            Some((date, time)) => (date, time),
+            None => (rest, ""),
+        };
+
+        let date = iso8601_part(date, &[('W', WEEK), ('D', DAY)]);
+        let time = iso8601_part(time, &[('H', HOUR), ('M', MINUTE), ('S', 1)]);
+
+        date.zip(time)
+            .and_then(|(date, time)| date.checked_add(time))
+            .map(Self)
+            .ok_or_else(unrecognised)
+    }
+
+    /// Formats as an ISO 8601 duration, e.g. `"P1DT2H30M"`.
+    pub fn to_iso8601(&self) -> String {
+        let (days, hours, minutes, seconds) = self.parts();
+
+        let mut iso = "P".to_string();
+
+        if days > 0 {
+            iso.push_str(&format!("{days}D"));
+        }
+
+        if hours > 0 || minutes > 0 || seconds > 0 || days == 0 {
+            iso.push('T');
+
+            if hours > 0 {
+                iso.push_str(&format!("{hours}H"));
+            }
+            if minutes > 0 {
+                iso.push_str(&format!("{minutes}M"));
+            }
+            if seconds > 0 || (hours == 0 && minutes == 0) {
+                iso.push_str(&format!("{seconds}S"));
+            }
+        }
+
+        iso
+    }
+
+    /// Parses natural language such as `"2 hours 30 minutes"`, `"1h30m"` or `"3 dagar"`.
+    pub fn parse_natural(input: &str) -> Result<Self, ParseError> {
+        let unrecognised = || ParseError::Unrecognised(input.to_string());
+
+        let languages = Language::all();
+        let lowercase = input.to_lowercase().replace(',', " ");
+        let mut tokens = lowercase
+            .split_whitespace()
+            .filter(|token| {
+                !languages
+                    .iter()
+                    .any(|language| conjunction(*language) == *token)
+            })
+            .peekable();
+
+        if tokens.peek().is_none() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut seconds: u64 = 0;
+
+        while let Some(mut token) = tokens.next() {
+            // Compact forms like "1h30m" hold several amounts in one token
+            while !token.is_empty() {
+                let (amount, rest) = token.split_at(
+                    token
+                        .find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(token.len()),
+                );
+                let (unit, rest) = match rest.find(|c: char| c.is_ascii_digit()) {
+                    Some(index) => rest.split_at(index),
+                    None if rest.is_empty() => (tokens.next().ok_or_else(unrecognised)?, ""),
+                    None => (rest, ""),
+                };
+
+                let amount: u64 = amount.parse().map_err(|_| unrecognised())?;
+                let unit = natural_unit(unit, &languages).ok_or_else(unrecognised)?;
+
+                seconds = amount
+                    .checked_mul(unit)
+                    .and_then(|amount| seconds.checked_add(amount))
+                    .ok_or_else(unrecognised)?;
+                token = rest;
+            }
+        }
+
+        Ok(Self(seconds))
+    }
+
+    /// Days, hours, minutes and seconds, with weeks counted as days.
+    fn parts(&self) -> (u64, u64, u64, u64) {
+        (
+            self.0 / DAY,
+            self.0 % DAY / HOUR,
+            self.0 % HOUR / MINUTE,
+            self.0 % MINUTE,
+        )
+    }
+}
+
+/// Seconds in the date or time part of an ISO 8601 duration, with designators in order, or
+/// `None` when malformed or too long to count.
+fn iso8601_part(mut part: &str, designators: &[(char, u64)]) -> Option<u64> {
+    let mut seconds: u64 = 0;
+
+    for (designator, unit) in designators {
+        if let Some((amount, rest)) = part.split_once(*designator) {
+            seconds = seconds.checked_add(amount.parse::<u64>().ok()?.checked_mul(*unit)?)?;
+            part = rest;
+        }
+    }
+
+    part.is_empty().then_some(seconds)
+}
+
+/// Seconds in a unit of natural language, in any of the languages.
+fn natural_unit(unit: &str, languages: &[Language]) -> Option<u64> {
+    let unit = fold(unit);
+
+    languages.iter().find_map(|language| {
+        unit_names(*language)
+            .into_iter()
+            .zip(UNITS)
+            .find_map(|(names, seconds)| names.contains(&unit.as_str()).then_some(seconds))
+    })
+}
+
+/// The names of seconds, minutes, hours, days and weeks in the given language, without accents.
+fn unit_names(language: Language) -> [&'static [&'static str]; 5] {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => [
+            &["s", "sek", "sekund", "sekunder"],
+            &["m", "min", "minut", "minuter"],
+            &["t", "tim", "timme", "timmar"],
+            &["d", "dag", "dagar"],
+            &["v", "vecka", "veckor"],
+        ],
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => [
+            &["s", "sek", "sekund", "sekunder"],
+            &["m", "min", "minutt", "minutter"],
+            &["t", "time", "timer"],
+            &["d", "dag", "dager"],
+            &["u", "uke", "uker"],
+        ],
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => [
+            &["s", "sek", "sekund", "sekunder"],
+            &["m", "min", "minut", "minutter"],
+            &["t", "time", "timer"],
+            &["d", "dag", "dage"],
+            &["u", "uge", "uger"],
+        ],
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => [
+            &["s", "seg", "segundo", "segundos"],
+            &["m", "min", "minuto", "minutos"],
+            &["h", "hora", "horas"],
+            &["d", "dia", "dias"],
+            &["sem", "semana", "semanas"],
+        ],
+        #[cfg(feature = "french")]
+        Language::French(_) => [
+            &["s", "sec", "seconde", "secondes"],
+            &["m", "min", "minute", "minutes"],
+            &["h", "heure", "heures"],
+            &["j", "jour", "jours"],
+            &["sem", "semaine", "semaines"],
+        ],
+        Language::English(_) => [
+            &["s", "sec", "secs", "second", "seconds"],
+            &["m", "min", "mins", "minute", "minutes"],
+            &["h", "hr", "hrs", "hour", "hours"],
+            &["d", "day", "days"],
+            &["w", "wk", "week", "weeks"],
+        ],
+    }
+}
+
+/// The word joining amounts in the given language, as in "2 hours and 30 minutes".
+fn conjunction(language: Language) -> &'static str {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => "och",
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => "og",
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => "og",
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => "y",
+        #[cfg(feature = "french")]
+        Language::French(_) => "et",
+        Language::English(_) => "and",
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (days, hours, minutes, seconds) = self.parts();
+
+        let parts: Vec<String> = [
+            (days, "day"),
+            (hours, "hour"),
+            (minutes, "minute"),
+            (seconds, "second"),
+        ]
+        .into_iter()
+        .filter(|(amount, _)| *amount > 0)
+        .map(|(amount, unit)| match amount {
+            1 => format!("1 {unit}"),
+            amount => format!("{amount} {unit}s"),
+        })
+        .collect();
+
+        match parts.is_empty() {
+            true => f.write_str("0 seconds"),
+            false => f.write_str(&parts.join(" ")),
+        }
+    }
+}
+
+impl FromStr for Duration {
+    type Err = ParseError;
+
+    /// Parses an ISO 8601 duration, falling back to natural language.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Self::parse_iso8601(s).or_else(|_| Self::parse_natural(s))
+    }
+}
+
+impl Add for Duration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl From<Duration> for chrono::Duration {
+    fn from(value: Duration) -> Self {
+        value.to_chrono()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Duration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_iso8601())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Duration {
+    fn schema_name() -> Cow<'static, str> {
+        "Duration".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A length of time, as an ISO 8601 duration like \"PT2H30M\" or natural language like \"2 hours 30 minutes\".",
+            "type": "string",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_iso8601() {
+        assert_eq!("P3D".parse(), Ok(Duration::from_days(3)));
+        assert_eq!(
+            "PT2H30M".parse(),
+            Ok(Duration::from_hours(2) + Duration::from_mins(30))
+        );
+        assert_eq!("P1W".parse(), Ok(Duration::from_days(7)));
+        assert_eq!("PT45S".parse(), Ok(Duration::from_secs(45)));
+        assert!(Duration::parse_iso8601("P1M").is_err());
+        assert!(Duration::parse_iso8601("P").is_err());
+        assert!(Duration::parse_iso8601("PT").is_err());
+        assert!(Duration::parse_iso8601("P99999999999999999W").is_err());
+        assert!(Duration::parse_iso8601("P1WT18446744073709551615S").is_err());
+    }
+
+    #[test]
+    fn parses_natural_language() {
+        let expected = Ok(Duration::from_hours(2) + Duration::from_mins(30));
+
+        assert_eq!("2 hours 30 minutes".parse(), expected);
+        assert_eq!("2h30m".parse(), expected);
+        #[cfg(feature = "swedish")]
+        {
+            assert_eq!("2 timmar och 30 minuter".parse(), expected);
+            assert_eq!("3 dagar".parse(), Ok(Duration::from_days(3)));
+        }
+        assert!("soon".parse::<Duration>().is_err());
+        assert!("99999999999999999 weeks".parse::<Duration>().is_err());
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "norwegian",
+        feature = "danish",
+        feature = "spanish",
+        feature = "french"
+    ))]
+    fn parses_every_language() {
+        let days = Ok(Duration::from_days(3));
+
+        assert_eq!("3 dager".parse(), days);
+        assert_eq!("3 dage".parse(), days);
+        assert_eq!("3 días".parse(), days);
+        assert_eq!("3 jours".parse(), days);
+        assert_eq!(
+            "2 heures et 30 minutes".parse(),
+            Ok(Duration::from_hours(2) + Duration::from_mins(30))
+        );
+    }
+
+    #[test]
+    fn saturates_chrono_conversion() {
+        assert_eq!(
+            Duration::from_hours(2).to_chrono(),
+            chrono::Duration::hours(2)
+        );
+        assert_eq!(
+            "P9999999999999W".parse::<Duration>().unwrap().to_chrono(),
+            chrono::Duration::MAX
+        );
+    }
+
+    #[test]
+    fn formats_both_forms() {
+        let duration = Duration::from_days(1) + Duration::from_hours(2) + Duration::from_mins(30);
+
+        assert_eq!(duration.to_iso8601(), "P1DT2H30M");
+        assert_eq!(duration.to_string(), "1 day 2 hours 30 minutes");
+        assert_eq!(Duration::default().to_iso8601(), "PT0S");
+        assert_eq!(Duration::from_days(3).to_iso8601(), "P3D");
+    }
+}
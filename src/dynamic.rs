@@ -0,0 +1,276 @@
+//! Languages defined at runtime, such as those loaded from a tenant's locale file.
+//!
+//! The built-in languages are chosen at compile time with features. A [`DynamicLanguage`]
+//! instead maps the English spelling of weekdays, months and relative keywords to its own, and
+//! can be deserialised from any serde format:
+//!
+//! ```json
+//! { "name": "Deutsch", "keywords": { "Monday": "Montag", "NextWeek": "Nächste Woche" } }
+//! ```
+//!
+//! Values parsed in a dynamic language are the built-in keywords in English, so they resolve,
+//! serialise and compare like any other. [`DynamicLanguage::spell`] writes them back out.
+//!
+//! Languages are registered with [`register`], after which [`parse`](crate::parse::parse) falls
+//! back to them. The registry needs the `std` feature. Without it nothing is registered.
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+use derive_more::Display;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Time,
+    language::Language,
+    macros::fold,
+    parse::{ParseError, parse_in, vocabulary},
+    traits::WithLanguage,
+};
+
+#[cfg(feature = "std")]
+static REGISTRY: RwLock<Vec<Arc<DynamicLanguage>>> = RwLock::new(Vec::new());
+
+/// Errors produced when registering a dynamic language.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
+pub enum DynamicLanguageError {
+    #[display("'{_0}' is not a weekday, month or relative keyword")]
+    UnknownKeyword(String),
+}
+
+impl core::error::Error for DynamicLanguageError {}
+
+/// A language defined at runtime, spelling keywords by their English names.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct DynamicLanguage {
+    name: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    keywords: BTreeMap<String, String>,
+}
+
+impl DynamicLanguage {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            keywords: BTreeMap::new(),
+        }
+    }
+
+    /// Spells the keyword with the given English name, e.g. `"Monday"` as `"Montag"`.
+    pub fn with_keyword(mut self, keyword: impl Into<String>, spelling: impl Into<String>) -> Self {
+        self.keywords.insert(keyword.into(), spelling.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Checks that every spelled keyword is a weekday, month or relative keyword.
+    pub fn validate(&self) -> Result<(), DynamicLanguageError> {
+        let known: Vec<String> = vocabulary(Language::default())
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        match self
+            .keywords
+            .keys()
+            .find(|keyword| !known.contains(keyword))
+        {
+            Some(keyword) => Err(DynamicLanguageError::UnknownKeyword(keyword.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes the value out in the language, falling back to English for values without a
+    /// spelling, such as exact dates.
+    pub fn spell(&self, time: &Time) -> String {
+        let english = time.with_language(Language::default()).to_string();
+
+        self.keywords.get(&english).cloned().unwrap_or(english)
+    }
+
+    /// Parses an expression with keywords spelled in the language, e.g. `"Nächste Woche 14:00"`.
+    ///
+    /// Spellings are matched ignoring case, whitespace and accents, the way [`parse`] matches
+    /// built-in keywords, and the rest of the expression is read like any other.
+    ///
+    /// [`parse`]: crate::parse::parse
+    pub fn parse(&self, input: &str) -> Result<Time, ParseError> {
+        let spellings: Vec<(String, &str)> = self
+            .keywords
+            .iter()
+            .map(|(keyword, spelling)| (fold(spelling), keyword.as_str()))
+            .collect();
+        let spelled = |tokens: &[&str]| {
+            let phrase = fold(&tokens.concat());
+
+            spellings
+                .iter()
+                .find(|(spelling, _)| *spelling == phrase)
+                .map(|(_, keyword)| *keyword)
+        };
+
+        let tokens: Vec<&str> = input.split_whitespace().collect();
+        let mut rewritten: Vec<&str> = Vec::new();
+        let mut index = 0;
+
+        // Replaces the longest spelling starting at each token with its English keyword
+        while index < tokens.len() {
+            let (keyword, consumed) = (1..=tokens.len() - index)
+                .rev()
+                .find_map(|count| Some((spelled(&tokens[index..index + count])?, count)))
+                .unwrap_or((tokens[index], 1));
+
+            rewritten.push(keyword);
+            index += consumed;
+        }
+
+        parse_in(&rewritten.join(" "), Language::default())
+            .map_err(|_| ParseError::Unrecognised(input.to_string()))
+    }
+}
+
+/// Registers a language, replacing any registered under the same name.
+#[cfg(feature = "std")]
+pub fn register(language: DynamicLanguage) -> Result<(), DynamicLanguageError> {
+    language.validate()?;
+
+    let mut registry = REGISTRY.write().unwrap();
+
+    registry.retain(|other| other.name != language.name);
+    registry.push(Arc::new(language));
+
+    Ok(())
+}
+
+/// Removes the language registered under the name, returning whether there was one.
+#[cfg(feature = "std")]
+pub fn unregister(name: &str) -> bool {
+    let mut registry = REGISTRY.write().unwrap();
+    let count = registry.len();
+
+    registry.retain(|other| other.name != name);
+    registry.len() != count
+}
+
+/// Unregisters the language when dropped, so a test's registration never leaks into the other
+/// tests sharing the registry, even when it fails.
+#[cfg(all(test, feature = "std"))]
+struct Unregister<'a>(&'a str);
+
+#[cfg(all(test, feature = "std"))]
+impl Drop for Unregister<'_> {
+    fn drop(&mut self) {
+        unregister(self.0);
+    }
+}
+
+/// Every registered language, in registration order.
+#[cfg(feature = "std")]
+pub fn registered() -> Vec<Arc<DynamicLanguage>> {
+    REGISTRY.read().unwrap().clone()
+}
+
+/// Every registered language, always none without the registry.
+#[cfg(not(feature = "std"))]
+pub fn registered() -> Vec<Arc<DynamicLanguage>> {
+    Vec::new()
+}
+
+/// The language registered under the name, e.g. the one a tenant is configured with.
+pub fn lookup(name: &str) -> Option<Arc<DynamicLanguage>> {
+    registered()
+        .into_iter()
+        .find(|language| language.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exact::ExactTime,
+        relative::Relative,
+        weekday::{Weekday, WeekdayTime},
+    };
+
+    fn german() -> DynamicLanguage {
+        DynamicLanguage::new("Deutsch")
+            .with_keyword("Monday", "Montag")
+            .with_keyword("Tomorrow", "Morgen")
+            .with_keyword("NextWeek", "Nächste Woche")
+    }
+
+    #[test]
+    fn parses_and_spells_keywords() {
+        let german = german();
+        let next_week = Time::Relative(Relative::next_week());
+
+        assert_eq!(german.parse("nächste woche"), Ok(next_week.clone()));
+        assert_eq!(german.spell(&next_week), "Nächste Woche");
+        assert_eq!(german.spell(&Time::Weekday(Weekday::friday())), "Friday");
+        assert_eq!(
+            german.parse("montag 14:00"),
+            Ok(Time::WeekdayTime(WeekdayTime::new(
+                Weekday::monday(),
+                ExactTime::new(14, 0, None)
+            )))
+        );
+        assert!(german.parse("übermorgen").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn loads_from_json() {
+        let german: DynamicLanguage = serde_json::from_str(
+            r#"{"name": "Deutsch", "keywords": {"Monday": "Montag", "Tomorrow": "Morgen"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(german.name(), "Deutsch");
+        assert_eq!(
+            german.parse("morgen"),
+            Ok(Time::Relative(Relative::tomorrow()))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keywords() {
+        let language = german().with_keyword("Someday", "Irgendwann");
+
+        assert_eq!(
+            language.validate(),
+            Err(DynamicLanguageError::UnknownKeyword("Someday".to_string()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn registers_languages() {
+        let _registration = Unregister("Deutsch");
+        register(german().with_keyword("Today", "Heute")).unwrap();
+
+        assert_eq!(
+            crate::parse::parse("heute"),
+            Ok(Time::Relative(Relative::today()))
+        );
+        assert_eq!(
+            lookup("Deutsch").map(|x| x.name().to_string()),
+            Some("Deutsch".into())
+        );
+        assert!(unregister("Deutsch"));
+        assert!(crate::parse::parse("heute").is_err());
+    }
+}
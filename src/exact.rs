@@ -1,24 +1,72 @@
 //! Exact date and time types with validation.
 
-use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use alloc::{format, string::String};
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Utc,
+};
+use core::fmt::Display;
 use derive_more::Display;
+#[cfg(feature = "schemars")]
 use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
 
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
+use crate::{language::Language, month::Month, strftime::name};
+
+/// Errors produced when strictly constructing exact dates and times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum ExactError {
+    #[display("month {_0} is not between 1 and 12")]
+    Month(u8),
+    #[display("day {_0} is not between 1 and 31")]
+    Day(u8),
+    #[display("hour {_0} is not between 0 and 23")]
+    Hour(u8),
+    #[display("minute {_0} is not between 0 and 59")]
+    Minute(u8),
+    #[display("second {_0} is not between 0 and 59")]
+    Second(u8),
+    #[display("millisecond {_0} is not between 0 and 999")]
+    Millisecond(u16),
+    #[display("'{_0}' does not exist")]
+    Nonexistent(ExactDate),
+}
+
+impl core::error::Error for ExactError {}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExactYear(i16);
 
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub struct ExactMonth(#[schemars(range(min = 1, max = 12))] u8);
+impl ExactYear {
+    pub const fn new(year: i16) -> Self {
+        Self(year)
+    }
+}
+
+impl From<i16> for ExactYear {
+    fn from(year: i16) -> Self {
+        Self(year)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+#[cfg_attr(feature = "schemars", schemars(!try_from))]
+pub struct ExactMonth(#[cfg_attr(feature = "schemars", schemars(range(min = 1, max = 12)))] u8);
 
 impl ExactMonth {
-    pub fn validate(self) -> Result<Self, Self> {
-        let valid = self.0.clamp(1, 12);
+    pub const fn validate(self) -> Result<Self, Self> {
+        let valid = if self.0 < 1 {
+            1
+        } else if self.0 > 12 {
+            12
+        } else {
+            self.0
+        };
 
         if self.0 == valid {
             Ok(self)
@@ -27,25 +75,55 @@ impl ExactMonth {
         }
     }
 
-    pub fn new(month: u8) -> Self {
+    pub const fn new(month: u8) -> Self {
         Self(month).validated()
     }
 
-    pub fn validated(self) -> Self {
+    pub const fn validated(self) -> Self {
         match self.validate() {
             Ok(x) | Err(x) => x,
         }
     }
+
+    /// Like [`ExactMonth::new`], but rejects values out of range instead of clamping them.
+    pub const fn try_new(month: u8) -> Result<Self, ExactError> {
+        match Self(month).validate() {
+            Ok(x) => Ok(x),
+            Err(_) => Err(ExactError::Month(month)),
+        }
+    }
+}
+
+impl From<ExactMonth> for u8 {
+    fn from(month: ExactMonth) -> Self {
+        month.0
+    }
+}
+
+impl TryFrom<u8> for ExactMonth {
+    type Error = ExactError;
+
+    fn try_from(month: u8) -> Result<Self, Self::Error> {
+        Self::try_new(month)
+    }
 }
 
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub struct ExactDay(#[schemars(range(min = 1, max = 31))] u8);
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+#[cfg_attr(feature = "schemars", schemars(!try_from))]
+pub struct ExactDay(#[cfg_attr(feature = "schemars", schemars(range(min = 1, max = 31)))] u8);
 
 impl ExactDay {
-    pub fn validate(self) -> Result<Self, Self> {
-        let valid = self.0.clamp(1, 31);
+    pub const fn validate(self) -> Result<Self, Self> {
+        let valid = if self.0 < 1 {
+            1
+        } else if self.0 > 31 {
+            31
+        } else {
+            self.0
+        };
 
         if self.0 == valid {
             Ok(self)
@@ -53,15 +131,49 @@ impl ExactDay {
             Err(Self(valid))
         }
     }
+
+    pub const fn new(day: u8) -> Self {
+        Self(day).validated()
+    }
+
+    pub const fn validated(self) -> Self {
+        match self.validate() {
+            Ok(x) | Err(x) => x,
+        }
+    }
+
+    /// Like [`ExactDay::new`], but rejects values out of range instead of clamping them.
+    pub const fn try_new(day: u8) -> Result<Self, ExactError> {
+        match Self(day).validate() {
+            Ok(x) => Ok(x),
+            Err(_) => Err(ExactError::Day(day)),
+        }
+    }
+}
+
+impl From<ExactDay> for u8 {
+    fn from(day: ExactDay) -> Self {
+        day.0
+    }
+}
+
+impl TryFrom<u8> for ExactDay {
+    type Error = ExactError;
+
+    fn try_from(day: u8) -> Result<Self, Self::Error> {
+        Self::try_new(day)
+    }
 }
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub struct ExactHour(#[schemars(range(min = 0, max = 23))] u8);
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+#[cfg_attr(feature = "schemars", schemars(!try_from))]
+pub struct ExactHour(#[cfg_attr(feature = "schemars", schemars(range(min = 0, max = 23)))] u8);
 
 impl ExactHour {
-    pub fn validate(self) -> Result<Self, Self> {
-        let valid = self.0.clamp(0, 23);
+    pub const fn validate(self) -> Result<Self, Self> {
+        let valid = if self.0 > 23 { 23 } else { self.0 };
 
         if self.0 == valid {
             Ok(self)
@@ -70,24 +182,42 @@ impl ExactHour {
         }
     }
 
-    pub fn new(hour: u8) -> Self {
+    pub const fn new(hour: u8) -> Self {
         Self(hour).validated()
     }
 
-    pub fn validated(self) -> Self {
+    pub const fn validated(self) -> Self {
         match self.validate() {
             Ok(x) | Err(x) => x,
         }
     }
+
+    /// Like [`ExactHour::new`], but rejects values out of range instead of clamping them.
+    pub const fn try_new(hour: u8) -> Result<Self, ExactError> {
+        match Self(hour).validate() {
+            Ok(x) => Ok(x),
+            Err(_) => Err(ExactError::Hour(hour)),
+        }
+    }
+}
+
+impl TryFrom<u8> for ExactHour {
+    type Error = ExactError;
+
+    fn try_from(hour: u8) -> Result<Self, Self::Error> {
+        Self::try_new(hour)
+    }
 }
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub struct ExactMinute(#[schemars(range(min = 0, max = 59))] u8);
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+#[cfg_attr(feature = "schemars", schemars(!try_from))]
+pub struct ExactMinute(#[cfg_attr(feature = "schemars", schemars(range(min = 0, max = 59)))] u8);
 
 impl ExactMinute {
-    pub fn validate(self) -> Result<Self, Self> {
-        let valid = self.0.clamp(0, 59);
+    pub const fn validate(self) -> Result<Self, Self> {
+        let valid = if self.0 > 59 { 59 } else { self.0 };
 
         if self.0 == valid {
             Ok(self)
@@ -95,24 +225,42 @@ impl ExactMinute {
             Err(Self(valid))
         }
     }
-    pub fn new(minute: u8) -> Self {
+    pub const fn new(minute: u8) -> Self {
         Self(minute).validated()
     }
 
-    pub fn validated(self) -> Self {
+    pub const fn validated(self) -> Self {
         match self.validate() {
             Ok(x) | Err(x) => x,
         }
     }
+
+    /// Like [`ExactMinute::new`], but rejects values out of range instead of clamping them.
+    pub const fn try_new(minute: u8) -> Result<Self, ExactError> {
+        match Self(minute).validate() {
+            Ok(x) => Ok(x),
+            Err(_) => Err(ExactError::Minute(minute)),
+        }
+    }
+}
+
+impl TryFrom<u8> for ExactMinute {
+    type Error = ExactError;
+
+    fn try_from(minute: u8) -> Result<Self, Self::Error> {
+        Self::try_new(minute)
+    }
 }
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub struct ExactSecond(#[schemars(range(min = 0, max = 59))] u8);
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+#[cfg_attr(feature = "schemars", schemars(!try_from))]
+pub struct ExactSecond(#[cfg_attr(feature = "schemars", schemars(range(min = 0, max = 59)))] u8);
 
 impl ExactSecond {
-    pub fn validate(self) -> Result<Self, Self> {
-        let valid = self.0.clamp(0, 59);
+    pub const fn validate(self) -> Result<Self, Self> {
+        let valid = if self.0 > 59 { 59 } else { self.0 };
 
         if self.0 == valid {
             Ok(self)
@@ -120,26 +268,133 @@ impl ExactSecond {
             Err(Self(valid))
         }
     }
-    pub fn new(second: u8) -> Self {
+    pub const fn new(second: u8) -> Self {
         Self(second).validated()
     }
 
-    pub fn validated(self) -> Self {
+    pub const fn validated(self) -> Self {
+        match self.validate() {
+            Ok(x) | Err(x) => x,
+        }
+    }
+
+    /// Like [`ExactSecond::new`], but rejects values out of range instead of clamping them.
+    pub const fn try_new(second: u8) -> Result<Self, ExactError> {
+        match Self(second).validate() {
+            Ok(x) => Ok(x),
+            Err(_) => Err(ExactError::Second(second)),
+        }
+    }
+}
+
+impl TryFrom<u8> for ExactSecond {
+    type Error = ExactError;
+
+    fn try_from(second: u8) -> Result<Self, Self::Error> {
+        Self::try_new(second)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
+#[cfg_attr(feature = "schemars", schemars(!try_from))]
+pub struct ExactMillisecond(
+    #[cfg_attr(feature = "schemars", schemars(range(min = 0, max = 999)))] u16,
+);
+
+impl ExactMillisecond {
+    pub const fn validate(self) -> Result<Self, Self> {
+        let valid = if self.0 > 999 { 999 } else { self.0 };
+
+        if self.0 == valid {
+            Ok(self)
+        } else {
+            Err(Self(valid))
+        }
+    }
+    pub const fn new(millisecond: u16) -> Self {
+        Self(millisecond).validated()
+    }
+
+    pub const fn validated(self) -> Self {
         match self.validate() {
             Ok(x) | Err(x) => x,
         }
     }
+
+    /// Like [`ExactMillisecond::new`], but rejects values out of range instead of clamping them.
+    pub const fn try_new(millisecond: u16) -> Result<Self, ExactError> {
+        match Self(millisecond).validate() {
+            Ok(x) => Ok(x),
+            Err(_) => Err(ExactError::Millisecond(millisecond)),
+        }
+    }
+}
+
+impl TryFrom<u16> for ExactMillisecond {
+    type Error = ExactError;
+
+    fn try_from(millisecond: u16) -> Result<Self, Self::Error> {
+        Self::try_new(millisecond)
+    }
 }
 
 /// A calendar date, optionally without a year for recurring dates.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+///
+/// Days past the end of the month are clamped to its last day, taking leap years into account.
+/// Dates without a year accept the 29th of February.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawExactDate"))]
+#[cfg_attr(feature = "schemars", schemars(!try_from))]
 pub enum ExactDate {
     WithYear(ExactYear, ExactMonth, ExactDay),
     WithoutYear(ExactMonth, ExactDay),
 }
 
+/// The shape of [`ExactDate`] before checking that the date exists.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "ExactDate")]
+enum RawExactDate {
+    WithYear(ExactYear, ExactMonth, ExactDay),
+    WithoutYear(ExactMonth, ExactDay),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawExactDate> for ExactDate {
+    type Error = ExactError;
+
+    fn try_from(raw: RawExactDate) -> Result<Self, Self::Error> {
+        match raw {
+            RawExactDate::WithYear(y, m, d) => Self::try_new(Some(y.0), m.0, d.0),
+            RawExactDate::WithoutYear(m, d) => Self::try_new(None, m.0, d.0),
+        }
+    }
+}
+
+/// The number of days in the month, in a leap year when no year is given.
+const fn days_in_month(year: Option<i16>, month: ExactMonth) -> u8 {
+    match (month.0, year) {
+        (2, Some(year)) if year % 4 != 0 || (year % 100 == 0 && year % 400 != 0) => 28,
+        (2, _) => 29,
+        (4 | 6 | 9 | 11, _) => 30,
+        _ => 31,
+    }
+}
+
+/// The date in the given year, on the 28th for the 29th of February outside leap years.
+fn in_year(year: i32, month: u8, day: u8) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month.into(), day.into())
+        .or_else(|| NaiveDate::from_ymd_opt(year, month.into(), 28))
+        .unwrap()
+}
+
 impl Display for ExactDate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             ExactDate::WithYear(y, m, d) => f.write_fmt(format_args!("{d}/{m}/{y}")),
             ExactDate::WithoutYear(m, d) => f.write_fmt(format_args!("{d}/{m}")),
@@ -148,20 +403,70 @@ impl Display for ExactDate {
 }
 
 impl ExactDate {
-    pub fn validate(self) -> Result<Self, Self> {
+    /// Writes the date out in the given language, e.g. "July 29, 2025", "den 29 juli 2025",
+    /// "29. juli 2025", "29 de julio de 2025" or "29 juillet 2025". Dates without a year leave it
+    /// out.
+    pub fn format(&self, language: Language) -> String {
+        let month = name(Month::from_number(self.month().into(), language), language);
+        let day = self.day();
+
+        let date = match language {
+            #[cfg(feature = "swedish")]
+            Language::Swedish(_) => format!("den {day} {month}"),
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => format!("{day}. {month}"),
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => format!("{day}. {month}"),
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => format!("{day} de {month}"),
+            #[cfg(feature = "french")]
+            Language::French(_) => format!("{day} {month}"),
+            Language::English(_) => format!("{month} {day}"),
+        };
+
+        match (language, self.year()) {
+            (_, None) => date,
+            #[cfg(feature = "spanish")]
+            (Language::Spanish(_), Some(year)) => format!("{date} de {year}"),
+            (Language::English(_), Some(year)) => format!("{date}, {year}"),
+            #[allow(unreachable_patterns)]
+            (_, Some(year)) => format!("{date} {year}"),
+        }
+    }
+
+    pub const fn validate(self) -> Result<Self, Self> {
+        let month = ExactMonth(self.month()).validated();
+        let days = days_in_month(self.year(), month);
+        let day = ExactDay(if self.day() > days { days } else { self.day() }).validated();
+
+        let valid = match self {
+            ExactDate::WithYear(y, ..) => Self::WithYear(y, month, day),
+            ExactDate::WithoutYear(..) => Self::WithoutYear(month, day),
+        };
+
+        if self.month() == month.0 && self.day() == day.0 {
+            Ok(self)
+        } else {
+            Err(valid)
+        }
+    }
+
+    pub const fn year(&self) -> Option<i16> {
         match self {
-            ExactDate::WithYear(y, m, d) => match (y, m.validate(), d.validate()) {
-                (y, Ok(m), Ok(d)) => Ok(Self::WithYear(y, m, d)),
-                (y, Ok(m), Err(d)) | (y, Err(m), Ok(d)) | (y, Err(m), Err(d)) => {
-                    Err(Self::WithYear(y, m, d))
-                }
-            },
-            ExactDate::WithoutYear(m, d) => match (m.validate(), d.validate()) {
-                (Ok(m), Ok(d)) => Ok(Self::WithoutYear(m, d)),
-                (Ok(m), Err(d)) | (Err(m), Ok(d)) | (Err(m), Err(d)) => {
-                    Err(Self::WithoutYear(m, d))
-                }
-            },
+            ExactDate::WithYear(year, ..) => Some(year.0),
+            ExactDate::WithoutYear(..) => None,
+        }
+    }
+
+    pub const fn month(&self) -> u8 {
+        match self {
+            ExactDate::WithYear(_, month, _) | ExactDate::WithoutYear(month, _) => month.0,
+        }
+    }
+
+    pub const fn day(&self) -> u8 {
+        match self {
+            ExactDate::WithYear(.., day) | ExactDate::WithoutYear(_, day) => day.0,
         }
     }
 
@@ -178,7 +483,7 @@ impl ExactDate {
             ExactDate::WithoutYear(m, d) => (relative_to.year(), m.0, d.0),
         };
 
-        NaiveDate::from_ymd_opt(year, month.into(), day.into()).unwrap_or_default()
+        in_year(year, month, day)
     }
     pub fn to_chrono_max(&self, relative_to: DateTime<Utc>) -> NaiveDate {
         let (year, month, day) = match self {
@@ -196,134 +501,417 @@ impl ExactDate {
             }
         };
 
-        NaiveDate::from_ymd_opt(year, month.into(), day.into()).unwrap_or_default()
+        in_year(year, month, day)
     }
 
-    pub fn new(year: Option<i16>, month: u8, day: u8) -> Self {
+    pub const fn new(year: Option<i16>, month: u8, day: u8) -> Self {
         match year {
-            Some(year) => {
-                Self::WithYear(ExactYear(year), ExactMonth(month), ExactDay(day)).validated()
-            }
-            None => Self::WithoutYear(ExactMonth(month), ExactDay(day)).validated(),
+            Some(year) => Self::with_year(year, month, day),
+            None => Self::without_year(month, day),
         }
     }
 
-    pub fn validated(self) -> Self {
+    /// A date in the given year, e.g. `ExactDate::with_year(2026, 1, 15)`.
+    pub const fn with_year(year: i16, month: u8, day: u8) -> Self {
+        Self::WithYear(ExactYear(year), ExactMonth(month), ExactDay(day)).validated()
+    }
+
+    /// A date recurring every year, e.g. `ExactDate::without_year(12, 24)`.
+    pub const fn without_year(month: u8, day: u8) -> Self {
+        Self::WithoutYear(ExactMonth(month), ExactDay(day)).validated()
+    }
+
+    pub const fn validated(self) -> Self {
         match self.validate() {
             Ok(x) | Err(x) => x,
         }
     }
+
+    /// Like [`ExactDate::new`], but rejects components out of range and dates that do not exist,
+    /// such as the 31st of April, instead of clamping them.
+    pub fn try_new(year: Option<i16>, month: u8, day: u8) -> Result<Self, ExactError> {
+        let (month, day) = (ExactMonth::try_new(month)?, ExactDay::try_new(day)?);
+
+        let date = match year {
+            Some(year) => Self::WithYear(ExactYear(year), month, day),
+            None => Self::WithoutYear(month, day),
+        };
+
+        date.validate().map_err(|_| ExactError::Nonexistent(date))
+    }
+}
+
+/// The zone a time of day is given in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Zone {
+    #[display("UTC")]
+    Utc,
+    /// The zone of whoever resolves the time, e.g. the user's local time.
+    Local,
+    /// A fixed offset in seconds east of UTC, e.g. 7200 for Stockholm in summer.
+    #[display(
+        "{}{:02}:{:02}",
+        if *_0 < 0 { '-' } else { '+' },
+        _0.unsigned_abs() / 3600,
+        _0.unsigned_abs() / 60 % 60
+    )]
+    Offset(i32),
+    /// An IANA zone, e.g. `Europe/Stockholm`, whose offset follows its daylight saving rules.
+    #[cfg(feature = "chrono-tz")]
+    #[display("{_0}")]
+    Named(#[cfg_attr(feature = "schemars", schemars(with = "String"))] chrono_tz::Tz),
+}
+
+impl Zone {
+    /// The zone at a fixed offset from UTC.
+    pub fn from_offset(offset: FixedOffset) -> Self {
+        Zone::Offset(offset.local_minus_utc())
+    }
+
+    /// The offset from UTC, if fixed.
+    pub fn fixed_offset(self) -> Option<FixedOffset> {
+        match self {
+            Zone::Utc => FixedOffset::east_opt(0),
+            Zone::Offset(seconds) => FixedOffset::east_opt(seconds),
+            Zone::Local => None,
+            #[cfg(feature = "chrono-tz")]
+            Zone::Named(_) => None,
+        }
+    }
+}
+
+/// Runs a UTC conversion on the wall clock of the zone when it is a fixed offset or a named zone,
+/// so the times it produces are read in that zone.
+pub(crate) fn in_offset(
+    zone: Option<Zone>,
+    relative_to: DateTime<Utc>,
+    convert: impl FnOnce(DateTime<Utc>) -> DateTime<Utc>,
+) -> DateTime<Utc> {
+    match zone {
+        Some(Zone::Offset(seconds)) => {
+            let offset = TimeDelta::seconds(seconds.into());
+
+            convert(relative_to + offset) - offset
+        }
+        #[cfg(feature = "chrono-tz")]
+        Some(Zone::Named(tz)) => {
+            crate::tz::on_wall_clock(&relative_to.with_timezone(&tz), convert).to_utc()
+        }
+        _ => convert(relative_to),
+    }
 }
 
-/// A time of day, optionally without seconds.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(untagged)]
+/// A time of day, optionally without seconds or with milliseconds, optionally annotated with a
+/// zone.
+///
+/// Times without a zone, or in [`Zone::Local`], resolve on the wall clock of the reference time
+/// given to the `_tz` conversions in [`crate::tz`]. [`Zone::Utc`] pins them to UTC, and
+/// [`Zone::Offset`] to its offset in every conversion, as does a named zone with the
+/// `chrono-tz` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(untagged, try_from = "RawExactTime"))]
+#[cfg_attr(feature = "schemars", schemars(!try_from))]
 pub enum ExactTime {
-    WithSecond(ExactHour, ExactMinute, ExactSecond),
-    WithoutSecond(ExactHour, ExactMinute),
+    WithSecond(
+        ExactHour,
+        ExactMinute,
+        ExactSecond,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        Option<Zone>,
+    ),
+    WithoutSecond(
+        ExactHour,
+        ExactMinute,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        Option<Zone>,
+    ),
+    WithMillisecond(
+        ExactHour,
+        ExactMinute,
+        ExactSecond,
+        ExactMillisecond,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        Option<Zone>,
+    ),
+}
+
+/// The shape of [`ExactTime`] before checking that its components are in range.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(untagged, rename = "ExactTime")]
+enum RawExactTime {
+    WithSecond(u8, u8, u8, #[serde(default)] Option<Zone>),
+    WithoutSecond(u8, u8, #[serde(default)] Option<Zone>),
+    WithMillisecond(u8, u8, u8, u16, #[serde(default)] Option<Zone>),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawExactTime> for ExactTime {
+    type Error = ExactError;
+
+    fn try_from(raw: RawExactTime) -> Result<Self, Self::Error> {
+        Ok(match raw {
+            RawExactTime::WithSecond(h, m, s, zone) => Self::WithSecond(
+                ExactHour::try_new(h)?,
+                ExactMinute::try_new(m)?,
+                ExactSecond::try_new(s)?,
+                zone,
+            ),
+            RawExactTime::WithoutSecond(h, m, zone) => {
+                Self::WithoutSecond(ExactHour::try_new(h)?, ExactMinute::try_new(m)?, zone)
+            }
+            RawExactTime::WithMillisecond(h, m, s, ms, zone) => Self::WithMillisecond(
+                ExactHour::try_new(h)?,
+                ExactMinute::try_new(m)?,
+                ExactSecond::try_new(s)?,
+                ExactMillisecond::try_new(ms)?,
+                zone,
+            ),
+        })
+    }
 }
 
 impl ExactTime {
-    pub fn new(hour: u8, minute: u8, second: Option<u8>) -> Self {
+    pub const fn new(hour: u8, minute: u8, second: Option<u8>) -> Self {
         match second {
-            Some(second) => {
-                Self::WithSecond(ExactHour(hour), ExactMinute(minute), ExactSecond(second))
-                    .validated()
-            }
-            None => Self::WithoutSecond(ExactHour(hour), ExactMinute(minute)).validated(),
+            Some(second) => Self::WithSecond(
+                ExactHour(hour),
+                ExactMinute(minute),
+                ExactSecond(second),
+                None,
+            )
+            .validated(),
+            None => Self::WithoutSecond(ExactHour(hour), ExactMinute(minute), None).validated(),
         }
     }
 
-    pub fn validated(self) -> Self {
+    /// Like [`ExactTime::new`], but rejects components out of range instead of clamping them.
+    pub fn try_new(hour: u8, minute: u8, second: Option<u8>) -> Result<Self, ExactError> {
+        let (hour, minute) = (ExactHour::try_new(hour)?, ExactMinute::try_new(minute)?);
+
+        Ok(match second {
+            Some(second) => Self::WithSecond(hour, minute, ExactSecond::try_new(second)?, None),
+            None => Self::WithoutSecond(hour, minute, None),
+        })
+    }
+
+    /// Adds milliseconds to the time, giving it seconds if it had none.
+    pub const fn with_millisecond(self, millisecond: u16) -> Self {
+        Self::WithMillisecond(
+            ExactHour(self.hour()),
+            ExactMinute(self.minute()),
+            ExactSecond(self.second()),
+            ExactMillisecond::new(millisecond),
+            self.zone(),
+        )
+    }
+
+    pub const fn validated(self) -> Self {
         match self.validate() {
             Ok(x) | Err(x) => x,
         }
     }
 
-    pub fn validate(self) -> Result<Self, Self> {
+    pub const fn validate(self) -> Result<Self, Self> {
         match self {
-            ExactTime::WithSecond(hour, minute, second) => {
+            ExactTime::WithSecond(hour, minute, second, zone) => {
                 match (hour.validate(), minute.validate(), second.validate()) {
-                    (Ok(h), Ok(m), Ok(s)) => Ok(Self::WithSecond(h, m, s)),
+                    (Ok(h), Ok(m), Ok(s)) => Ok(Self::WithSecond(h, m, s, zone)),
                     (Ok(h), Ok(m), Err(s))
                     | (Ok(h), Err(m), Ok(s))
                     | (Ok(h), Err(m), Err(s))
                     | (Err(h), Ok(m), Ok(s))
                     | (Err(h), Ok(m), Err(s))
                     | (Err(h), Err(m), Ok(s))
-                    | (Err(h), Err(m), Err(s)) => Err(Self::WithSecond(h, m, s)),
+                    | (Err(h), Err(m), Err(s)) => Err(Self::WithSecond(h, m, s, zone)),
                 }
             }
-            ExactTime::WithoutSecond(hour, minute) => match (hour.validate(), minute.validate()) {
-                (Ok(h), Ok(m)) => Ok(Self::WithoutSecond(h, m)),
-                (Ok(h), Err(m)) | (Err(h), Ok(m)) | (Err(h), Err(m)) => {
-                    Err(Self::WithoutSecond(h, m))
+            ExactTime::WithoutSecond(hour, minute, zone) => {
+                match (hour.validate(), minute.validate()) {
+                    (Ok(h), Ok(m)) => Ok(Self::WithoutSecond(h, m, zone)),
+                    (Ok(h), Err(m)) | (Err(h), Ok(m)) | (Err(h), Err(m)) => {
+                        Err(Self::WithoutSecond(h, m, zone))
+                    }
                 }
-            },
+            }
+            ExactTime::WithMillisecond(hour, minute, second, millisecond, zone) => {
+                match (
+                    hour.validate(),
+                    minute.validate(),
+                    second.validate(),
+                    millisecond.validate(),
+                ) {
+                    (Ok(h), Ok(m), Ok(s), Ok(ms)) => Ok(Self::WithMillisecond(h, m, s, ms, zone)),
+                    (Ok(h) | Err(h), Ok(m) | Err(m), Ok(s) | Err(s), Ok(ms) | Err(ms)) => {
+                        Err(Self::WithMillisecond(h, m, s, ms, zone))
+                    }
+                }
+            }
         }
     }
 
-    pub fn hour(&self) -> u8 {
+    pub const fn hour(&self) -> u8 {
         match self {
-            ExactTime::WithSecond(hour, ..) | ExactTime::WithoutSecond(hour, ..) => hour.0,
+            ExactTime::WithSecond(hour, ..)
+            | ExactTime::WithoutSecond(hour, ..)
+            | ExactTime::WithMillisecond(hour, ..) => hour.0,
         }
     }
 
-    pub fn minute(&self) -> u8 {
+    pub const fn minute(&self) -> u8 {
         match self {
-            ExactTime::WithSecond(_, minute, ..) | ExactTime::WithoutSecond(_, minute, ..) => {
-                minute.0
-            }
+            ExactTime::WithSecond(_, minute, ..)
+            | ExactTime::WithoutSecond(_, minute, ..)
+            | ExactTime::WithMillisecond(_, minute, ..) => minute.0,
         }
     }
 
-    pub fn second(&self) -> u8 {
+    pub const fn second(&self) -> u8 {
         match self {
-            ExactTime::WithSecond(_, _, second) => second.0,
+            ExactTime::WithSecond(_, _, second, _)
+            | ExactTime::WithMillisecond(_, _, second, ..) => second.0,
             ExactTime::WithoutSecond(..) => 0,
         }
     }
 
+    pub const fn millisecond(&self) -> u16 {
+        match self {
+            ExactTime::WithMillisecond(.., millisecond, _) => millisecond.0,
+            _ => 0,
+        }
+    }
+
+    /// Whether the time was given with seconds.
+    pub const fn has_second(&self) -> bool {
+        !matches!(self, ExactTime::WithoutSecond(..))
+    }
+
+    /// Whether the time was given with milliseconds.
+    pub const fn has_millisecond(&self) -> bool {
+        matches!(self, ExactTime::WithMillisecond(..))
+    }
+
+    /// The zone the time is given in, if annotated.
+    pub const fn zone(&self) -> Option<Zone> {
+        match self {
+            ExactTime::WithSecond(.., zone)
+            | ExactTime::WithoutSecond(.., zone)
+            | ExactTime::WithMillisecond(.., zone) => *zone,
+        }
+    }
+
+    /// Annotates the time with a zone, replacing any previous one.
+    pub const fn with_zone(self, zone: Zone) -> Self {
+        self.replace_zone(Some(zone))
+    }
+
+    /// Removes any zone annotation.
+    pub const fn without_zone(self) -> Self {
+        self.replace_zone(None)
+    }
+
+    const fn replace_zone(self, zone: Option<Zone>) -> Self {
+        match self {
+            ExactTime::WithSecond(h, m, s, _) => ExactTime::WithSecond(h, m, s, zone),
+            ExactTime::WithoutSecond(h, m, _) => ExactTime::WithoutSecond(h, m, zone),
+            ExactTime::WithMillisecond(h, m, s, ms, _) => {
+                ExactTime::WithMillisecond(h, m, s, ms, zone)
+            }
+        }
+    }
+
+    /// Converts from a chrono time of day, keeping milliseconds when there are any. Leap seconds
+    /// end at the last millisecond of the minute.
     pub fn from_chrono(x: NaiveTime) -> Self {
-        Self::WithSecond(
+        let time = Self::WithSecond(
             ExactHour(x.hour() as u8),
             ExactMinute(x.minute() as u8),
             ExactSecond(x.second() as u8),
-        )
+            None,
+        );
+
+        match x.nanosecond() / 1_000_000 {
+            0 => time,
+            millisecond => time.with_millisecond(millisecond.min(999) as u16),
+        }
     }
 
+    /// Converts to a chrono time of day, ignoring any zone.
     pub fn to_chrono(&self) -> NaiveTime {
-        let (h, m, s) = match self {
-            ExactTime::WithSecond(h, m, s) => (h.0, m.0, s.0),
-            ExactTime::WithoutSecond(h, m) => (h.0, m.0, 0),
-        };
+        let (h, m, s) = (self.hour(), self.minute(), self.second());
 
-        NaiveTime::from_hms_opt(h.into(), m.into(), s.into()).unwrap_or_default()
+        NaiveTime::from_hms_milli_opt(h.into(), m.into(), s.into(), self.millisecond().into())
+            .unwrap_or_default()
     }
 }
 
 impl Display for ExactTime {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            ExactTime::WithSecond(hour, minute, second) => {
-                f.write_fmt(format_args!("{hour}:{minute}:{second}"))
+            ExactTime::WithSecond(hour, minute, second, _) => {
+                write!(f, "{:02}:{:02}:{:02}", hour.0, minute.0, second.0)?
+            }
+            ExactTime::WithoutSecond(hour, minute, _) => {
+                write!(f, "{:02}:{:02}", hour.0, minute.0)?
             }
-            ExactTime::WithoutSecond(hour, minute) => f.write_fmt(format_args!("{hour}:{minute}")),
+            ExactTime::WithMillisecond(hour, minute, second, millisecond, _) => write!(
+                f,
+                "{:02}:{:02}:{:02}.{:03}",
+                hour.0, minute.0, second.0, millisecond.0
+            )?,
+        }
+
+        match self.zone() {
+            Some(zone) => write!(f, " {zone}"),
+            None => Ok(()),
         }
     }
 }
 
 /// A combination of date and time.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[display("{} {}", self.0, self.1)]
 pub struct ExactDateTime(ExactDate, ExactTime);
 
 impl ExactDateTime {
-    pub fn new(date: ExactDate, time: ExactTime) -> Self {
+    pub const fn new(date: ExactDate, time: ExactTime) -> Self {
         Self(date.validated(), time.validated())
     }
 
-    pub fn validate(self) -> Result<Self, Self> {
+    /// Like [`ExactDateTime::new`], but rejects components out of range instead of clamping
+    /// them.
+    pub fn try_new(date: ExactDate, time: ExactTime) -> Result<Self, ExactError> {
+        ExactDate::try_new(date.year(), date.month(), date.day())?;
+        ExactTime::try_new(time.hour(), time.minute(), Some(time.second()))?;
+        ExactMillisecond::try_new(time.millisecond())?;
+
+        Ok(Self(date, time))
+    }
+
+    pub const fn date(&self) -> ExactDate {
+        self.0
+    }
+
+    pub const fn time(&self) -> ExactTime {
+        self.1
+    }
+
+    pub const fn validate(self) -> Result<Self, Self> {
         match (self.0.validate(), self.1.validate()) {
             (Ok(m), Ok(d)) => Ok(Self(m, d)),
             (Ok(m), Err(d)) | (Err(m), Ok(d)) | (Err(m), Err(d)) => Err(Self(m, d)),
@@ -331,23 +919,34 @@ impl ExactDateTime {
     }
 
     pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
-        NaiveDateTime::new(self.0.to_chrono_min(relative_to), self.1.to_chrono()).and_utc()
+        in_offset(self.1.zone(), relative_to, |relative_to| {
+            NaiveDateTime::new(self.0.to_chrono_min(relative_to), self.1.to_chrono()).and_utc()
+        })
     }
 
     pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
-        NaiveDateTime::new(self.0.to_chrono_max(relative_to), self.1.to_chrono()).and_utc()
+        in_offset(self.1.zone(), relative_to, |relative_to| {
+            NaiveDateTime::new(self.0.to_chrono_max(relative_to), self.1.to_chrono()).and_utc()
+        })
     }
 
-    pub fn validated(self) -> Self {
+    pub const fn validated(self) -> Self {
         match self.validate() {
             Ok(x) | Err(x) => x,
         }
     }
+
+    /// Writes the date out in the given language followed by the time, e.g.
+    /// "July 29, 2025 09:05". See [`ExactDate::format`].
+    pub fn format(&self, language: Language) -> String {
+        format!("{} {}", self.0.format(language), self.1)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
     use chrono::NaiveDate;
 
     fn base_time() -> DateTime<Utc> {
@@ -530,7 +1129,7 @@ mod tests {
 
     #[test]
     fn exact_date_february_29_non_leap() {
-        // Test Feb 29 in a non-leap year - should return default (epoch)
+        // Test Feb 29 in a non-leap year - should clamp to Feb 28
         let base = DateTime::parse_from_rfc3339("2025-03-01T00:00:00-00:00")
             .unwrap()
             .to_utc();
@@ -538,7 +1137,283 @@ mod tests {
         let feb_29 = ExactDate::new(Some(2025), 2, 29);
         let result = feb_29.to_chrono_min(base);
 
-        // from_ymd_opt returns None for invalid dates, unwrap_or_default gives epoch
-        assert_eq!(result, NaiveDate::default());
+        assert_eq!(result, NaiveDate::from_ymd_opt(2025, 2, 28).unwrap());
+    }
+
+    #[test]
+    fn exact_date_february_29_without_year() {
+        let leap_day = ExactDate::without_year(2, 29);
+        let january = DateTime::parse_from_rfc3339("2025-01-15T00:00:00-00:00")
+            .unwrap()
+            .to_utc();
+        let march = DateTime::parse_from_rfc3339("2025-03-01T00:00:00-00:00")
+            .unwrap()
+            .to_utc();
+
+        assert_eq!(
+            leap_day.to_chrono_min(january),
+            NaiveDate::from_ymd_opt(2025, 2, 28).unwrap()
+        );
+        assert_eq!(
+            leap_day.to_chrono_max(march),
+            NaiveDate::from_ymd_opt(2026, 2, 28).unwrap()
+        );
+        assert_eq!(
+            leap_day.to_chrono_max(
+                DateTime::parse_from_rfc3339("2027-06-01T00:00:00-00:00")
+                    .unwrap()
+                    .to_utc()
+            ),
+            NaiveDate::from_ymd_opt(2028, 2, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn exact_date_day_aware_validation() {
+        assert_eq!(format!("{}", ExactDate::new(None, 4, 31)), "30/4");
+        assert_eq!(
+            format!("{}", ExactDate::new(Some(2024), 2, 30)),
+            "29/2/2024"
+        );
+        assert_eq!(format!("{}", ExactDate::new(None, 2, 29)), "29/2");
+        assert!(
+            ExactDate::WithoutYear(ExactMonth(4), ExactDay(31))
+                .validate()
+                .is_err()
+        );
+
+        assert_eq!(
+            ExactDate::try_new(Some(2025), 2, 29)
+                .unwrap_err()
+                .to_string(),
+            "'29/2/2025' does not exist"
+        );
+        assert!(ExactDate::try_new(Some(2024), 2, 29).is_ok());
+        #[cfg(feature = "serde")]
+        assert!(serde_json::from_str::<ExactDate>(r#"{"WithoutYear":[4,31]}"#).is_err());
+    }
+
+    #[test]
+    fn constructs_in_const_context() {
+        const DEADLINE: ExactDate = ExactDate::with_year(2026, 1, 15);
+        const LEAP_DAY: ExactDate = ExactDate::with_year(2025, 2, 29);
+        const CHRISTMAS: ExactDate = ExactDate::without_year(12, 24);
+        const STANDUP: ExactTime = ExactTime::new(9, 15, None).with_zone(Zone::Utc);
+        const MIDNIGHT: ExactTime = ExactTime::new(24, 0, Some(0));
+
+        assert_eq!(DEADLINE.to_string(), "15/1/2026");
+        assert_eq!(LEAP_DAY.to_string(), "28/2/2025");
+        assert_eq!(CHRISTMAS.to_string(), "24/12");
+        assert_eq!(STANDUP, ExactTime::new(9, 15, None).with_zone(Zone::Utc));
+        assert_eq!(MIDNIGHT.to_string(), "23:00:00");
+        assert_eq!(
+            const { ExactMonth::try_new(13) },
+            Err(ExactError::Month(13))
+        );
+        assert_eq!(
+            const { ExactDate::with_year(2100, 2, 29) },
+            ExactDate::new(Some(2100), 2, 28)
+        );
+    }
+
+    #[test]
+    fn exact_time_pads() {
+        assert_eq!(ExactTime::new(9, 5, None).to_string(), "09:05");
+        assert_eq!(ExactTime::new(9, 5, Some(7)).to_string(), "09:05:07");
+    }
+
+    #[test]
+    fn exact_date_format() {
+        let date = ExactDate::new(Some(2025), 7, 29);
+        let date_time = ExactDateTime::new(date, ExactTime::new(9, 5, None));
+
+        assert_eq!(date.format(Language::default()), "July 29, 2025");
+        assert_eq!(
+            ExactDate::new(None, 7, 29).format(Language::default()),
+            "July 29"
+        );
+        assert_eq!(date_time.format(Language::default()), "July 29, 2025 09:05");
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn exact_date_format_swedish() {
+        use crate::language::Swedish;
+
+        let swedish = Language::Swedish(Swedish::default());
+
+        assert_eq!(
+            ExactDate::new(Some(2025), 7, 29).format(swedish),
+            "den 29 juli 2025"
+        );
+        assert_eq!(
+            ExactDate::new(None, 12, 1).format(swedish),
+            "den 1 december"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "norwegian", feature = "danish"))]
+    fn exact_date_format_norwegian_and_danish() {
+        use crate::language::{Danish, Norwegian};
+
+        let norwegian = Language::Norwegian(Norwegian::default());
+        let danish = Language::Danish(Danish::default());
+
+        assert_eq!(
+            ExactDate::new(Some(2025), 12, 24).format(norwegian),
+            "24. desember 2025"
+        );
+        assert_eq!(ExactDate::new(None, 5, 1).format(danish), "1. maj");
+    }
+
+    #[test]
+    #[cfg(all(feature = "spanish", feature = "french"))]
+    fn exact_date_format_spanish_and_french() {
+        use crate::language::{French, Spanish};
+
+        let spanish = Language::Spanish(Spanish::default());
+        let french = Language::French(French::default());
+
+        assert_eq!(
+            ExactDate::new(Some(2025), 7, 29).format(spanish),
+            "29 de julio de 2025"
+        );
+        assert_eq!(ExactDate::new(None, 8, 15).format(french), "15 août");
+    }
+
+    #[test]
+    fn exact_time_zones() {
+        let time = ExactTime::new(9, 0, None).with_zone(Zone::Local);
+
+        assert_eq!(time.zone(), Some(Zone::Local));
+        assert_eq!(time.without_zone(), ExactTime::new(9, 0, None));
+        assert_eq!(format!("{}", time), "09:00 Local");
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(serde_json::to_string(&time).unwrap(), r#"[9,0,"Local"]"#);
+            assert_eq!(
+                serde_json::from_str::<ExactTime>(r#"[9,0,"Local"]"#).unwrap(),
+                time
+            );
+            assert_eq!(
+                serde_json::from_str::<ExactTime>("[9,0]").unwrap(),
+                ExactTime::new(9, 0, None)
+            );
+        }
+        assert_eq!(
+            ExactTime::WithSecond(
+                ExactHour(25),
+                ExactMinute(0),
+                ExactSecond(0),
+                Some(Zone::Utc)
+            )
+            .validated(),
+            ExactTime::new(23, 0, Some(0)).with_zone(Zone::Utc)
+        );
+    }
+
+    #[test]
+    fn exact_date_time_offsets() {
+        let stockholm = ExactTime::new(10, 30, None).with_zone(Zone::Offset(7200));
+        let date_time = ExactDateTime::new(ExactDate::new(Some(2025), 7, 29), stockholm);
+
+        assert_eq!(
+            date_time.to_chrono_min(base_time()).to_rfc3339(),
+            "2025-07-29T08:30:00+00:00"
+        );
+        assert_eq!(stockholm.to_string(), "10:30 +02:00");
+        assert_eq!(Zone::Offset(-19800).to_string(), "-05:30");
+        assert_eq!(
+            Zone::Offset(7200).fixed_offset(),
+            FixedOffset::east_opt(7200)
+        );
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(
+                serde_json::to_string(&stockholm).unwrap(),
+                r#"[10,30,{"Offset":7200}]"#
+            );
+            assert_eq!(
+                serde_json::from_str::<ExactTime>(r#"[10,30,{"Offset":7200}]"#).unwrap(),
+                stockholm
+            );
+        }
+
+        // Without a year, the next occurrence is found on the offset's wall clock
+        let midnight = ExactTime::new(0, 30, None).with_zone(Zone::Offset(7200));
+        let date_time = ExactDateTime::new(ExactDate::new(None, 7, 30), midnight);
+        let relative_to = DateTime::parse_from_rfc3339("2025-07-29T23:00:00Z")
+            .unwrap()
+            .to_utc();
+
+        assert_eq!(
+            date_time.to_chrono_max(relative_to).to_rfc3339(),
+            "2025-07-29T22:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn exact_time_milliseconds() {
+        let original = NaiveTime::from_hms_milli_opt(14, 30, 45, 250).unwrap();
+        let time = ExactTime::from_chrono(original);
+
+        assert_eq!(time.millisecond(), 250);
+        assert_eq!(time.to_chrono(), original);
+        assert_eq!(time.to_string(), "14:30:45.250");
+        assert_eq!(time.with_zone(Zone::Utc).to_string(), "14:30:45.250 UTC");
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(serde_json::to_string(&time).unwrap(), "[14,30,45,250]");
+            assert_eq!(
+                serde_json::from_str::<ExactTime>("[14,30,45,250]").unwrap(),
+                time
+            );
+            assert_eq!(
+                serde_json::from_str::<ExactTime>(r#"[14,30,45,250,"Utc"]"#).unwrap(),
+                time.with_zone(Zone::Utc)
+            );
+            assert!(serde_json::from_str::<ExactTime>("[14,30,45,1000]").is_err());
+        }
+        assert_eq!(
+            ExactTime::new(9, 5, None).with_millisecond(5).to_string(),
+            "09:05:00.005"
+        );
+        assert!(!ExactTime::from_chrono(NaiveTime::MIN).has_millisecond());
+    }
+
+    #[test]
+    fn strict_construction() {
+        assert_eq!(ExactMonth::try_new(13), Err(ExactError::Month(13)));
+        assert_eq!(ExactMonth::try_from(12), Ok(ExactMonth::new(12)));
+        assert_eq!(ExactDay::try_new(0), Err(ExactError::Day(0)));
+        assert_eq!(
+            ExactDate::try_new(Some(2025), 7, 29),
+            Ok(ExactDate::new(Some(2025), 7, 29))
+        );
+        assert_eq!(
+            ExactTime::try_new(14, 60, None),
+            Err(ExactError::Minute(60))
+        );
+        assert_eq!(
+            ExactTime::try_new(24, 0, Some(0)).unwrap_err().to_string(),
+            "hour 24 is not between 0 and 23"
+        );
+
+        let time = ExactTime::new(9, 0, None).with_zone(Zone::Local);
+        let date_time = ExactDateTime::try_new(ExactDate::new(None, 12, 24), time).unwrap();
+        assert_eq!(date_time.time(), time);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn rejects_malformed_json() {
+        assert!(serde_json::from_str::<ExactMonth>("13").is_err());
+        assert!(serde_json::from_str::<ExactTime>("[25,0]").is_err());
+        assert!(serde_json::from_str::<ExactDate>(r#"{"WithoutYear":[2,32]}"#).is_err());
+        assert_eq!(
+            serde_json::from_str::<ExactDate>(r#"{"WithoutYear":[2,28]}"#).unwrap(),
+            ExactDate::new(None, 2, 28)
+        );
     }
 }
@@ -0,0 +1,384 @@
+//! Culturally named days, such as "Christmas Eve", "Easter Sunday" or "Midsommarafton".
+//!
+//! Days resolve to a date per year through [`Holiday`], which applications can implement for
+//! their own days. [`register`] adds a day to the [named] registry, after which its
+//! names parse, deserialise and display like built-in keywords, as
+//! [`Time::Named`](crate::Time::Named). [`register_all`] does so for every [`NamedDay`].
+
+use core::fmt;
+
+use chrono::{Datelike, Days, NaiveDate};
+
+use crate::language::Language;
+#[cfg(feature = "std")]
+use crate::named::{self, NamedDate, Recurrence};
+
+/// A day recurring once a year, on a date that may move between years.
+///
+/// Days are told apart by their name in the default language, the way the registry tells named
+/// dates apart.
+pub trait Holiday: fmt::Debug + Send + Sync {
+    /// The name in the given language, e.g. `"Christmas Eve"` or `"Julafton"`.
+    fn name(&self, language: Language) -> &str;
+
+    /// The date in the given year, or `None` when the day does not occur that year. Days must
+    /// keep recurring, as resolving one searches year by year for its next occurrence.
+    fn date_in(&self, year: i32) -> Option<NaiveDate>;
+}
+
+/// A built-in named day, observed on a fixed date or computed from Easter or Midsummer.
+///
+/// Midsummer follows the Swedish convention, with Midsummer Eve on the Friday between June 19th
+/// and 25th. St John's Eve and Day, celebrated as midsummer in Norway and Denmark, stay on June
+/// 23rd and 24th.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedDay {
+    NewYearsDay,
+    Epiphany,
+    GoodFriday,
+    EasterSunday,
+    EasterMonday,
+    AscensionDay,
+    Pentecost,
+    MidsummerEve,
+    MidsummerDay,
+    StJohnsEve,
+    StJohnsDay,
+    ChristmasEve,
+    ChristmasDay,
+    BoxingDay,
+    NewYearsEve,
+}
+
+impl NamedDay {
+    /// Every built-in day, in calendar order.
+    pub const ALL: [NamedDay; 15] = [
+        NamedDay::NewYearsDay,
+        NamedDay::Epiphany,
+        NamedDay::GoodFriday,
+        NamedDay::EasterSunday,
+        NamedDay::EasterMonday,
+        NamedDay::AscensionDay,
+        NamedDay::Pentecost,
+        NamedDay::MidsummerEve,
+        NamedDay::MidsummerDay,
+        NamedDay::StJohnsEve,
+        NamedDay::StJohnsDay,
+        NamedDay::ChristmasEve,
+        NamedDay::ChristmasDay,
+        NamedDay::BoxingDay,
+        NamedDay::NewYearsEve,
+    ];
+
+    fn english(self) -> &'static str {
+        match self {
+            NamedDay::NewYearsDay => "New Year's Day",
+            NamedDay::Epiphany => "Epiphany",
+            NamedDay::GoodFriday => "Good Friday",
+            NamedDay::EasterSunday => "Easter Sunday",
+            NamedDay::EasterMonday => "Easter Monday",
+            NamedDay::AscensionDay => "Ascension Day",
+            NamedDay::Pentecost => "Pentecost",
+            NamedDay::MidsummerEve => "Midsummer Eve",
+            NamedDay::MidsummerDay => "Midsummer Day",
+            NamedDay::StJohnsEve => "St John's Eve",
+            NamedDay::StJohnsDay => "St John's Day",
+            NamedDay::ChristmasEve => "Christmas Eve",
+            NamedDay::ChristmasDay => "Christmas Day",
+            NamedDay::BoxingDay => "Boxing Day",
+            NamedDay::NewYearsEve => "New Year's Eve",
+        }
+    }
+
+    #[cfg(feature = "swedish")]
+    fn swedish(self) -> &'static str {
+        match self {
+            NamedDay::NewYearsDay => "Nyårsdagen",
+            NamedDay::Epiphany => "Trettondedag jul",
+            NamedDay::GoodFriday => "Långfredagen",
+            NamedDay::EasterSunday => "Påskdagen",
+            NamedDay::EasterMonday => "Annandag påsk",
+            NamedDay::AscensionDay => "Kristi himmelsfärdsdag",
+            NamedDay::Pentecost => "Pingstdagen",
+            NamedDay::MidsummerEve => "Midsommarafton",
+            NamedDay::MidsummerDay => "Midsommardagen",
+            NamedDay::StJohnsEve => "Sankt Hans afton",
+            NamedDay::StJohnsDay => "Johannes döparens dag",
+            NamedDay::ChristmasEve => "Julafton",
+            NamedDay::ChristmasDay => "Juldagen",
+            NamedDay::BoxingDay => "Annandag jul",
+            NamedDay::NewYearsEve => "Nyårsafton",
+        }
+    }
+
+    #[cfg(feature = "norwegian")]
+    fn norwegian(self) -> &'static str {
+        match self {
+            NamedDay::NewYearsDay => "Første nyttårsdag",
+            NamedDay::Epiphany => "Hellige tre kongers dag",
+            NamedDay::GoodFriday => "Langfredag",
+            NamedDay::EasterSunday => "Første påskedag",
+            NamedDay::EasterMonday => "Andre påskedag",
+            NamedDay::AscensionDay => "Kristi himmelfartsdag",
+            NamedDay::Pentecost => "Første pinsedag",
+            NamedDay::MidsummerEve => "Midtsommeraften",
+            NamedDay::MidsummerDay => "Midtsommerdagen",
+            NamedDay::StJohnsEve => "Sankthansaften",
+            NamedDay::StJohnsDay => "Sankthansdagen",
+            NamedDay::ChristmasEve => "Julaften",
+            NamedDay::ChristmasDay => "Første juledag",
+            NamedDay::BoxingDay => "Andre juledag",
+            NamedDay::NewYearsEve => "Nyttårsaften",
+        }
+    }
+
+    #[cfg(feature = "danish")]
+    fn danish(self) -> &'static str {
+        match self {
+            NamedDay::NewYearsDay => "Nytårsdag",
+            NamedDay::Epiphany => "Helligtrekongersdag",
+            NamedDay::GoodFriday => "Langfredag",
+            NamedDay::EasterSunday => "Påskedag",
+            NamedDay::EasterMonday => "Anden påskedag",
+            NamedDay::AscensionDay => "Kristi himmelfartsdag",
+            NamedDay::Pentecost => "Pinsedag",
+            NamedDay::MidsummerEve => "Midsommeraften",
+            NamedDay::MidsummerDay => "Midsommerdag",
+            NamedDay::StJohnsEve => "Sankthansaften",
+            NamedDay::StJohnsDay => "Sankthansdag",
+            NamedDay::ChristmasEve => "Juleaften",
+            NamedDay::ChristmasDay => "Juledag",
+            NamedDay::BoxingDay => "Anden juledag",
+            NamedDay::NewYearsEve => "Nytårsaften",
+        }
+    }
+
+    #[cfg(feature = "spanish")]
+    fn spanish(self) -> &'static str {
+        match self {
+            NamedDay::NewYearsDay => "Año Nuevo",
+            NamedDay::Epiphany => "Día de Reyes",
+            NamedDay::GoodFriday => "Viernes Santo",
+            NamedDay::EasterSunday => "Domingo de Resurrección",
+            NamedDay::EasterMonday => "Lunes de Pascua",
+            NamedDay::AscensionDay => "Día de la Ascensión",
+            NamedDay::Pentecost => "Pentecostés",
+            NamedDay::MidsummerEve => "Víspera de Midsommar",
+            NamedDay::MidsummerDay => "Día de Midsommar",
+            NamedDay::StJohnsEve => "Víspera de San Juan",
+            NamedDay::StJohnsDay => "Día de San Juan",
+            NamedDay::ChristmasEve => "Nochebuena",
+            NamedDay::ChristmasDay => "Navidad",
+            NamedDay::BoxingDay => "San Esteban",
+            NamedDay::NewYearsEve => "Nochevieja",
+        }
+    }
+
+    #[cfg(feature = "french")]
+    fn french(self) -> &'static str {
+        match self {
+            NamedDay::NewYearsDay => "Jour de l'an",
+            NamedDay::Epiphany => "Épiphanie",
+            NamedDay::GoodFriday => "Vendredi saint",
+            NamedDay::EasterSunday => "Pâques",
+            NamedDay::EasterMonday => "Lundi de Pâques",
+            NamedDay::AscensionDay => "Ascension",
+            NamedDay::Pentecost => "Pentecôte",
+            NamedDay::MidsummerEve => "Veille de Midsommar",
+            NamedDay::MidsummerDay => "Midsommar",
+            NamedDay::StJohnsEve => "Veille de la Saint-Jean",
+            NamedDay::StJohnsDay => "Saint-Jean",
+            NamedDay::ChristmasEve => "Réveillon de Noël",
+            NamedDay::ChristmasDay => "Noël",
+            NamedDay::BoxingDay => "Saint-Étienne",
+            NamedDay::NewYearsEve => "Saint-Sylvestre",
+        }
+    }
+}
+
+impl Holiday for NamedDay {
+    fn name(&self, language: Language) -> &str {
+        match language {
+            Language::English(_) => self.english(),
+            #[cfg(feature = "swedish")]
+            Language::Swedish(_) => self.swedish(),
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => self.norwegian(),
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => self.danish(),
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => self.spanish(),
+            #[cfg(feature = "french")]
+            Language::French(_) => self.french(),
+        }
+    }
+
+    fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        let date = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+        let after_easter = |days| easter_sunday(year)?.checked_add_days(Days::new(days));
+
+        match self {
+            NamedDay::NewYearsDay => date(1, 1),
+            NamedDay::Epiphany => date(1, 6),
+            NamedDay::GoodFriday => easter_sunday(year)?.checked_sub_days(Days::new(2)),
+            NamedDay::EasterSunday => easter_sunday(year),
+            NamedDay::EasterMonday => after_easter(1),
+            NamedDay::AscensionDay => after_easter(39),
+            NamedDay::Pentecost => after_easter(49),
+            NamedDay::MidsummerEve => date(6, 19).map(next_friday),
+            NamedDay::MidsummerDay => date(6, 19).map(|first| next_friday(first) + Days::new(1)),
+            NamedDay::StJohnsEve => date(6, 23),
+            NamedDay::StJohnsDay => date(6, 24),
+            NamedDay::ChristmasEve => date(12, 24),
+            NamedDay::ChristmasDay => date(12, 25),
+            NamedDay::BoxingDay => date(12, 26),
+            NamedDay::NewYearsEve => date(12, 31),
+        }
+    }
+}
+
+impl fmt::Display for NamedDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.english())
+    }
+}
+
+/// Easter Sunday in the Gregorian calendar, by the anonymous Gregorian algorithm.
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let h = (19 * a + b - b / 4 - (b - (b + 8) / 25 + 1) / 3 + 15).rem_euclid(30);
+    let l = (32 + 2 * (b % 4) + 2 * (c / 4) - h - c % 4).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// The first Friday on or after the date.
+fn next_friday(date: NaiveDate) -> NaiveDate {
+    date + Days::new(chrono::Weekday::Fri.days_since(date.weekday()).into())
+}
+
+/// Registers a day as a named date, labelled with its name in every enabled language and
+/// replacing any registered under its name in the default language.
+#[cfg(feature = "std")]
+pub fn register(holiday: &'static dyn Holiday) {
+    let date = Language::all().into_iter().fold(
+        NamedDate::new(
+            holiday.name(Language::default()),
+            Recurrence::Yearly(holiday),
+        ),
+        |date, language| date.with_label(language, holiday.name(language)),
+    );
+
+    named::register(date);
+}
+
+/// Registers every [`NamedDay`].
+#[cfg(feature = "std")]
+pub fn register_all() {
+    for day in &NamedDay::ALL {
+        register(day);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::named::{NamedTime, Unregister, unregister};
+    #[cfg(feature = "std")]
+    use chrono::DateTime;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn computes_dates() {
+        assert_eq!(
+            NamedDay::EasterSunday.date_in(2024),
+            Some(date("2024-03-31"))
+        );
+        assert_eq!(
+            NamedDay::EasterSunday.date_in(2025),
+            Some(date("2025-04-20"))
+        );
+        assert_eq!(NamedDay::GoodFriday.date_in(2026), Some(date("2026-04-03")));
+        assert_eq!(
+            NamedDay::AscensionDay.date_in(2025),
+            Some(date("2025-05-29"))
+        );
+        assert_eq!(NamedDay::Pentecost.date_in(2025), Some(date("2025-06-08")));
+        assert_eq!(
+            NamedDay::MidsummerEve.date_in(2025),
+            Some(date("2025-06-20"))
+        );
+        assert_eq!(
+            NamedDay::MidsummerDay.date_in(2026),
+            Some(date("2026-06-20"))
+        );
+        assert_eq!(NamedDay::StJohnsEve.date_in(2025), Some(date("2025-06-23")));
+        assert_eq!(NamedDay::StJohnsDay.date_in(2026), Some(date("2026-06-24")));
+        assert_eq!(
+            NamedDay::ChristmasEve.date_in(2025),
+            Some(date("2025-12-24"))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug)]
+    struct TestFounders;
+
+    #[cfg(feature = "std")]
+    impl Holiday for TestFounders {
+        fn name(&self, _: Language) -> &str {
+            "Test Founders' Day"
+        }
+
+        fn date_in(&self, year: i32) -> Option<NaiveDate> {
+            // Every other year, on the first Monday of May
+            let first = NaiveDate::from_ymd_opt(year, 5, 1)?;
+            let days = chrono::Weekday::Mon.days_since(first.weekday());
+            (year % 2 == 0).then(|| first + Days::new(days.into()))
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn registers_days() {
+        static FOUNDERS: TestFounders = TestFounders;
+        let _registrations = (
+            Unregister("New Year's Eve"),
+            Unregister("Test Founders' Day"),
+        );
+        register(&NamedDay::NewYearsEve);
+        register(&FOUNDERS);
+
+        let relative_to = DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc();
+        let next = |label: &str| {
+            let named: NamedTime = label.parse().unwrap();
+            named.to_chrono_min(relative_to).date_naive()
+        };
+
+        assert_eq!(next("new year's eve"), date("2025-12-31"));
+        assert_eq!(next("Test Founders' Day"), date("2026-05-04"));
+        #[cfg(feature = "swedish")]
+        assert_eq!(next("nyårsafton"), date("2025-12-31"));
+        #[cfg(feature = "norwegian")]
+        {
+            let _registration = Unregister("St John's Eve");
+            register(&NamedDay::StJohnsEve);
+
+            assert_eq!(next("sankthansaften"), date("2026-06-23"));
+        }
+
+        assert!(unregister("New Year's Eve"));
+        assert!(unregister("Test Founders' Day"));
+    }
+}
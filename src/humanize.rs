@@ -0,0 +1,256 @@
+//! Human-readable distances to values, such as "in 3 days" or "2 hours ago".
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use chrono::{DateTime, Utc};
+
+use crate::{Time, language::Language};
+
+/// The smallest unit a humanized distance is counted in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Granularity {
+    Days,
+    Hours,
+    #[default]
+    Minutes,
+}
+
+impl Granularity {
+    const ALL: [Granularity; 3] = [Granularity::Days, Granularity::Hours, Granularity::Minutes];
+
+    fn seconds(self) -> i64 {
+        match self {
+            Granularity::Days => 24 * 60 * 60,
+            Granularity::Hours => 60 * 60,
+            Granularity::Minutes => 60,
+        }
+    }
+
+    /// The name of the unit in the given language, singular for an amount of one.
+    fn name(self, amount: i64, language: Language) -> &'static str {
+        let singular = amount == 1;
+
+        match language {
+            #[cfg(feature = "swedish")]
+            Language::Swedish(_) => match (self, singular) {
+                (Granularity::Days, true) => "dag",
+                (Granularity::Days, false) => "dagar",
+                (Granularity::Hours, true) => "timme",
+                (Granularity::Hours, false) => "timmar",
+                (Granularity::Minutes, true) => "minut",
+                (Granularity::Minutes, false) => "minuter",
+            },
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => match (self, singular) {
+                (Granularity::Days, true) => "dag",
+                (Granularity::Days, false) => "dager",
+                (Granularity::Hours, true) => "time",
+                (Granularity::Hours, false) => "timer",
+                (Granularity::Minutes, true) => "minutt",
+                (Granularity::Minutes, false) => "minutter",
+            },
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => match (self, singular) {
+                (Granularity::Days, true) => "dag",
+                (Granularity::Days, false) => "dage",
+                (Granularity::Hours, true) => "time",
+                (Granularity::Hours, false) => "timer",
+                (Granularity::Minutes, true) => "minut",
+                (Granularity::Minutes, false) => "minutter",
+            },
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => match (self, singular) {
+                (Granularity::Days, true) => "día",
+                (Granularity::Days, false) => "días",
+                (Granularity::Hours, true) => "hora",
+                (Granularity::Hours, false) => "horas",
+                (Granularity::Minutes, true) => "minuto",
+                (Granularity::Minutes, false) => "minutos",
+            },
+            #[cfg(feature = "french")]
+            Language::French(_) => match (self, singular) {
+                (Granularity::Days, true) => "jour",
+                (Granularity::Days, false) => "jours",
+                (Granularity::Hours, true) => "heure",
+                (Granularity::Hours, false) => "heures",
+                (Granularity::Minutes, true) => "minute",
+                (Granularity::Minutes, false) => "minutes",
+            },
+            Language::English(_) => match (self, singular) {
+                (Granularity::Days, true) => "day",
+                (Granularity::Days, false) => "days",
+                (Granularity::Hours, true) => "hour",
+                (Granularity::Hours, false) => "hours",
+                (Granularity::Minutes, true) => "minute",
+                (Granularity::Minutes, false) => "minutes",
+            },
+        }
+    }
+}
+
+/// Phrases a distance in the given language, e.g. "in 3 days" or "för 2 timmar sedan".
+fn phrase(amount: i64, unit: &str, ahead: bool, language: Language) -> String {
+    match (language, ahead) {
+        #[cfg(feature = "swedish")]
+        (Language::Swedish(_), true) => format!("om {amount} {unit}"),
+        #[cfg(feature = "swedish")]
+        (Language::Swedish(_), false) => format!("för {amount} {unit} sedan"),
+        #[cfg(feature = "norwegian")]
+        (Language::Norwegian(_), true) => format!("om {amount} {unit}"),
+        #[cfg(feature = "norwegian")]
+        (Language::Norwegian(_), false) => format!("for {amount} {unit} siden"),
+        #[cfg(feature = "danish")]
+        (Language::Danish(_), true) => format!("om {amount} {unit}"),
+        #[cfg(feature = "danish")]
+        (Language::Danish(_), false) => format!("for {amount} {unit} siden"),
+        #[cfg(feature = "spanish")]
+        (Language::Spanish(_), true) => format!("en {amount} {unit}"),
+        #[cfg(feature = "spanish")]
+        (Language::Spanish(_), false) => format!("hace {amount} {unit}"),
+        #[cfg(feature = "french")]
+        (Language::French(_), true) => format!("dans {amount} {unit}"),
+        #[cfg(feature = "french")]
+        (Language::French(_), false) => format!("il y a {amount} {unit}"),
+        (Language::English(_), true) => format!("in {amount} {unit}"),
+        (Language::English(_), false) => format!("{amount} {unit} ago"),
+    }
+}
+
+/// The word for a value that is under way.
+fn now(language: Language) -> &'static str {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => "nu",
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => "nå",
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => "nu",
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => "ahora",
+        #[cfg(feature = "french")]
+        Language::French(_) => "maintenant",
+        Language::English(_) => "now",
+    }
+}
+
+impl Time {
+    /// Describes how far away the value is in the given language, e.g. "in 3 days", "om 3
+    /// dagar" or "2 hours ago", counting down to the minute.
+    ///
+    /// See [`Time::humanize_with`].
+    pub fn humanize(self, relative_to: DateTime<Utc>, language: Language) -> String {
+        self.humanize_with(relative_to, language, Granularity::default())
+    }
+
+    /// Describes how far away the value is in the given language, counting in units no smaller
+    /// than the granularity.
+    ///
+    /// Values ahead count to their earliest timestamp, and values behind count from it. Values
+    /// under way, or less than half a unit away, are "now". The distance is given in the largest
+    /// whole unit it spans, rounded to the nearest amount, so 36 hours is "in 2 days".
+    pub fn humanize_with(
+        self,
+        relative_to: DateTime<Utc>,
+        language: Language,
+        granularity: Granularity,
+    ) -> String {
+        let min = self.clone().to_chrono_min(relative_to);
+        let max = self.to_chrono_max(relative_to);
+
+        let ahead = relative_to < min;
+
+        if !ahead && (relative_to < max || relative_to == min) {
+            return now(language).to_string();
+        }
+
+        let seconds = (min - relative_to).num_seconds().abs();
+
+        let unit = Granularity::ALL
+            .into_iter()
+            .filter(|unit| *unit <= granularity)
+            .find(|unit| seconds >= unit.seconds())
+            .unwrap_or(granularity);
+
+        let amount = (seconds + unit.seconds() / 2) / unit.seconds();
+
+        match amount {
+            0 => now(language).to_string(),
+            amount => phrase(amount, unit.name(amount, language), ahead, language),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{offset::Unit, relative::Relative};
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn humanizes_in_english() {
+        // Tuesday July 29th, 2025
+        let relative_to = at("2025-07-29T10:30:05Z");
+        let humanize = |time: Time| time.humanize(relative_to, Language::default());
+
+        assert_eq!(
+            humanize(Time::Relative(Relative::offset(3, Unit::Days))),
+            "in 3 days"
+        );
+        assert_eq!(
+            humanize(Time::DateTime(at("2025-07-29T08:30:00Z"))),
+            "2 hours ago"
+        );
+        assert_eq!(
+            humanize(Time::DateTime(at("2025-07-29T10:31:05Z"))),
+            "in 1 minute"
+        );
+        assert_eq!(humanize(Time::Relative(Relative::today())), "now");
+        assert_eq!(humanize(Time::DateTime(relative_to)), "now");
+    }
+
+    #[test]
+    fn respects_granularity() {
+        let relative_to = at("2025-07-29T10:30:05Z");
+        let tomorrow = Time::Relative(Relative::tomorrow());
+        let humanize = |granularity| {
+            tomorrow
+                .clone()
+                .humanize_with(relative_to, Language::default(), granularity)
+        };
+
+        assert_eq!(humanize(Granularity::Minutes), "in 13 hours");
+        assert_eq!(humanize(Granularity::Hours), "in 13 hours");
+        assert_eq!(humanize(Granularity::Days), "in 1 day");
+
+        let soon = Time::DateTime(at("2025-07-29T10:45:05Z"));
+        assert_eq!(
+            soon.humanize_with(relative_to, Language::default(), Granularity::Hours),
+            "now"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn humanizes_in_swedish() {
+        use crate::language::Swedish;
+
+        let relative_to = at("2025-07-29T10:30:05Z");
+        let swedish = Language::Swedish(Swedish::default());
+        let humanize = |time: Time| time.humanize(relative_to, swedish);
+
+        assert_eq!(
+            humanize(Time::Relative(Relative::offset(3, Unit::Days))),
+            "om 3 dagar"
+        );
+        assert_eq!(
+            humanize(Time::Relative(Relative::yesterday())),
+            "för 1 dag sedan"
+        );
+        assert_eq!(humanize(Time::Relative(Relative::this_week())), "nu");
+    }
+}
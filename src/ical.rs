@@ -0,0 +1,366 @@
+//! Conversion to and from iCalendar (RFC 5545) recurrence rules.
+//!
+//! Requires the `ical` feature. Only rules with a direct counterpart in the crate are supported,
+//! so parts like `COUNT`, `UNTIL` or multiple `BYDAY` values are rejected rather than dropped.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::num::NonZeroU32;
+
+use chrono::{NaiveDate, NaiveTime};
+use derive_more::Display;
+
+use crate::{
+    Time,
+    exact::{ExactDate, ExactDay, ExactMonth},
+    language::Language,
+    month::Month,
+    ordinal::{Ordinal, OrdinalWeekday},
+    recurrence::Recurring,
+    relative::Relative,
+    weekday::Weekday,
+};
+
+/// Errors produced when reading a recurrence rule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
+pub enum RRuleError {
+    #[display("malformed recurrence rule '{_0}'")]
+    Malformed(String),
+    #[display("recurrence rule '{_0}' has no counterpart")]
+    Unsupported(String),
+}
+
+impl core::error::Error for RRuleError {}
+
+const WEEKDAYS: [(&str, chrono::Weekday); 7] = [
+    ("MO", chrono::Weekday::Mon),
+    ("TU", chrono::Weekday::Tue),
+    ("WE", chrono::Weekday::Wed),
+    ("TH", chrono::Weekday::Thu),
+    ("FR", chrono::Weekday::Fri),
+    ("SA", chrono::Weekday::Sat),
+    ("SU", chrono::Weekday::Sun),
+];
+
+const ORDINALS: [(i8, Ordinal); 6] = [
+    (1, Ordinal::First),
+    (2, Ordinal::Second),
+    (3, Ordinal::Third),
+    (4, Ordinal::Fourth),
+    (5, Ordinal::Fifth),
+    (-1, Ordinal::Last),
+];
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    let weekday = weekday.to_chrono();
+
+    WEEKDAYS.iter().find(|(_, x)| *x == weekday).unwrap().0
+}
+
+fn ordinal_number(ordinal: Ordinal) -> i8 {
+    ORDINALS.iter().find(|(_, x)| *x == ordinal).unwrap().0
+}
+
+fn month_from_number(month: u8) -> Month {
+    let first = NaiveDate::from_ymd_opt(2000, month.into(), 1).unwrap();
+
+    Month::from_chrono(
+        first.and_time(NaiveTime::MIN).and_utc(),
+        false,
+        Language::default(),
+    )
+}
+
+/// The parts of a recurrence rule the crate can represent.
+#[derive(Default)]
+struct Rule {
+    freq: String,
+    interval: Option<NonZeroU32>,
+    by_day: Option<(Option<Ordinal>, chrono::Weekday)>,
+    by_month: Option<ExactMonth>,
+    by_month_day: Option<ExactDay>,
+    dtstart: Option<NaiveDate>,
+}
+
+impl Rule {
+    /// Parses a bare rule such as `FREQ=WEEKLY;BYDAY=MO`, or content lines with an optional
+    /// `DTSTART` followed by an `RRULE`.
+    fn parse(input: &str) -> Result<Self, RRuleError> {
+        let malformed = || RRuleError::Malformed(input.to_string());
+        let unsupported = || RRuleError::Unsupported(input.to_string());
+        let mut rule = Rule::default();
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let upper = line.to_uppercase();
+
+            if upper.starts_with("DTSTART") {
+                let (_, value) = upper.split_once(':').ok_or_else(malformed)?;
+                let date = value.get(..8).ok_or_else(malformed)?;
+                rule.dtstart =
+                    Some(NaiveDate::parse_from_str(date, "%Y%m%d").map_err(|_| malformed())?);
+                continue;
+            }
+
+            let parts = upper.strip_prefix("RRULE:").unwrap_or(&upper);
+
+            for part in parts.split(';') {
+                let (key, value) = part.split_once('=').ok_or_else(malformed)?;
+
+                match key {
+                    "FREQ" => rule.freq = value.to_string(),
+                    "INTERVAL" => rule.interval = Some(value.parse().map_err(|_| malformed())?),
+                    "BYDAY" => {
+                        let split = value.len().checked_sub(2).ok_or_else(malformed)?;
+                        let (ordinal, code) = value.split_at(split);
+                        let weekday = WEEKDAYS
+                            .iter()
+                            .find(|(x, _)| *x == code)
+                            .ok_or_else(malformed)?
+                            .1;
+                        let ordinal = match ordinal.trim_start_matches('+') {
+                            "" => None,
+                            ordinal => {
+                                let number: i8 = ordinal.parse().map_err(|_| unsupported())?;
+                                let found = ORDINALS.iter().find(|(x, _)| *x == number);
+                                Some(found.ok_or_else(unsupported)?.1)
+                            }
+                        };
+                        rule.by_day = Some((ordinal, weekday));
+                    }
+                    "BYMONTH" => {
+                        let month = value.parse().map_err(|_| unsupported())?;
+                        rule.by_month = Some(ExactMonth::try_new(month).map_err(|_| malformed())?);
+                    }
+                    "BYMONTHDAY" => {
+                        let day = value.parse().map_err(|_| unsupported())?;
+                        rule.by_month_day = Some(ExactDay::try_new(day).map_err(|_| malformed())?);
+                    }
+                    "WKST" => {}
+                    _ => return Err(unsupported()),
+                }
+            }
+        }
+
+        match rule.freq.as_str() {
+            "" => Err(malformed()),
+            _ if rule.interval.is_some_and(|x| x.get() > 1) && rule.freq != "DAILY" => {
+                Err(unsupported())
+            }
+            _ => Ok(rule),
+        }
+    }
+}
+
+impl Recurring {
+    /// Converts to a recurrence rule, e.g. `FREQ=WEEKLY;BYDAY=MO`.
+    ///
+    /// Every N days depends on its start, so it's written as a `DTSTART` content line followed by
+    /// an `RRULE` one. Note that iCalendar skips months too short for a monthly day, where
+    /// [`Recurring`] falls back to the last day of the month.
+    pub fn to_rrule(&self) -> String {
+        match self {
+            Recurring::Weekly(weekday) => format!("FREQ=WEEKLY;BYDAY={}", weekday_code(*weekday)),
+            Recurring::Monthly(day) => format!("FREQ=MONTHLY;BYMONTHDAY={day}"),
+            Recurring::Yearly(month, day) => {
+                format!("FREQ=YEARLY;BYMONTH={month};BYMONTHDAY={day}")
+            }
+            Recurring::EveryNDays { days, from } => format!(
+                "DTSTART;VALUE=DATE:{}\nRRULE:FREQ=DAILY;INTERVAL={days}",
+                from.format("%Y%m%d")
+            ),
+        }
+    }
+
+    /// Reads a recurrence rule as written by [`Recurring::to_rrule`], with or without an
+    /// `RRULE:` prefix.
+    ///
+    /// Daily rules with an interval above one need a `DTSTART` line to anchor them.
+    pub fn from_rrule(rrule: &str) -> Result<Self, RRuleError> {
+        let unsupported = || RRuleError::Unsupported(rrule.to_string());
+        let rule = Rule::parse(rrule)?;
+
+        match (
+            rule.freq.as_str(),
+            rule.by_day,
+            rule.by_month,
+            rule.by_month_day,
+        ) {
+            ("WEEKLY", Some((None, weekday)), None, None) => Ok(Recurring::Weekly(
+                Weekday::from_chrono_weekday(weekday, Language::default()),
+            )),
+            ("MONTHLY", None, None, Some(day)) => Ok(Recurring::Monthly(day)),
+            ("YEARLY", None, Some(month), Some(day)) => Ok(Recurring::Yearly(month, day)),
+            ("DAILY", None, None, None) => {
+                let days = rule.interval.unwrap_or(NonZeroU32::MIN);
+                let from = match rule.dtstart {
+                    Some(from) => from,
+                    None if days == NonZeroU32::MIN => NaiveDate::default(),
+                    None => return Err(unsupported()),
+                };
+
+                Ok(Recurring::EveryNDays { days, from })
+            }
+            _ => Err(unsupported()),
+        }
+    }
+}
+
+impl Time {
+    /// Converts a recurring value to a recurrence rule, or `None` when it doesn't recur.
+    ///
+    /// Weekdays become weekly rules, dates without a year become yearly rules and ordinal
+    /// weekdays become monthly or yearly rules, e.g. `FREQ=MONTHLY;BYDAY=-1FR` for "Last Friday of the month".
+    pub fn to_rrule(&self) -> Option<String> {
+        match self {
+            Time::Weekday(weekday) => Some(Recurring::Weekly(*weekday).to_rrule()),
+            Time::Relative(Relative::Date(ExactDate::WithoutYear(month, day))) => {
+                Some(Recurring::Yearly(*month, *day).to_rrule())
+            }
+            Time::OrdinalWeekday(x) => {
+                let by_day = format!(
+                    "BYDAY={}{}",
+                    ordinal_number(x.ordinal()),
+                    weekday_code(x.weekday())
+                );
+
+                Some(match x.month() {
+                    Some(month) => format!(
+                        "FREQ=YEARLY;BYMONTH={};{by_day}",
+                        month.to_chrono().number_from_month()
+                    ),
+                    None => format!("FREQ=MONTHLY;{by_day}"),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Reads a recurrence rule as a weekday, a date without a year or an ordinal weekday.
+    ///
+    /// Rules without such a counterpart, like monthly days, are rejected; use
+    /// [`Recurring::from_rrule`] for those.
+    pub fn from_rrule(rrule: &str) -> Result<Self, RRuleError> {
+        let rule = Rule::parse(rrule)?;
+        let weekday = |weekday| Weekday::from_chrono_weekday(weekday, Language::default());
+
+        match (
+            rule.freq.as_str(),
+            rule.by_day,
+            rule.by_month,
+            rule.by_month_day,
+        ) {
+            ("WEEKLY", Some((None, x)), None, None) => Ok(Time::Weekday(weekday(x))),
+            ("YEARLY", None, Some(month), Some(day)) => Ok(Time::Relative(Relative::Date(
+                ExactDate::WithoutYear(month, day),
+            ))),
+            ("MONTHLY", Some((Some(ordinal), x)), None, None) => Ok(Time::OrdinalWeekday(
+                OrdinalWeekday::new(ordinal, weekday(x), None),
+            )),
+            ("YEARLY", Some((Some(ordinal), x)), Some(month), None) => Ok(Time::OrdinalWeekday(
+                OrdinalWeekday::new(ordinal, weekday(x), Some(month_from_number(month.into()))),
+            )),
+            _ => Err(RRuleError::Unsupported(rrule.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_recurring() {
+        let every_3_days = Recurring::EveryNDays {
+            days: NonZeroU32::new(3).unwrap(),
+            from: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
+        };
+        let cases = [
+            (Recurring::Weekly(Weekday::monday()), "FREQ=WEEKLY;BYDAY=MO"),
+            (
+                Recurring::Monthly(ExactDay::new(25)),
+                "FREQ=MONTHLY;BYMONTHDAY=25",
+            ),
+            (
+                Recurring::Yearly(ExactMonth::new(12), ExactDay::new(24)),
+                "FREQ=YEARLY;BYMONTH=12;BYMONTHDAY=24",
+            ),
+            (
+                every_3_days,
+                "DTSTART;VALUE=DATE:20250701\nRRULE:FREQ=DAILY;INTERVAL=3",
+            ),
+        ];
+
+        for (recurring, rrule) in cases {
+            assert_eq!(recurring.to_rrule(), rrule);
+            assert_eq!(Recurring::from_rrule(rrule), Ok(recurring));
+        }
+
+        assert_eq!(
+            Recurring::from_rrule("RRULE:freq=weekly;byday=fr;wkst=mo"),
+            Ok(Recurring::Weekly(Weekday::friday()))
+        );
+    }
+
+    #[test]
+    fn converts_times() {
+        let cases = [
+            (Time::Weekday(Weekday::monday()), "FREQ=WEEKLY;BYDAY=MO"),
+            (
+                Time::Relative(Relative::Date(ExactDate::new(None, 12, 24))),
+                "FREQ=YEARLY;BYMONTH=12;BYMONTHDAY=24",
+            ),
+            (
+                Time::OrdinalWeekday(OrdinalWeekday::new(Ordinal::Last, Weekday::friday(), None)),
+                "FREQ=MONTHLY;BYDAY=-1FR",
+            ),
+            (
+                Time::OrdinalWeekday(OrdinalWeekday::new(
+                    Ordinal::First,
+                    Weekday::monday(),
+                    Some(Month::march()),
+                )),
+                "FREQ=YEARLY;BYMONTH=3;BYDAY=1MO",
+            ),
+        ];
+
+        for (time, rrule) in cases {
+            assert_eq!(time.to_rrule().as_deref(), Some(rrule));
+            assert_eq!(Time::from_rrule(rrule), Ok(time));
+        }
+
+        assert_eq!(
+            Time::Relative(Relative::Date(ExactDate::new(Some(2025), 12, 24))).to_rrule(),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_rules() {
+        for rrule in [
+            "FREQ=WEEKLY;BYDAY=MO,TU",
+            "FREQ=WEEKLY;BYDAY=MO;COUNT=3",
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO",
+            "FREQ=DAILY;INTERVAL=3",
+            "FREQ=MONTHLY;BYDAY=6MO",
+        ] {
+            assert!(
+                matches!(
+                    Recurring::from_rrule(rrule),
+                    Err(RRuleError::Unsupported(_))
+                ),
+                "{rrule}"
+            );
+        }
+
+        assert!(matches!(
+            Recurring::from_rrule("FREQ=YEARLY;BYMONTH=13;BYMONTHDAY=1"),
+            Err(RRuleError::Malformed(_))
+        ));
+        assert!(matches!(
+            Time::from_rrule("FREQ=MONTHLY;BYMONTHDAY=25"),
+            Err(RRuleError::Unsupported(_))
+        ));
+    }
+}
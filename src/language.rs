@@ -1,20 +1,29 @@
 //! Language variants for time representations.
 
+use alloc::{vec, vec::Vec};
 use derive_more::Display;
+#[cfg(feature = "schemars")]
 use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::traits::WithLanguage;
 
-/// Swedish language representation, in English or Swedish.
+/// Swedish language representation, spelled in each enabled language.
 #[cfg(feature = "swedish")]
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum Swedish {
     #[default]
     Swedish,
     Svenska,
+    #[cfg(any(feature = "norwegian", feature = "danish"))]
+    Svensk,
+    #[cfg(feature = "spanish")]
+    Sueco,
+    #[cfg(feature = "french")]
+    Suédois,
 }
 
 #[cfg(feature = "swedish")]
@@ -22,20 +31,174 @@ impl WithLanguage for Swedish {
     fn with_language(&self, language: Language) -> Self {
         match language {
             Language::Swedish(_) => Self::Svenska,
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => Self::Svensk,
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => Self::Svensk,
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => Self::Sueco,
+            #[cfg(feature = "french")]
+            Language::French(_) => Self::Suédois,
             Language::English(_) => Self::Swedish,
         }
     }
 }
 
-/// English language representation, in English or Swedish.
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
+/// Norwegian (Bokmål) language representation, spelled in each enabled language.
+#[cfg(feature = "norwegian")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Norwegian {
+    #[default]
+    Norwegian,
+    #[cfg(feature = "swedish")]
+    Norska,
+    Norsk,
+    #[cfg(feature = "spanish")]
+    Noruego,
+    #[cfg(feature = "french")]
+    Norvégien,
+}
+
+#[cfg(feature = "norwegian")]
+impl WithLanguage for Norwegian {
+    fn with_language(&self, language: Language) -> Self {
+        match language {
+            #[cfg(feature = "swedish")]
+            Language::Swedish(_) => Self::Norska,
+            Language::Norwegian(_) => Self::Norsk,
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => Self::Norsk,
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => Self::Noruego,
+            #[cfg(feature = "french")]
+            Language::French(_) => Self::Norvégien,
+            Language::English(_) => Self::Norwegian,
+        }
+    }
+}
+
+/// Danish language representation, spelled in each enabled language.
+#[cfg(feature = "danish")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Danish {
+    #[default]
+    Danish,
+    #[cfg(feature = "swedish")]
+    Danska,
+    Dansk,
+    #[cfg(feature = "spanish")]
+    Danés,
+    #[cfg(feature = "french")]
+    Danois,
+}
+
+#[cfg(feature = "danish")]
+impl WithLanguage for Danish {
+    fn with_language(&self, language: Language) -> Self {
+        match language {
+            #[cfg(feature = "swedish")]
+            Language::Swedish(_) => Self::Danska,
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => Self::Dansk,
+            Language::Danish(_) => Self::Dansk,
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => Self::Danés,
+            #[cfg(feature = "french")]
+            Language::French(_) => Self::Danois,
+            Language::English(_) => Self::Danish,
+        }
+    }
+}
+
+/// Spanish language representation, spelled in each enabled language.
+#[cfg(feature = "spanish")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Spanish {
+    #[default]
+    Spanish,
+    #[cfg(feature = "swedish")]
+    Spanska,
+    #[cfg(any(feature = "norwegian", feature = "danish"))]
+    Spansk,
+    Español,
+    #[cfg(feature = "french")]
+    Espagnol,
+}
+
+#[cfg(feature = "spanish")]
+impl WithLanguage for Spanish {
+    fn with_language(&self, language: Language) -> Self {
+        match language {
+            #[cfg(feature = "swedish")]
+            Language::Swedish(_) => Self::Spanska,
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => Self::Spansk,
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => Self::Spansk,
+            Language::Spanish(_) => Self::Español,
+            #[cfg(feature = "french")]
+            Language::French(_) => Self::Espagnol,
+            Language::English(_) => Self::Spanish,
+        }
+    }
+}
+
+/// French language representation, spelled in each enabled language.
+#[cfg(feature = "french")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum French {
+    #[default]
+    French,
+    #[cfg(feature = "swedish")]
+    Franska,
+    #[cfg(any(feature = "norwegian", feature = "danish"))]
+    Fransk,
+    #[cfg(feature = "spanish")]
+    Francés,
+    Français,
+}
+
+#[cfg(feature = "french")]
+impl WithLanguage for French {
+    fn with_language(&self, language: Language) -> Self {
+        match language {
+            #[cfg(feature = "swedish")]
+            Language::Swedish(_) => Self::Franska,
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => Self::Fransk,
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => Self::Fransk,
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => Self::Francés,
+            Language::French(_) => Self::Français,
+            Language::English(_) => Self::French,
+        }
+    }
+}
+
+/// English language representation, spelled in each enabled language.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum English {
     #[default]
     English,
     #[cfg(feature = "swedish")]
     Engelska,
+    #[cfg(any(feature = "norwegian", feature = "danish"))]
+    Engelsk,
+    #[cfg(feature = "spanish")]
+    Inglés,
+    #[cfg(feature = "french")]
+    Anglais,
 }
 
 impl WithLanguage for English {
@@ -43,18 +206,35 @@ impl WithLanguage for English {
         match language {
             #[cfg(feature = "swedish")]
             Language::Swedish(_) => Self::Engelska,
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => Self::Engelsk,
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => Self::Engelsk,
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => Self::Inglés,
+            #[cfg(feature = "french")]
+            Language::French(_) => Self::Anglais,
             Language::English(_) => Self::English,
         }
     }
 }
-
 /// Language selection for time representations.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-#[serde(untagged)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Language {
     English(English),
     #[cfg(feature = "swedish")]
     Swedish(Swedish),
+    #[cfg(feature = "norwegian")]
+    Norwegian(Norwegian),
+    #[cfg(feature = "danish")]
+    Danish(Danish),
+    #[cfg(feature = "spanish")]
+    Spanish(Spanish),
+    #[cfg(feature = "french")]
+    French(French),
 }
 
 impl Default for Language {
@@ -63,8 +243,31 @@ impl Default for Language {
     }
 }
 
+impl Language {
+    /// Every language enabled in this build, in its default representation.
+    pub fn all() -> Vec<Language> {
+        vec![
+            Self::English(English::default()),
+            #[cfg(feature = "swedish")]
+            Self::Swedish(Swedish::default()),
+            #[cfg(feature = "norwegian")]
+            Self::Norwegian(Norwegian::default()),
+            #[cfg(feature = "danish")]
+            Self::Danish(Danish::default()),
+            #[cfg(feature = "spanish")]
+            Self::Spanish(Spanish::default()),
+            #[cfg(feature = "french")]
+            Self::French(French::default()),
+        ]
+    }
+}
+
 impl WithLanguage for Language {
     fn with_language(&self, language: Language) -> Self {
         language
     }
+
+    fn language(&self) -> Language {
+        *self
+    }
 }
@@ -6,216 +6,692 @@
 //!
 //! # Features
 //!
+//! - `std` (default): Enables conversions relative to the current time and the registry of named
+//!   dates. Without it the crate is `no_std` and only needs `alloc`.
+//! - `serde` (default): Implements `Serialize` and `Deserialize` for all types.
+//! - `schemars` (default): Implements `JsonSchema` for all types. Enables `serde`.
 //! - `swedish` (default): Enables Swedish language variants for all time types.
+//! - `calendar-system`: Enables dates in the ISO week calendar and a trait for others.
+//! - `time`: Enables conversions to and from the `time` crate's dates and timestamps.
+//! - `chrono-tz`: Enables IANA zones such as `Europe/Stockholm` on times of day.
+//! - `testing`: Exposes value generators and `proptest` strategies for every type. Enables `std`.
 
-use chrono::{DateTime, Months, NaiveTime, Utc};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::{cmp::Ordering, fmt, ops::Range, str::FromStr};
+
+use chrono::{DateTime, Days, NaiveTime, Utc};
 use derive_more::Display;
+#[cfg(feature = "schemars")]
 use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    exact::ExactDateTime,
+    candidate::Candidate,
+    exact::{ExactDateTime, ExactTime, in_offset},
     language::Language,
-    month::{
-        April, August, December, February, January, July, June, March, May, Month, November,
-        October, September,
-    },
-    relative::{Relative, ThisMonth, ThisWeek, Today, Tomorrow},
-    traits::FromLanguage,
-    weekday::{Friday, Monday, Saturday, Sunday, Thursday, Tuesday, Wednesday, Weekday},
+    month::Month,
+    named::NamedTime,
+    options::{FromChronoOptions, Options},
+    ordinal::OrdinalWeekday,
+    relative::Relative,
+    traits::WithLanguage,
+    weekday::{LastWeekday, Weekday, WeekdayTime},
 };
 
+pub mod bucket;
+pub mod builder;
+pub mod business;
+pub mod calendar;
+#[cfg(feature = "calendar-system")]
+pub mod calendar_system;
+pub mod candidate;
+pub mod day_part;
+pub mod deadline;
+pub mod dedup;
+pub mod duration;
+pub mod dynamic;
 pub mod exact;
+pub mod holiday;
+pub mod humanize;
+#[cfg(feature = "ical")]
+pub mod ical;
 pub mod language;
+pub mod localized;
+mod macros;
 pub mod month;
+pub mod named;
+pub mod offset;
+pub mod options;
+pub mod ordinal;
+pub mod parse;
+pub mod period;
+pub mod quarter;
+pub mod recurrence;
 pub mod relative;
+pub mod resolved;
+pub mod resolver;
+pub mod span;
+pub mod strftime;
+#[cfg(feature = "serde")]
+pub mod structured;
+#[cfg(feature = "serde")]
+pub mod tagged;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+#[cfg(feature = "time")]
+mod time_crate;
 pub mod traits;
+pub mod tz;
+pub mod unix;
+#[cfg(feature = "serde")]
+mod untagged;
+pub mod week_number;
 pub mod weekday;
+pub mod weekday_set;
 
 /// A time representation supporting relative, named, exact, and absolute forms.
 ///
 /// Serialises as an untagged enum, allowing natural JSON representations like
-/// `"Today"`, `"Monday"`, `"2025-07-29T10:30:05Z"`, etc.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-#[serde(untagged)]
+/// `"Today"`, `"Monday"`, `"2025-07-29T10:30:05Z"`, etc. Strings matching none of them fail to
+/// deserialise with the closest keyword suggested, e.g. "did you mean 'Tuesday'?".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Time {
     Relative(Relative),
     Weekday(Weekday),
+    WeekdayTime(WeekdayTime),
     Month(Month),
     Exact(ExactDateTime),
     DateTime(DateTime<Utc>),
+    Named(NamedTime),
+    OrdinalWeekday(OrdinalWeekday),
+    Boundary(Boundary),
+    At(At),
+    LastWeekday(LastWeekday),
+}
+
+/// The kind of a [`Time`], without its contents.
+///
+/// Discriminants are stable across releases and never reused, so they are safe to store or pass
+/// across FFI boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[repr(u8)]
+pub enum TimeKind {
+    Relative = 0,
+    Weekday = 1,
+    WeekdayTime = 2,
+    Month = 3,
+    Exact = 4,
+    Instant = 5,
+    Named = 6,
+    Boundary = 7,
+    OrdinalWeekday = 8,
+    At = 9,
+    LastWeekday = 10,
+}
+
+impl TimeKind {
+    /// The stable numeric discriminant.
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    /// The kind with the given discriminant, if any.
+    pub fn from_id(id: u8) -> Option<Self> {
+        Some(match id {
+            0 => Self::Relative,
+            1 => Self::Weekday,
+            2 => Self::WeekdayTime,
+            3 => Self::Month,
+            4 => Self::Exact,
+            5 => Self::Instant,
+            6 => Self::Named,
+            7 => Self::Boundary,
+            8 => Self::OrdinalWeekday,
+            9 => Self::At,
+            10 => Self::LastWeekday,
+            _ => return None,
+        })
+    }
+}
+
+/// Error returned when converting a number that no [`Weekday`] or [`Month`] has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[display("{_0} is out of range")]
+pub struct OutOfRange(pub(crate) u8);
+
+impl OutOfRange {
+    /// The number that was out of range.
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl core::error::Error for OutOfRange {}
+
+impl FromStr for Time {
+    type Err = parse::ParseError;
+
+    /// Parses a natural language expression in any enabled language. See [`parse::parse`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse(s)
+    }
+}
+
+impl WithLanguage for Time {
+    /// Re-expresses every keyword in the value in the given language, leaving exact dates and
+    /// times untouched.
+    fn with_language(&self, language: Language) -> Self {
+        match self {
+            Time::Relative(x) => Time::Relative(x.with_language(language)),
+            Time::Weekday(x) => Time::Weekday(x.with_language(language)),
+            Time::WeekdayTime(x) => Time::WeekdayTime(x.with_language(language)),
+            Time::Month(x) => Time::Month(x.with_language(language)),
+            Time::Named(x) => Time::Named(x.with_language(language)),
+            Time::OrdinalWeekday(x) => Time::OrdinalWeekday(x.with_language(language)),
+            Time::LastWeekday(x) => Time::LastWeekday(x.with_language(language)),
+            Time::Exact(_) | Time::DateTime(_) => self.clone(),
+            Time::Boundary(Boundary::StartOf(x)) => x.with_language(language).start_of(),
+            Time::Boundary(Boundary::EndOf(x)) => x.with_language(language).end_of(),
+            Time::At(at) => at.day().with_language(language).at(at.time()),
+        }
+    }
+}
+
+/// One end of a windowed value, as an instant rather than a range.
+///
+/// Serialises externally tagged, e.g. `{"EndOf": "NextWeek"}`, so it stays distinguishable
+/// from the value it wraps.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Boundary {
+    /// The earliest timestamp of the value.
+    #[display("start of {_0}")]
+    StartOf(Box<Time>),
+    /// The latest timestamp of the value.
+    #[display("end of {_0}")]
+    EndOf(Box<Time>),
+}
+
+impl Boundary {
+    /// The wrapped value.
+    pub fn time(&self) -> &Time {
+        match self {
+            Boundary::StartOf(time) | Boundary::EndOf(time) => time,
+        }
+    }
+
+    /// Converts to the boundary instant, relative to the given time.
+    pub fn to_chrono(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_with(relative_to, Options::default())
+    }
+
+    /// Converts to the boundary instant, relative to the given time and following the given
+    /// conventions.
+    pub fn to_chrono_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
+        match self {
+            Boundary::StartOf(time) => time.to_chrono_min_with(relative_to, options),
+            Boundary::EndOf(time) => time.to_chrono_max_with(relative_to, options),
+        }
+    }
+}
+
+/// A value at a time of day, e.g. "Tomorrow 09:00" or "ThisWeek 17:30".
+///
+/// The value is resolved first and the time of day applied to it: the earliest timestamp is the
+/// time on the first day of its window, and the latest the time on its last day. Serialises as
+/// an object, e.g. `{"day": "Tomorrow", "time": [9, 0]}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct At {
+    day: Box<Time>,
+    time: ExactTime,
+}
+
+impl At {
+    pub fn new(day: Time, time: ExactTime) -> Self {
+        Self {
+            day: Box::new(day),
+            time,
+        }
+    }
+
+    /// The value the time of day applies to.
+    pub fn day(&self) -> &Time {
+        &self.day
+    }
+
+    pub fn time(&self) -> ExactTime {
+        self.time
+    }
+
+    /// Converts to the time of day on the first day of the value, relative to the given time
+    /// and following the given conventions.
+    pub fn to_chrono_min_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
+        in_offset(self.time.zone(), relative_to, |relative_to| {
+            self.day
+                .to_chrono_min_with(relative_to, options)
+                .with_time(self.time.to_chrono())
+                .unwrap()
+        })
+    }
+
+    /// Converts to the time of day on the last day of the value, relative to the given time and
+    /// following the given conventions.
+    pub fn to_chrono_max_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
+        in_offset(self.time.zone(), relative_to, |relative_to| {
+            let min = self.day.clone().to_chrono_min_with(relative_to, options);
+            let max = self.day.to_chrono_max_with(relative_to, options);
+
+            // Windows end at the exclusive midnight after their last day
+            let last_day = if max > min && max.time() == NaiveTime::MIN {
+                max.checked_sub_days(Days::new(1)).unwrap()
+            } else {
+                max
+            };
+
+            last_day.with_time(self.time.to_chrono()).unwrap()
+        })
+    }
+}
+
+impl fmt::Display for At {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let time = self.time;
+
+        write!(f, "{} {:02}:{:02}", self.day, time.hour(), time.minute())?;
+
+        if time.has_second() {
+            write!(f, ":{:02}", time.second())?;
+        }
+
+        match time.zone() {
+            Some(zone) => write!(f, " {zone}"),
+            None => Ok(()),
+        }
+    }
 }
 
 impl Time {
+    /// The kind of value, e.g. [`TimeKind::Weekday`] for `"Monday"`.
+    pub fn kind(&self) -> TimeKind {
+        match self {
+            Time::Relative(_) => TimeKind::Relative,
+            Time::Weekday(_) => TimeKind::Weekday,
+            Time::WeekdayTime(_) => TimeKind::WeekdayTime,
+            Time::Month(_) => TimeKind::Month,
+            Time::Exact(_) => TimeKind::Exact,
+            Time::DateTime(_) => TimeKind::Instant,
+            Time::Named(_) => TimeKind::Named,
+            Time::OrdinalWeekday(_) => TimeKind::OrdinalWeekday,
+            Time::Boundary(_) => TimeKind::Boundary,
+            Time::At(_) => TimeKind::At,
+            Time::LastWeekday(_) => TimeKind::LastWeekday,
+        }
+    }
+
     /// Converts to the earliest possible timestamp, relative to the current time.
+    #[cfg(feature = "std")]
     pub fn to_chrono_min_now(self) -> DateTime<Utc> {
         self.to_chrono_min(Utc::now())
     }
 
     /// Converts to the earliest possible timestamp, relative to the given time.
     pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_min_with(relative_to, Options::default())
+    }
+
+    /// Converts to the earliest possible timestamp, relative to the given time and following
+    /// the given conventions.
+    pub fn to_chrono_min_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
         match self {
-            Time::Relative(relative) => relative.to_chrono_min(relative_to),
+            Time::Relative(relative) => relative.to_chrono_min_with(relative_to, options),
             Time::Weekday(weekday) => weekday.to_chrono_min(relative_to, true),
-            Time::Month(month) => month
-                .to_chrono_max(relative_to, true)
-                .checked_sub_months(Months::new(1))
-                .unwrap(),
+            Time::WeekdayTime(weekday_time) => weekday_time.to_chrono_min(relative_to),
+            Time::Month(month) => month.to_chrono_min(relative_to, true),
             Time::Exact(exact) => exact.to_chrono_min(relative_to),
             Time::DateTime(date_time) => date_time,
+            Time::Named(named) => named.to_chrono_min(relative_to),
+            Time::OrdinalWeekday(ordinal_weekday) => ordinal_weekday.to_chrono_min(relative_to),
+            Time::Boundary(boundary) => boundary.to_chrono_with(relative_to, options),
+            Time::At(at) => at.to_chrono_min_with(relative_to, options),
+            Time::LastWeekday(last_weekday) => last_weekday.to_chrono_min(relative_to),
         }
     }
 
     /// Converts to the latest possible timestamp, relative to the current time.
+    #[cfg(feature = "std")]
     pub fn to_chrono_max_now(self) -> DateTime<Utc> {
         self.to_chrono_max(Utc::now())
     }
 
     /// Converts to the latest possible timestamp, relative to the given time.
     pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_max_with(relative_to, Options::default())
+    }
+
+    /// Converts to the latest possible timestamp, relative to the given time and following the
+    /// given conventions.
+    pub fn to_chrono_max_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
         match self {
-            Time::Relative(relative) => relative.to_chrono_max(relative_to),
+            Time::Relative(relative) => relative.to_chrono_max_with(relative_to, options),
             Time::Weekday(weekday) => weekday.to_chrono_max(relative_to, true),
+            Time::WeekdayTime(weekday_time) => weekday_time.to_chrono_max(relative_to),
             Time::Month(month) => month.to_chrono_max(relative_to, true),
             Time::Exact(exact) => exact.to_chrono_max(relative_to),
             Time::DateTime(date_time) => date_time,
+            Time::Named(named) => named.to_chrono_max(relative_to),
+            Time::OrdinalWeekday(ordinal_weekday) => ordinal_weekday.to_chrono_max(relative_to),
+            Time::Boundary(boundary) => boundary.to_chrono_with(relative_to, options),
+            Time::At(at) => at.to_chrono_max_with(relative_to, options),
+            Time::LastWeekday(last_weekday) => last_weekday.to_chrono_max(relative_to),
         }
     }
 
-    /// Converts a chrono timestamp to the most natural time representation.
+    /// Converts to the earliest timestamp, resolving weekdays and months to their most recent
+    /// prior occurrence, e.g. "last Tuesday", relative to the given time.
     ///
-    /// When `relative_to` is provided, attempts to express the timestamp as a relative
-    /// or named time (e.g., "Today", "Monday") in the specified language.
-    pub fn from_max_chrono(
-        date_time: DateTime<Utc>,
-        relative_to: Option<DateTime<Utc>>,
-        language: Language,
-    ) -> Time {
-        if let Some(now) = relative_to
-            && date_time.time() == NaiveTime::MIN
-        {
-            let today = Relative::Today(Today::from_language(language));
-            if date_time == today.clone().to_chrono_max(now) {
-                return Time::Relative(today);
-            };
+    /// Other values resolve as with [`Time::to_chrono_min`].
+    pub fn to_chrono_min_past(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Time::Weekday(weekday) => weekday.to_chrono_min_past(relative_to, true),
+            Time::Month(month) => month.to_chrono_min_past(relative_to, true),
+            Time::Boundary(Boundary::StartOf(time)) => time.to_chrono_min_past(relative_to),
+            Time::Boundary(Boundary::EndOf(time)) => time.to_chrono_max_past(relative_to),
+            time => time.to_chrono_min(relative_to),
+        }
+    }
 
-            let tomorrow = Relative::Tomorrow(Tomorrow::from_language(language));
-            if date_time == tomorrow.clone().to_chrono_max(now) {
-                return Time::Relative(tomorrow);
-            }
+    /// Converts to the latest timestamp, resolving weekdays and months to their most recent
+    /// prior occurrence, relative to the given time.
+    ///
+    /// Other values resolve as with [`Time::to_chrono_max`].
+    pub fn to_chrono_max_past(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Time::Weekday(weekday) => weekday.to_chrono_max_past(relative_to, true),
+            Time::Month(month) => month.to_chrono_max_past(relative_to, true),
+            Time::Boundary(Boundary::StartOf(time)) => time.to_chrono_min_past(relative_to),
+            Time::Boundary(Boundary::EndOf(time)) => time.to_chrono_max_past(relative_to),
+            time => time.to_chrono_max(relative_to),
+        }
+    }
 
-            let monday = Time::Weekday(Weekday::Monday(Monday::from_language(language)));
-            if date_time == monday.clone().to_chrono_max(now) {
-                return monday;
-            }
+    /// Time left until the earliest timestamp, negative once it has passed.
+    ///
+    /// Resolves as with [`Time::to_chrono_min`], so `"Monday"` counts down to the next Monday
+    /// even on a Monday, and `"Today"` gives the negative time since midnight.
+    pub fn time_until(self, relative_to: DateTime<Utc>) -> chrono::Duration {
+        self.to_chrono_min(relative_to) - relative_to
+    }
 
-            let tuesday = Time::Weekday(Weekday::Tuesday(Tuesday::from_language(language)));
-            if date_time == tuesday.clone().to_chrono_max(now) {
-                return tuesday;
-            }
+    /// Time elapsed since the value last started, negative while it is still ahead.
+    ///
+    /// Resolves as with [`Time::to_chrono_min_past`], so `"Monday"` counts from the most recent
+    /// Monday rather than the next one.
+    pub fn time_since(self, relative_to: DateTime<Utc>) -> chrono::Duration {
+        relative_to - self.to_chrono_min_past(relative_to)
+    }
 
-            let wednesday = Time::Weekday(Weekday::Wednesday(Wednesday::from_language(language)));
-            if date_time == wednesday.clone().to_chrono_max(now) {
-                return wednesday;
-            }
+    /// Whether the whole value has ended by the given time, e.g. `"Yesterday"`.
+    ///
+    /// Values resolve relative to the given time, so relative keywords always fall on the same
+    /// side of it: "Yesterday" is past, "Today" current and "Tomorrow" future. Values span from
+    /// their earliest timestamp up to, but not including, their latest, which for whole days is
+    /// the midnight after the last one, so "Yesterday" is past at the very midnight ending it.
+    /// Values at a single instant, such as exact date times, are past once it has gone by.
+    pub fn is_past(&self, relative_to: DateTime<Utc>) -> bool {
+        self.clone().to_chrono_max(relative_to) <= relative_to && !self.is_current(relative_to)
+    }
 
-            let thursday = Time::Weekday(Weekday::Thursday(Thursday::from_language(language)));
-            if date_time == thursday.clone().to_chrono_max(now) {
-                return thursday;
-            }
+    /// Whether the whole value is still ahead of the given time, e.g. `"Tomorrow"`.
+    ///
+    /// Resolves as with [`Time::to_chrono_min`], so `"Monday"` is in the future even on a Monday.
+    pub fn is_future(&self, relative_to: DateTime<Utc>) -> bool {
+        relative_to < self.clone().to_chrono_min(relative_to)
+    }
 
-            let friday = Time::Weekday(Weekday::Friday(Friday::from_language(language)));
-            if date_time == friday.clone().to_chrono_max(now) {
-                return friday;
-            }
+    /// Whether the value contains the given time, e.g. `"Today"`, when it is neither past nor
+    /// future.
+    ///
+    /// The earliest timestamp is contained and the latest is not, so "Today" is current at the
+    /// midnight starting it, and a value running until midnight no longer is at that midnight.
+    /// Values at a single instant contain only that instant.
+    pub fn is_current(&self, relative_to: DateTime<Utc>) -> bool {
+        let min = self.clone().to_chrono_min(relative_to);
+        let max = self.clone().to_chrono_max(relative_to);
+
+        min <= relative_to && (relative_to < max || relative_to == min)
+    }
 
-            let saturday = Time::Weekday(Weekday::Saturday(Saturday::from_language(language)));
-            if date_time == saturday.clone().to_chrono_max(now) {
-                return saturday;
-            }
+    /// Parses a natural language expression, matching names only in the given language.
+    ///
+    /// See [`parse::parse`] for the supported forms. Use [`str::parse`] to accept any enabled
+    /// language.
+    pub fn parse_str(input: &str, language: Language) -> Result<Time, parse::ParseError> {
+        parse::parse_in(input, language)
+    }
 
-            let sunday = Time::Weekday(Weekday::Sunday(Sunday::from_language(language)));
-            if date_time == sunday.clone().to_chrono_max(now) {
-                return sunday;
-            }
+    /// Collapses the value to its earliest timestamp, e.g. "start of next week".
+    pub fn start_of(self) -> Time {
+        Time::Boundary(Boundary::StartOf(Box::new(self)))
+    }
 
-            let january = Time::Month(Month::January(January::from_language(language)));
-            if date_time == january.clone().to_chrono_max(now) {
-                return january;
-            }
+    /// Collapses the value to its latest timestamp, e.g. "end of next week".
+    pub fn end_of(self) -> Time {
+        Time::Boundary(Boundary::EndOf(Box::new(self)))
+    }
 
-            let february = Time::Month(Month::February(February::from_language(language)));
-            if date_time == february.clone().to_chrono_max(now) {
-                return february;
-            }
+    /// Applies a time of day to the value, e.g. "tomorrow at 09:00".
+    pub fn at(self, time: ExactTime) -> Time {
+        Time::At(At::new(self, time))
+    }
 
-            let march = Time::Month(Month::March(March::from_language(language)));
-            if date_time == march.clone().to_chrono_max(now) {
-                return march;
-            }
+    /// How far through the value's window the given time is, from `0.0` to `1.0`.
+    ///
+    /// Intended for windows such as `Today`, `ThisWeek` and `ThisMonth`. Times before the
+    /// window give `0.0` and times after it `1.0`, as do values without a window, like a
+    /// timestamp, once reached.
+    pub fn progress(self, relative_to: DateTime<Utc>) -> f64 {
+        let min = self.clone().to_chrono_min(relative_to);
+        let max = self.to_chrono_max(relative_to);
+
+        if relative_to <= min && min < max {
+            return 0.0;
+        }
 
-            let april = Time::Month(Month::April(April::from_language(language)));
-            if date_time == april.clone().to_chrono_max(now) {
-                return april;
-            }
+        if relative_to >= max {
+            return 1.0;
+        }
 
-            let may = Time::Month(Month::May(May::from_language(language)));
-            if date_time == may.clone().to_chrono_max(now) {
-                return may;
-            }
+        let elapsed = (relative_to - min).num_milliseconds() as f64;
+        let total = (max - min).num_milliseconds() as f64;
 
-            let june = Time::Month(Month::June(June::from_language(language)));
-            if date_time == june.clone().to_chrono_max(now) {
-                return june;
-            }
+        (elapsed / total).clamp(0.0, 1.0)
+    }
 
-            let july = Time::Month(Month::July(July::from_language(language)));
-            if date_time == july.clone().to_chrono_max(now) {
-                return july;
-            }
+    /// Whether the value's window ends at or before the instant, relative to the given time.
+    ///
+    /// Windows ending up to `tolerance` after the instant still count, so alerting logic
+    /// doesn't flap on sub-second differences. Pass `chrono::Duration::zero()` for an exact
+    /// comparison.
+    pub fn is_before(
+        self,
+        instant: DateTime<Utc>,
+        relative_to: DateTime<Utc>,
+        tolerance: chrono::Duration,
+    ) -> bool {
+        self.to_chrono_max(relative_to) <= instant + tolerance.abs()
+    }
 
-            let august = Time::Month(Month::August(August::from_language(language)));
-            if date_time == august.clone().to_chrono_max(now) {
-                return august;
-            }
+    /// Whether the value's window starts at or after the instant, relative to the given time.
+    ///
+    /// Windows starting up to `tolerance` before the instant still count. See [`Time::is_before`].
+    pub fn is_after(
+        self,
+        instant: DateTime<Utc>,
+        relative_to: DateTime<Utc>,
+        tolerance: chrono::Duration,
+    ) -> bool {
+        self.to_chrono_min(relative_to) >= instant - tolerance.abs()
+    }
 
-            let september = Time::Month(Month::September(September::from_language(language)));
-            if date_time == september.clone().to_chrono_max(now) {
-                return september;
-            }
+    /// Orders two values by their windows relative to the given time, earliest start first and
+    /// earliest end on ties.
+    ///
+    /// Values with the same window compare equal, so the start of `"Today"` and midnight on the
+    /// 29th are interchangeable on the 29th. Use [`ResolvedTime`](resolved::ResolvedTime) to sort
+    /// collections directly.
+    pub fn cmp_at(&self, other: &Time, relative_to: DateTime<Utc>) -> Ordering {
+        let window = |time: &Time| {
+            (
+                time.clone().to_chrono_min(relative_to),
+                time.clone().to_chrono_max(relative_to),
+            )
+        };
+
+        window(self).cmp(&window(other))
+    }
 
-            let october = Time::Month(Month::October(October::from_language(language)));
-            if date_time == october.clone().to_chrono_max(now) {
-                return october;
-            }
+    /// Intersects the value's window with an external bound, such as a business quarter.
+    ///
+    /// Returns `None` when they don't overlap. Values without a window, like a timestamp, give
+    /// an empty range at the instant when it falls within the bound.
+    pub fn clamp_window(
+        self,
+        relative_to: DateTime<Utc>,
+        bound: Range<DateTime<Utc>>,
+    ) -> Option<Range<DateTime<Utc>>> {
+        let min = self.clone().to_chrono_min(relative_to);
+        let max = self.to_chrono_max(relative_to);
+
+        if min == max {
+            return bound.contains(&min).then_some(min..max);
+        }
 
-            let november = Time::Month(Month::November(November::from_language(language)));
-            if date_time == november.clone().to_chrono_max(now) {
-                return november;
-            }
+        let start = min.max(bound.start);
+        let end = max.min(bound.end);
 
-            let december = Time::Month(Month::December(December::from_language(language)));
-            if date_time == december.clone().to_chrono_max(now) {
-                return december;
-            }
+        (start < end).then_some(start..end)
+    }
 
-            let this_week = Relative::ThisWeek(ThisWeek::from_language(language));
-            if date_time == this_week.clone().to_chrono_max(now) {
-                return Time::Relative(this_week);
+    /// Converts a chrono timestamp to the most natural time representation.
+    ///
+    /// When `relative_to` is provided, attempts to express the timestamp as a relative
+    /// or named time (e.g., "Today", "Monday") in the specified language. Timestamps ending a
+    /// part of today, tomorrow or yesterday at the default hours become e.g. "ThisMorning".
+    /// Quarter boundaries become "ThisQuarter" or "NextQuarter" rather than a month name, and
+    /// the end of the current or next clock hour becomes "ThisHour" or "NextHour".
+    pub fn from_max_chrono(
+        date_time: DateTime<Utc>,
+        relative_to: Option<DateTime<Utc>>,
+        language: Language,
+    ) -> Time {
+        Self::from_max_chrono_ordered(date_time, relative_to, language, &Candidate::DEFAULT_ORDER)
+    }
+
+    /// Converts a chrono timestamp to the most natural time representation, choosing between
+    /// matching keywords as the options prefer.
+    pub fn from_max_chrono_with(
+        date_time: DateTime<Utc>,
+        relative_to: Option<DateTime<Utc>>,
+        language: Language,
+        options: FromChronoOptions,
+    ) -> Time {
+        match relative_to {
+            Some(now) if options.allows(date_time, now) => {
+                let candidates = options.candidates();
+                Self::from_max_chrono_ordered(date_time, relative_to, language, &candidates)
             }
+            _ => Time::DateTime(date_time),
+        }
+    }
+
+    /// Converts a chrono timestamp to the most natural time representation, trying only the
+    /// given groups of keywords and in the given order.
+    ///
+    /// See [`Candidate`] for which keywords each group holds.
+    pub fn from_max_chrono_ordered(
+        date_time: DateTime<Utc>,
+        relative_to: Option<DateTime<Utc>>,
+        language: Language,
+        candidates: &[Candidate],
+    ) -> Time {
+        let Some(now) = relative_to else {
+            return Time::DateTime(date_time);
+        };
+
+        let boundary = |time: Time| time.to_chrono_max(now);
+
+        Candidate::find(candidates, date_time, language, boundary)
+            .unwrap_or(Time::DateTime(date_time))
+    }
+
+    /// Converts a chrono timestamp to the most natural time representation, treating it as the
+    /// start of a window rather than its end.
+    ///
+    /// The counterpart of [`Time::from_max_chrono`], for timestamps produced by
+    /// [`Time::to_chrono_min`], e.g. the start of today rendering as "Today".
+    ///
+    /// When `relative_to` is provided, attempts to express the timestamp as a relative
+    /// or named time (e.g., "Today", "Monday") in the specified language.
+    pub fn from_min_chrono(
+        date_time: DateTime<Utc>,
+        relative_to: Option<DateTime<Utc>>,
+        language: Language,
+    ) -> Time {
+        Self::from_min_chrono_ordered(date_time, relative_to, language, &Candidate::DEFAULT_ORDER)
+    }
 
-            let this_month = Relative::ThisMonth(ThisMonth::from_language(language));
-            if date_time == this_month.clone().to_chrono_max(now) {
-                return Time::Relative(this_month);
+    /// Converts a chrono timestamp starting a window to the most natural time representation,
+    /// choosing between matching keywords as the options prefer.
+    pub fn from_min_chrono_with(
+        date_time: DateTime<Utc>,
+        relative_to: Option<DateTime<Utc>>,
+        language: Language,
+        options: FromChronoOptions,
+    ) -> Time {
+        match relative_to {
+            Some(now) if options.allows(date_time, now) => {
+                let candidates = options.candidates();
+                Self::from_min_chrono_ordered(date_time, relative_to, language, &candidates)
             }
+            _ => Time::DateTime(date_time),
         }
+    }
+
+    /// Converts a chrono timestamp starting a window to the most natural time representation,
+    /// trying only the given groups of keywords and in the given order.
+    pub fn from_min_chrono_ordered(
+        date_time: DateTime<Utc>,
+        relative_to: Option<DateTime<Utc>>,
+        language: Language,
+        candidates: &[Candidate],
+    ) -> Time {
+        let Some(now) = relative_to else {
+            return Time::DateTime(date_time);
+        };
 
-        Time::DateTime(date_time)
+        let boundary = |time: Time| time.to_chrono_min(now);
+
+        Candidate::find(candidates, date_time, language, boundary)
+            .unwrap_or(Time::DateTime(date_time))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::{format, string::ToString, vec::Vec};
     use chrono::{Datelike, Days};
 
     use super::*;
@@ -373,15 +849,12 @@ mod tests {
     fn midnight_next_monday_means_sunday() {
         let tuesday = base_time();
 
-        dbg!(&tuesday);
         let midnight_next_monday = tuesday
             .checked_add_days(Days::new(6))
             .unwrap()
             .with_time(NaiveTime::MIN)
             .unwrap();
 
-        dbg!(&midnight_next_monday);
-
         assert_eq!(
             midnight_next_monday.weekday(),
             chrono::Weekday::Mon,
@@ -390,11 +863,6 @@ mod tests {
 
         let time = Time::from_max_chrono(midnight_next_monday, Some(tuesday), Language::default());
 
-        println!(
-            "sunday chrono max {}",
-            Time::Weekday(Weekday::sunday()).to_chrono_max(tuesday)
-        );
-
         assert_eq!(time, Time::Weekday(Weekday::sunday()));
     }
 
@@ -411,6 +879,94 @@ mod tests {
         assert_eq!(next_july.day(), 1);
     }
 
+    #[test]
+    fn numeric_conversions() {
+        use crate::exact::ExactMonth;
+
+        assert_eq!(Weekday::from(chrono::Weekday::Wed), Weekday::wednesday());
+        assert_eq!(
+            chrono::Weekday::from(Weekday::sunday()),
+            chrono::Weekday::Sun
+        );
+        assert_eq!(Weekday::sunday().number_from_monday(), 7);
+        assert_eq!(Weekday::sunday().number_from_sunday(), 1);
+        assert_eq!(Weekday::saturday().number_from_sunday(), 7);
+        assert_eq!(Weekday::try_from(1), Ok(Weekday::monday()));
+        assert_eq!(Weekday::try_from(8), Err(OutOfRange(8)));
+
+        assert_eq!(Month::from(chrono::Month::March), Month::march());
+        assert_eq!(
+            chrono::Month::from(Month::december()),
+            chrono::Month::December
+        );
+        assert_eq!(Month::from(ExactMonth::new(7)), Month::july());
+        assert_eq!(u8::from(Month::october()), 10);
+        assert_eq!(Month::try_from(12), Ok(Month::december()));
+        assert_eq!(Month::try_from(0), Err(OutOfRange(0)));
+
+        for number in 1..=12 {
+            assert_eq!(Month::try_from(number).map(Month::number), Ok(number));
+        }
+    }
+
+    #[test]
+    fn occurrences() {
+        let tuesday = base_time(); // July 29th, 2025
+        let at = |s| DateTime::parse_from_rfc3339(s).unwrap().to_utc();
+
+        let fridays: Vec<_> = Weekday::friday().occurrences(tuesday).take(3).collect();
+        assert_eq!(
+            fridays,
+            [
+                at("2025-08-01T00:00:00Z"),
+                at("2025-08-08T00:00:00Z"),
+                at("2025-08-15T00:00:00Z")
+            ]
+        );
+
+        // The reference day is included
+        assert_eq!(
+            Weekday::tuesday().occurrences(tuesday).next(),
+            Some(at("2025-07-29T00:00:00Z"))
+        );
+
+        let marches: Vec<_> = Month::march().occurrences(tuesday).take(2).collect();
+        assert_eq!(
+            marches,
+            [at("2026-03-01T00:00:00Z"), at("2027-03-01T00:00:00Z")]
+        );
+        assert_eq!(
+            Month::july().occurrences(tuesday).next(),
+            Some(at("2025-07-01T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn past_future_and_current() {
+        let tuesday = base_time(); // July 29th, 2025
+        let midnight = tuesday.with_time(NaiveTime::MIN).unwrap();
+
+        assert!(Time::Relative(Relative::today()).is_current(tuesday));
+        assert!(Time::Relative(Relative::tomorrow()).is_future(tuesday));
+        assert!(Time::Relative(Relative::yesterday()).is_past(tuesday));
+
+        // Windows start at the inclusive midnight of their first day and end at the exclusive
+        // midnight after their last
+        assert!(Relative::today().is_current(midnight));
+        assert!(!Relative::today().is_future(midnight));
+        assert!(Relative::yesterday().is_past(midnight));
+        assert!(!Relative::yesterday().is_current(midnight));
+
+        // Instants contain only themselves
+        let instant = Time::DateTime(tuesday);
+        assert!(instant.is_current(tuesday));
+        assert!(!instant.is_past(tuesday));
+        assert!(instant.is_past(tuesday + chrono::Duration::seconds(1)));
+
+        // Weekdays resolve to their next occurrence
+        assert!(Time::Weekday(Weekday::tuesday()).is_future(tuesday));
+    }
+
     #[test]
     fn month_skipping_self() {
         let tuesday = base_time(); // July 29th, 2025
@@ -424,6 +980,25 @@ mod tests {
         assert_eq!(next_july.day(), 1);
     }
 
+    #[test]
+    fn month_chrono_min() {
+        let tuesday = base_time(); // July 29th, 2025
+
+        let july = Month::july();
+
+        // Without skipping, the current month starts on July 1st
+        let this_july = july.to_chrono_min(tuesday, false);
+        assert_eq!(this_july.year(), 2025);
+        assert_eq!(this_july.month(), 7);
+        assert_eq!(this_july.day(), 1);
+
+        // Skipping self should give us next year's July
+        let next_july = july.to_chrono_min(tuesday, true);
+        assert_eq!(next_july.year(), 2026);
+        assert_eq!(next_july.month(), 7);
+        assert_eq!(next_july.day(), 1);
+    }
+
     #[test]
     fn relative_today_conversion() {
         let tuesday = base_time();
@@ -431,7 +1006,7 @@ mod tests {
         let today = Relative::today();
 
         // Min should be start of today
-        let min = today.clone().to_chrono_min(tuesday);
+        let min = today.to_chrono_min(tuesday);
         assert_eq!(min.date_naive(), tuesday.date_naive());
         assert_eq!(min.time(), NaiveTime::MIN);
 
@@ -447,7 +1022,7 @@ mod tests {
         let tomorrow = Relative::tomorrow();
 
         // Min should be start of tomorrow
-        let min = tomorrow.clone().to_chrono_min(tuesday);
+        let min = tomorrow.to_chrono_min(tuesday);
         assert_eq!(min, tuesday.checked_add_days(Days::new(1)).unwrap().with_time(NaiveTime::MIN).unwrap());
 
         // Max should be start of day after tomorrow
@@ -458,7 +1033,7 @@ mod tests {
     #[test]
     #[cfg(feature = "swedish")]
     fn language_switching_weekday() {
-        use crate::traits::WithLanguage;
+        use crate::{traits::WithLanguage, weekday::Monday};
         use crate::language::{Language, Swedish};
 
         let monday_english = Monday::default();
@@ -471,7 +1046,7 @@ mod tests {
     #[test]
     #[cfg(feature = "swedish")]
     fn language_switching_month() {
-        use crate::traits::WithLanguage;
+        use crate::{month::January, traits::WithLanguage};
         use crate::language::{Language, Swedish};
 
         let january_english = January::default();
@@ -484,7 +1059,7 @@ mod tests {
     #[test]
     #[cfg(feature = "swedish")]
     fn language_switching_relative() {
-        use crate::traits::WithLanguage;
+        use crate::{relative::Today, traits::WithLanguage};
         use crate::language::{Language, Swedish};
 
         let today_english = Today::default();
@@ -494,6 +1069,93 @@ mod tests {
         assert_eq!(format!("{}", today_swedish), "Idag");
     }
 
+    #[test]
+    #[cfg(all(feature = "swedish", feature = "serde"))]
+    fn detects_language() {
+        use crate::{
+            language::{English, Swedish},
+            traits::WithLanguage,
+        };
+
+        let english = Language::English(English::default());
+        let swedish = Language::Swedish(Swedish::default());
+        let måndag: Time = serde_json::from_str(r#""Måndag""#).unwrap();
+
+        assert_eq!(måndag.language(), swedish);
+        assert_eq!(Weekday::monday().language(), english);
+        assert_eq!(Month::january().with_language(swedish).language(), swedish);
+        assert_eq!(Relative::today().with_language(swedish).language(), swedish);
+        assert_eq!(Time::DateTime(base_time()).language(), english);
+
+        let deadline = Time::Relative(Relative::tomorrow())
+            .at(ExactTime::new(9, 0, None))
+            .end_of();
+        let translated = deadline.with_language(swedish);
+
+        assert_eq!(translated.to_string(), "end of Imorgon 09:00");
+        assert_eq!(translated.language(), swedish);
+        assert_eq!(translated.with_language(english), deadline);
+    }
+
+    #[test]
+    #[cfg(all(feature = "norwegian", feature = "danish", feature = "serde"))]
+    fn detects_norwegian_and_danish() {
+        use crate::{
+            language::{Danish, Norwegian},
+            traits::WithLanguage,
+        };
+
+        let norwegian = Language::Norwegian(Norwegian::default());
+        let danish = Language::Danish(Danish::default());
+        let next_week: Time = serde_json::from_str(r#""NæsteUge""#).unwrap();
+
+        assert_eq!(next_week.language(), danish);
+        assert_eq!(next_week.with_language(norwegian).to_string(), "NesteUke");
+        assert_eq!(serde_json::to_string(&next_week).unwrap(), r#""NæsteUge""#);
+        assert_eq!(
+            Time::Month(Month::may())
+                .with_language(norwegian)
+                .to_string(),
+            "Mai"
+        );
+        // Spellings shared between languages are one variant
+        assert_eq!(
+            Weekday::monday().with_language(norwegian),
+            Weekday::monday().with_language(danish)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "spanish", feature = "french", feature = "serde"))]
+    fn detects_spanish_and_french() {
+        use crate::{
+            language::{French, Spanish},
+            traits::WithLanguage,
+        };
+
+        let spanish = Language::Spanish(Spanish::default());
+        let french = Language::French(French::default());
+        let today: Time = serde_json::from_str(r#""aujourd'hui""#).unwrap();
+
+        assert_eq!(today.language(), french);
+        assert_eq!(today.to_string(), "Aujourd'hui");
+        assert_eq!(serde_json::to_string(&today).unwrap(), r#""Aujourd'hui""#);
+        assert_eq!(today.with_language(spanish).to_string(), "Hoy");
+        // Accents are optional when deserialising
+        assert_eq!(
+            serde_json::from_str::<Time>(r#""Manana""#)
+                .unwrap()
+                .language(),
+            spanish
+        );
+        assert_eq!(
+            Time::Month(Month::march())
+                .with_language(french)
+                .to_string(),
+            "Mars"
+        );
+    }
+
     #[test]
     fn this_week_conversion() {
         let tuesday = base_time(); // July 29th, 2025
@@ -529,6 +1191,11 @@ mod tests {
 
         let this_month = Relative::this_month();
 
+        // This month should start at midnight on July 1st
+        let min = this_month.to_chrono_min(tuesday);
+        assert_eq!(min.month(), 7);
+        assert_eq!(min.day(), 1);
+
         // This month should end at midnight on August 1st
         let max = this_month.to_chrono_max(tuesday);
         assert_eq!(max.month(), 8);
@@ -618,4 +1285,635 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn weekday_time_from_str() {
+        let weekday_time: WeekdayTime = "Monday 14:00".parse().unwrap();
+
+        assert_eq!(weekday_time.weekday(), Weekday::monday());
+        assert_eq!(weekday_time.time().hour(), 14);
+        assert_eq!(weekday_time.to_string(), "Monday 14:00");
+
+        let zoned: WeekdayTime = "Monday 09:00 Local".parse().unwrap();
+
+        assert_eq!(zoned.time().zone(), Some(exact::Zone::Local));
+        assert_eq!(zoned.to_string(), "Monday 09:00 Local");
+
+        assert!("Monday".parse::<WeekdayTime>().is_err());
+        assert!("Monday 25:00".parse::<WeekdayTime>().is_err());
+        assert!("Someday 14:00".parse::<WeekdayTime>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn weekday_time_deserialization() {
+        use serde::de::{IntoDeserializer, value::StrDeserializer};
+
+        let deserialize = |s: &'static str| {
+            let deserializer: StrDeserializer<serde::de::value::Error> = s.into_deserializer();
+            Time::deserialize(deserializer).unwrap()
+        };
+
+        assert!(matches!(deserialize("Monday 14:00"), Time::WeekdayTime(_)));
+        assert!(matches!(deserialize("Monday"), Time::Weekday(_)));
+
+        #[cfg(feature = "swedish")]
+        {
+            let time = deserialize("Fredag 09:30");
+
+            assert!(matches!(time, Time::WeekdayTime(_)));
+            assert_eq!(time.to_string(), "Fredag 09:30");
+        }
+    }
+
+    #[test]
+    fn weekday_time_conversion() {
+        let tuesday = base_time();
+        let later_today: WeekdayTime = "Tuesday 14:00".parse().unwrap();
+        let earlier_today: WeekdayTime = "Tuesday 09:00".parse().unwrap();
+        let tomorrow: WeekdayTime = "Wednesday 09:00".parse().unwrap();
+
+        // The reference day is skipped, as with `Time::Weekday`
+        assert_eq!(
+            later_today.to_chrono_min(tuesday),
+            tuesday
+                .checked_add_days(Days::new(7))
+                .unwrap()
+                .with_time(NaiveTime::from_hms_opt(14, 0, 0).unwrap())
+                .unwrap()
+        );
+        assert_eq!(
+            Time::from_str("next tuesday at 3pm")
+                .unwrap()
+                .to_chrono_min(tuesday)
+                .to_rfc3339(),
+            "2025-08-05T15:00:00+00:00"
+        );
+        assert_eq!(
+            tomorrow.to_chrono_min(tuesday).to_rfc3339(),
+            "2025-07-30T09:00:00+00:00"
+        );
+        assert_eq!(
+            earlier_today.to_chrono_max(tuesday),
+            tuesday
+                .checked_add_days(Days::new(7))
+                .unwrap()
+                .with_time(NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn weekday_time_offsets() {
+        // 12:30:05 on a Tuesday in +02:00
+        let tuesday = base_time();
+        let passed: WeekdayTime = "Tuesday 12:00 +02:00".parse().unwrap();
+        let tonight: WeekdayTime = "Wednesday 01:00 +02:00".parse().unwrap();
+
+        assert_eq!(
+            passed.to_chrono_min(tuesday).to_rfc3339(),
+            "2025-08-05T10:00:00+00:00"
+        );
+        assert_eq!(
+            tonight.to_chrono_max(tuesday).to_rfc3339(),
+            "2025-07-29T23:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn window_progress() {
+        // 10:30:05 on a Tuesday
+        let tuesday = base_time();
+
+        let today = Time::Relative(Relative::today()).progress(tuesday);
+        assert!((today - 37805.0 / 86400.0).abs() < 1e-9);
+
+        let this_week = Time::Relative(Relative::this_week()).progress(tuesday);
+        assert!((this_week - (86400.0 + 37805.0) / (7.0 * 86400.0)).abs() < 1e-9);
+
+        assert_eq!(Time::Relative(Relative::tomorrow()).progress(tuesday), 0.0);
+        assert_eq!(Time::DateTime(tuesday).progress(tuesday), 1.0);
+    }
+
+    #[test]
+    fn window_clamping() {
+        let tuesday = base_time();
+        let midnight = |day| {
+            DateTime::parse_from_rfc3339(&format!("2025-07-{day:02}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+
+        // This week runs from Monday 28th to midnight after Sunday August 3rd
+        let this_week = Time::Relative(Relative::this_week());
+
+        assert_eq!(
+            this_week
+                .clone()
+                .clamp_window(tuesday, midnight(1)..midnight(30)),
+            Some(midnight(28)..midnight(30))
+        );
+        assert_eq!(
+            this_week.clamp_window(tuesday, midnight(1)..midnight(28)),
+            None
+        );
+        assert_eq!(
+            Time::DateTime(tuesday).clamp_window(tuesday, midnight(29)..midnight(30)),
+            Some(tuesday..tuesday)
+        );
+    }
+
+    #[test]
+    fn boundaries() {
+        let tuesday = base_time();
+        let next_week = Time::Relative(Relative::next_week());
+
+        let start = next_week.clone().start_of();
+        let end = next_week.clone().end_of();
+
+        assert_eq!(
+            start.clone().to_chrono_min(tuesday),
+            next_week.clone().to_chrono_min(tuesday)
+        );
+        assert_eq!(
+            start.clone().to_chrono_max(tuesday),
+            next_week.clone().to_chrono_min(tuesday)
+        );
+        assert_eq!(
+            end.clone().to_chrono_min(tuesday),
+            next_week.to_chrono_max(tuesday)
+        );
+
+        assert_eq!(end.to_string(), "end of NextWeek");
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(
+                serde_json::to_string(&start).unwrap(),
+                r#"{"StartOf":"NextWeek"}"#
+            );
+            assert_eq!(
+                serde_json::from_str::<Time>(r#"{"StartOf":"NextWeek"}"#).unwrap(),
+                start
+            );
+        }
+    }
+
+    #[test]
+    fn week_parity_conversion() {
+        // Tuesday July 29th, 2025 is in week 31
+        let tuesday = base_time();
+        let midnight = |date| {
+            DateTime::parse_from_rfc3339(&format!("2025-{date}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+
+        let odd = Relative::odd_week();
+        assert_eq!(odd.to_chrono_min(tuesday), midnight("07-28"));
+        assert_eq!(odd.to_chrono_max(tuesday), midnight("08-04"));
+
+        let even = Relative::even_week();
+        assert_eq!(even.to_chrono_min(tuesday), midnight("08-04"));
+        assert_eq!(even.to_chrono_max(tuesday), midnight("08-11"));
+    }
+
+    #[test]
+    fn tolerant_comparisons() {
+        let tuesday = base_time();
+        let today = Time::Relative(Relative::today());
+        let midnight = DateTime::parse_from_rfc3339("2025-07-30T00:00:00Z")
+            .unwrap()
+            .to_utc();
+        let zero = chrono::Duration::zero();
+        let second = chrono::Duration::seconds(1);
+
+        assert!(today.clone().is_before(midnight, tuesday, zero));
+        assert!(!today.clone().is_before(midnight - second, tuesday, zero));
+        assert!(today.clone().is_before(midnight - second, tuesday, second));
+
+        let tomorrow = Time::Relative(Relative::tomorrow());
+        assert!(tomorrow.clone().is_after(midnight, tuesday, zero));
+        assert!(!tomorrow.clone().is_after(midnight + second, tuesday, zero));
+        assert!(tomorrow.is_after(midnight + second, tuesday, second));
+    }
+
+    #[test]
+    fn kinds() {
+        assert_eq!(Time::Weekday(Weekday::monday()).kind(), TimeKind::Weekday);
+        assert_eq!(Time::DateTime(base_time()).kind(), TimeKind::Instant);
+        assert_eq!(
+            Time::Relative(Relative::today()).end_of().kind(),
+            TimeKind::Boundary
+        );
+
+        for id in 0..11 {
+            assert_eq!(TimeKind::from_id(id).map(TimeKind::id), Some(id));
+        }
+        assert_eq!(TimeKind::from_id(11), None);
+    }
+
+    #[test]
+    fn parses_from_str() {
+        assert_eq!(
+            "next friday".parse::<Time>(),
+            Ok(Time::Weekday(Weekday::friday()))
+        );
+        assert_eq!(
+            Time::parse_str("Tomorrow", Language::default()),
+            Ok(Time::Relative(Relative::tomorrow()))
+        );
+
+        #[cfg(feature = "swedish")]
+        {
+            use crate::language::Swedish;
+
+            let swedish = Language::Swedish(Swedish::default());
+
+            assert!("imorgon".parse::<Time>().is_ok());
+            assert!(Time::parse_str("imorgon", Language::default()).is_err());
+            assert!(Time::parse_str("fredag 10:30", swedish).is_ok());
+            assert!(Time::parse_str("den 29 july", swedish).is_err());
+        }
+    }
+
+    #[test]
+    fn month_and_year_conversion() {
+        let tuesday = base_time();
+        let on = |date| {
+            DateTime::parse_from_rfc3339(&format!("{date}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+
+        let next_month = Relative::next_month();
+        assert_eq!(next_month.to_chrono_min(tuesday), on("2025-08-01"));
+        assert_eq!(next_month.to_chrono_max(tuesday), on("2025-09-01"));
+
+        let this_year = Relative::this_year();
+        assert_eq!(this_year.to_chrono_min(tuesday), on("2025-01-01"));
+        assert_eq!(this_year.to_chrono_max(tuesday), on("2026-01-01"));
+
+        let next_year = Relative::next_year();
+        assert_eq!(next_year.to_chrono_min(tuesday), on("2026-01-01"));
+        assert_eq!(next_year.to_chrono_max(tuesday), on("2027-01-01"));
+
+        assert_eq!(
+            Time::from_max_chrono(on("2027-01-01"), Some(tuesday), Language::default()),
+            Time::Relative(Relative::next_year())
+        );
+    }
+
+    #[test]
+    fn quarter_conversion() {
+        let tuesday = base_time();
+        let on = |date| {
+            DateTime::parse_from_rfc3339(&format!("{date}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+
+        let current = Relative::this_quarter();
+        assert_eq!(current.to_chrono_min(tuesday), on("2025-07-01"));
+        assert_eq!(current.to_chrono_max(tuesday), on("2025-10-01"));
+
+        let next = Relative::next_quarter();
+        assert_eq!(next.to_chrono_min(tuesday), on("2025-10-01"));
+        assert_eq!(next.to_chrono_max(tuesday), on("2026-01-01"));
+
+        assert_eq!(
+            Time::from_max_chrono(on("2025-10-01"), Some(tuesday), Language::default()),
+            Time::Relative(Relative::this_quarter())
+        );
+        assert_eq!(
+            Time::from_min_chrono(on("2025-10-01"), Some(tuesday), Language::default()),
+            Time::Relative(Relative::next_quarter())
+        );
+        assert_eq!("Q4".parse(), Ok(Time::Relative(Relative::quarter(4, None))));
+    }
+
+    #[test]
+    fn week_start_option() {
+        let tuesday = base_time();
+        let options = Options::default().with_week_start(chrono::Weekday::Sun);
+        let on = |date| {
+            DateTime::parse_from_rfc3339(&format!("2025-{date}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+
+        let this_week = Time::Relative(Relative::this_week());
+        assert_eq!(this_week.clone().to_chrono_min(tuesday), on("07-28"));
+        assert_eq!(
+            this_week.clone().to_chrono_min_with(tuesday, options),
+            on("07-27")
+        );
+        assert_eq!(this_week.to_chrono_max_with(tuesday, options), on("08-03"));
+
+        let next_week = Time::Relative(Relative::next_week()).end_of();
+        assert_eq!(next_week.to_chrono_min_with(tuesday, options), on("08-10"));
+    }
+
+    #[test]
+    fn weekend_conversion() {
+        let on = |date| {
+            DateTime::parse_from_rfc3339(&format!("2025-{date}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+        let tuesday = base_time();
+        let sunday = on("08-03");
+
+        let this_weekend = Relative::this_weekend();
+        assert_eq!(this_weekend.to_chrono_min(tuesday), on("08-02"));
+        assert_eq!(this_weekend.to_chrono_max(tuesday), on("08-04"));
+        assert_eq!(this_weekend.to_chrono_min(sunday), on("08-02"));
+        assert_eq!(this_weekend.to_chrono_max(sunday), on("08-04"));
+
+        let next_weekend = Relative::next_weekend();
+        assert_eq!(next_weekend.to_chrono_min(tuesday), on("08-09"));
+        assert_eq!(next_weekend.to_chrono_max(tuesday), on("08-11"));
+
+        assert_eq!(
+            Time::from_max_chrono(on("08-11"), Some(tuesday), Language::default()),
+            Time::Relative(Relative::next_weekend())
+        );
+    }
+
+    #[test]
+    fn past_conversion() {
+        let tuesday = base_time();
+        let on = |date| {
+            DateTime::parse_from_rfc3339(&format!("{date}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+
+        let tue = Time::Weekday(Weekday::tuesday());
+        assert_eq!(tue.clone().to_chrono_min_past(tuesday), on("2025-07-22"));
+        assert_eq!(tue.to_chrono_max_past(tuesday), on("2025-07-23"));
+
+        let today = Weekday::tuesday();
+        assert_eq!(today.to_chrono_min_past(tuesday, false), on("2025-07-29"));
+        assert_eq!(today.to_chrono_max_past(tuesday, false), tuesday);
+
+        let march = Time::Month(Month::march());
+        assert_eq!(march.clone().to_chrono_min_past(tuesday), on("2025-03-01"));
+        assert_eq!(march.to_chrono_max_past(tuesday), on("2025-04-01"));
+
+        let last_july = Time::Month(Month::july());
+        assert_eq!(last_july.to_chrono_min_past(tuesday), on("2024-07-01"));
+    }
+
+    #[test]
+    fn from_min_chrono_detection() {
+        let tuesday = base_time();
+        let on = |date| {
+            DateTime::parse_from_rfc3339(&format!("{date}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+        let from = |date| Time::from_min_chrono(on(date), Some(tuesday), Language::default());
+
+        assert_eq!(from("2025-07-29"), Time::Relative(Relative::today()));
+        assert_eq!(from("2025-07-28"), Time::Relative(Relative::yesterday()));
+        assert_eq!(from("2025-07-30"), Time::Relative(Relative::tomorrow()));
+        assert_eq!(from("2025-08-01"), Time::Weekday(Weekday::friday()));
+        assert_eq!(from("2025-08-04"), Time::Weekday(Weekday::monday()));
+        assert_eq!(from("2025-09-01"), Time::Month(Month::september()));
+        assert_eq!(from("2026-01-01"), Time::Month(Month::january()));
+        assert_eq!(from("2025-07-01"), Time::Relative(Relative::this_month()));
+
+        let afternoon = on("2025-07-29") + chrono::Duration::hours(15);
+        assert_eq!(
+            Time::from_min_chrono(afternoon, Some(tuesday), Language::default()),
+            Time::DateTime(afternoon)
+        );
+    }
+
+    #[test]
+    fn offset_conversion() {
+        use crate::offset::Unit;
+
+        let tuesday = base_time();
+        let on = |date| {
+            DateTime::parse_from_rfc3339(&format!("{date}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+        let window = |time: Time| {
+            let min = time.clone().to_chrono_min(tuesday);
+            (min, time.to_chrono_max(tuesday))
+        };
+        let ahead = |amount, unit| window(Time::Relative(Relative::offset(amount, unit)));
+
+        assert_eq!(ahead(3, Unit::Days), (on("2025-08-01"), on("2025-08-02")));
+        assert_eq!(ahead(2, Unit::Weeks), (on("2025-08-11"), on("2025-08-18")));
+        assert_eq!(ahead(6, Unit::Months), (on("2026-01-01"), on("2026-02-01")));
+        assert_eq!(ahead(2, Unit::Years), (on("2027-01-01"), on("2028-01-01")));
+
+        let next_week = Time::Relative(Relative::next_week());
+        assert_eq!(ahead(1, Unit::Weeks), window(next_week));
+
+        // Offsets past the representable range end there rather than overflowing
+        let end = (DateTime::<Utc>::MAX_UTC, DateTime::<Utc>::MAX_UTC);
+        assert_eq!(ahead(u32::MAX, Unit::Days), end);
+        assert_eq!(ahead(u32::MAX, Unit::Years), end);
+        assert!(ahead(Unit::Years.max_amount(), Unit::Years).1 < end.0);
+        assert!("in 4294967295 days".parse::<Time>().is_err());
+
+        #[cfg(feature = "serde")]
+        {
+            let in_3_days = Time::Relative(Relative::offset(3, Unit::Days));
+            let json = serde_json::to_string(&in_3_days).unwrap();
+            assert_eq!(json, r#""In3Days""#);
+            assert_eq!(serde_json::from_str::<Time>(&json).unwrap(), in_3_days);
+        }
+    }
+
+    #[test]
+    fn business_day_conversion() {
+        use crate::{business::BusinessCalendar, holiday::NamedDay, offset::Unit};
+
+        // Friday August 1st, 2025
+        let friday = base_time() + chrono::Duration::days(3);
+        let on = |date| {
+            DateTime::parse_from_rfc3339(&format!("{date}T00:00:00Z"))
+                .unwrap()
+                .to_utc()
+        };
+        let window = |time: Relative, options| {
+            let min = time.to_chrono_min_with(friday, options);
+            (min, time.to_chrono_max_with(friday, options))
+        };
+        let options = Options::default();
+        let next = window(Relative::next_business_day(), options);
+        let in_5 = window(Relative::offset(5, Unit::BusinessDays), options);
+
+        assert_eq!(next, (on("2025-08-04"), on("2025-08-05")));
+        assert_eq!(in_5, (on("2025-08-08"), on("2025-08-09")));
+
+        let christmas = Relative::offset(1, Unit::BusinessDays);
+        let december = on("2025-12-23");
+        let calendar = BusinessCalendar::new(&[&NamedDay::ChristmasEve, &NamedDay::ChristmasDay]);
+        let options = options.with_business_calendar(calendar);
+        let min = christmas.to_chrono_min_with(december, options);
+        assert_eq!(min, on("2025-12-26"));
+    }
+
+    #[test]
+    fn from_max_chrono_day_parts() {
+        use crate::day_part::{Day, Part};
+
+        let tuesday = base_time();
+        let from = |s| {
+            let date_time = DateTime::parse_from_rfc3339(s).unwrap().to_utc();
+            Time::from_max_chrono(date_time, Some(tuesday), Language::default())
+        };
+
+        assert_eq!(
+            from("2025-07-29T12:00:00Z"),
+            Time::Relative(Relative::day_part(Part::Morning, Some(Day::Today)))
+        );
+        assert_eq!(
+            from("2025-07-30T06:00:00Z"),
+            Time::Relative(Relative::tonight())
+        );
+        assert_eq!(
+            from("2025-07-28T22:00:00Z"),
+            Time::Relative(Relative::day_part(Part::Evening, Some(Day::Yesterday)))
+        );
+        assert!(matches!(from("2025-07-29T12:30:00Z"), Time::DateTime(_)));
+    }
+
+    #[test]
+    fn at_conversion() {
+        let tuesday = base_time();
+        let on = |s| DateTime::parse_from_rfc3339(s).unwrap().to_utc();
+        let nine = ExactTime::new(9, 0, None);
+
+        let tomorrow = Time::Relative(Relative::tomorrow()).at(nine);
+        let expected = on("2025-07-30T09:00:00Z");
+        assert_eq!(tomorrow.clone().to_chrono_min(tuesday), expected);
+        assert_eq!(tomorrow.clone().to_chrono_max(tuesday), expected);
+        assert_eq!(tomorrow.kind(), TimeKind::At);
+        assert_eq!(tomorrow.to_string(), "Tomorrow 09:00");
+
+        let week = Time::Relative(Relative::this_week()).at(ExactTime::new(17, 30, None));
+        let monday = on("2025-07-28T17:30:00Z");
+        assert_eq!(week.clone().to_chrono_min(tuesday), monday);
+        assert_eq!(week.to_chrono_max(tuesday), on("2025-08-03T17:30:00Z"));
+
+        // A fixed offset applies to the day as well as the time
+        let zoned: Time = "tomorrow at 09:00 +02:00".parse().unwrap();
+        let expected = on("2025-07-30T07:00:00Z");
+        assert_eq!(zoned.clone().to_chrono_min(tuesday), expected);
+        assert_eq!(zoned.clone().to_chrono_max(tuesday), expected);
+        let late = on("2025-07-29T22:30:00Z"); // Already Wednesday in +02:00
+        assert_eq!(zoned.to_chrono_min(late), on("2025-07-31T07:00:00Z"));
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&tomorrow).unwrap();
+            assert_eq!(json, r#"{"day":"Tomorrow","time":[9,0]}"#);
+            assert_eq!(serde_json::from_str::<Time>(&json).unwrap(), tomorrow);
+        }
+    }
+
+    #[test]
+    fn time_until_and_since() {
+        use chrono::Duration;
+
+        // Tuesday July 29th, 2025 at 10:30:05
+        let tuesday = base_time();
+        let since_midnight = Duration::hours(10) + Duration::minutes(30) + Duration::seconds(5);
+        let until_midnight = Duration::days(1) - since_midnight;
+
+        let today = Time::Relative(Relative::today());
+        assert_eq!(today.clone().time_until(tuesday), -since_midnight);
+        assert_eq!(today.time_since(tuesday), since_midnight);
+
+        let tomorrow = Relative::tomorrow();
+        assert_eq!(tomorrow.time_until(tuesday), until_midnight);
+        assert_eq!(tomorrow.time_since(tuesday), -until_midnight);
+
+        let monday = Weekday::monday();
+        let until_monday = until_midnight + Duration::days(5);
+        assert_eq!(Time::Weekday(monday).time_until(tuesday), until_monday);
+        assert_eq!(monday.time_until(tuesday, true), until_monday);
+        assert_eq!(
+            Time::Weekday(monday).time_since(tuesday),
+            since_midnight + Duration::days(1)
+        );
+
+        let tue = Weekday::tuesday();
+        assert_eq!(tue.time_since(tuesday, false), since_midnight);
+        let week_ago = since_midnight + Duration::days(7);
+        assert_eq!(tue.time_since(tuesday, true), week_ago);
+
+        let july = Month::july();
+        let since_first = since_midnight + Duration::days(28);
+        assert_eq!(july.time_until(tuesday, false), -since_first);
+        assert_eq!(july.time_since(tuesday, false), since_first);
+    }
+
+    #[test]
+    fn cmp_at_orders_by_window() {
+        use core::cmp::Ordering;
+
+        let tuesday = base_time();
+        let today = Time::Relative(Relative::today());
+        let tomorrow = Time::Relative(Relative::tomorrow());
+        let this_week = Time::Relative(Relative::this_week());
+        let midnight: Time = "2025-07-29T00:00:00Z".parse().unwrap();
+
+        assert_eq!(today.cmp_at(&tomorrow, tuesday), Ordering::Less);
+        assert_eq!(tomorrow.cmp_at(&today, tuesday), Ordering::Greater);
+        assert_eq!(this_week.cmp_at(&today, tuesday), Ordering::Less);
+        let start_of_today = today.clone().start_of();
+        assert_eq!(start_of_today.cmp_at(&midnight, tuesday), Ordering::Equal);
+        assert_eq!(today.cmp_at(&midnight, tuesday), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hashes_as_keys() {
+        use std::collections::HashSet;
+
+        let times: HashSet<Time> = [
+            Time::Relative(Relative::today()),
+            Time::Relative(Relative::today()),
+            Time::Weekday(Weekday::monday()),
+            Time::Relative(Relative::Date(exact::ExactDate::new(None, 7, 29))),
+            Time::Relative(Relative::Date(exact::ExactDate::new(None, 7, 29))),
+            Time::Relative(Relative::tomorrow()).at(ExactTime::new(9, 0, None)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(times.len(), 4);
+        assert!(times.contains(&Time::Weekday(Weekday::monday())));
+    }
+
+    #[test]
+    #[cfg(all(feature = "swedish", feature = "serde"))]
+    fn deserializes_keywords_loosely() {
+        use crate::{language::Swedish, traits::WithLanguage};
+
+        let deserialize = |json: &str| serde_json::from_str::<Time>(json).unwrap();
+        let monday = Time::Weekday(Weekday::monday());
+        let october = Month::october().with_language(Language::Swedish(Swedish::default()));
+
+        assert_eq!(deserialize(r#""monday""#), monday);
+        assert_eq!(deserialize(r#""MONDAY""#), monday);
+        assert_eq!(deserialize(r#""Mon""#), monday);
+        assert_eq!(deserialize(r#""jan""#), Time::Month(Month::january()));
+        assert_eq!(deserialize(r#""okt""#), Time::Month(october));
+        let this_week = Time::Relative(Relative::this_week());
+        assert_eq!(deserialize(r#""this week""#), this_week);
+
+        let måndag = deserialize(r#""mån""#);
+        assert_eq!(serde_json::to_string(&måndag).unwrap(), r#""Måndag""#);
+        let mon = deserialize(r#""mon""#);
+        assert_eq!(serde_json::to_string(&mon).unwrap(), r#""Monday""#);
+    }
 }
@@ -0,0 +1,63 @@
+//! Time values carrying their language explicitly.
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Time, language::Language};
+
+/// A time value paired with the language it is presented in.
+///
+/// Serialises both fields, e.g. `{"language": "Swedish", "value": "Imorgon"}`, for APIs that
+/// carry the language explicitly rather than inferring it from the value's spelling.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[display("{value}")]
+pub struct LocalizedTime {
+    pub language: Language,
+    pub value: Time,
+}
+
+impl LocalizedTime {
+    pub fn new(language: Language, value: Time) -> Self {
+        Self { language, value }
+    }
+
+    /// Converts the value to its earliest possible timestamp, relative to the given time.
+    pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.value.to_chrono_min(relative_to)
+    }
+
+    /// Converts the value to its latest possible timestamp, relative to the given time.
+    pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.value.to_chrono_max(relative_to)
+    }
+}
+
+impl From<LocalizedTime> for Time {
+    fn from(value: LocalizedTime) -> Self {
+        value.value
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::relative::Relative;
+
+    #[test]
+    fn serialises_both_fields() {
+        let localized = LocalizedTime::new(Language::default(), Time::Relative(Relative::today()));
+        let json = serde_json::to_string(&localized).unwrap();
+
+        assert_eq!(json, r#"{"language":"English","value":"Today"}"#);
+        assert_eq!(
+            serde_json::from_str::<LocalizedTime>(&json).unwrap(),
+            localized
+        );
+    }
+}
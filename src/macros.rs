@@ -0,0 +1,129 @@
+//! Declarative generation of keyword types.
+
+use alloc::string::String;
+
+/// Generates a unit enum per keyword with a variant per spelling, plus its `WithLanguage` impl.
+///
+/// Each entry is the English spelling, followed by the spelling in each other language where it
+/// differs, keyed by the feature enabling the language, e.g. `Monday { "swedish": Måndag }` or
+/// just `April`. Languages spelling a keyword the same share its variant, e.g.
+/// `Friday { "swedish" | "norwegian" | "danish": Fredag }`, which exists whenever any of them is
+/// enabled. Languages without a spelling use the English one. Adding a language means adding its
+/// spellings to the entries of each module's table.
+///
+/// A spelling that is no identifier may give how it is written, e.g.
+/// `Today { "french": Aujourdhui = "Aujourd'hui" }`. A spelling may be followed by an abbreviation
+/// accepted when deserialising, e.g. `Monday as "Mon" { "swedish": Måndag as "Mån" }`.
+/// Deserialising ignores case, whitespace, apostrophes, hyphens and Spanish and French accents,
+/// and serialising always writes the full spelling.
+macro_rules! keywords {
+    ($(
+        $(#[$meta:meta])*
+        $english:ident $(as $english_alias:literal)?
+        $({ $(
+            $($feature:tt)|+ : $spelling:ident $(= $written:literal)? $(as $alias:literal)?
+        ),+ $(,)? })?
+    ),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, ::derive_more::Display)]
+            #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+            #[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+            pub enum $english {
+                #[default]
+                $english,
+                $($(
+                    #[cfg(any($(feature = $feature),+))]
+                    $(
+                        #[display($written)]
+                        #[cfg_attr(feature = "serde", serde(rename = $written))]
+                    )?
+                    $spelling,
+                )+)?
+            }
+
+            impl $crate::traits::WithLanguage for $english {
+                fn with_language(&self, language: $crate::language::Language) -> Self {
+                    match language {
+                        $($($(
+                            #[cfg(feature = $feature)]
+                            keywords!(@language $feature) => Self::$spelling,
+                        )+)+)?
+                        #[allow(unreachable_patterns)]
+                        _ => Self::$english,
+                    }
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de> ::serde::Deserialize<'de> for $english {
+                fn deserialize<D: ::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let s = <::alloc::borrow::Cow<str>>::deserialize(deserializer)?;
+                    let input = $crate::macros::fold(&s);
+                    let matches = |spelling: &str| $crate::macros::fold(spelling) == input;
+
+                    if matches(stringify!($english)) $(|| matches($english_alias))? {
+                        return Ok(Self::$english);
+                    }
+
+                    $($(
+                        #[cfg(any($(feature = $feature),+))]
+                        if matches(stringify!($spelling)) $(|| matches($alias))? {
+                            return Ok(Self::$spelling);
+                        }
+                    )+)?
+
+                    Err(::serde::de::Error::custom(::core::format_args!(
+                        "expected '{}', found '{s}'",
+                        stringify!($english),
+                    )))
+                }
+            }
+        )*
+    };
+    (@language "swedish") => {
+        $crate::language::Language::Swedish(_)
+    };
+    (@language "norwegian") => {
+        $crate::language::Language::Norwegian(_)
+    };
+    (@language "danish") => {
+        $crate::language::Language::Danish(_)
+    };
+    (@language "spanish") => {
+        $crate::language::Language::Spanish(_)
+    };
+    (@language "french") => {
+        $crate::language::Language::French(_)
+    };
+}
+
+pub(crate) use keywords;
+
+/// Folds a spelling for comparison, ignoring case, whitespace, apostrophes, hyphens and the
+/// accents of Spanish and French. Nordic letters such as `å`, `ä` and `ø` are kept, since they
+/// tell Scandinavian spellings apart.
+pub(crate) fn fold(spelling: &str) -> String {
+    let mut folded = String::with_capacity(spelling.len());
+
+    for c in spelling.to_lowercase().chars() {
+        match c {
+            'á' | 'à' | 'â' => folded.push('a'),
+            'é' | 'è' | 'ê' | 'ë' => folded.push('e'),
+            'í' | 'î' | 'ï' => folded.push('i'),
+            'ó' | 'ô' => folded.push('o'),
+            'ú' | 'ù' | 'û' | 'ü' => folded.push('u'),
+            'ÿ' => folded.push('y'),
+            'ñ' => folded.push('n'),
+            'ç' => folded.push('c'),
+            'œ' => folded.push_str("oe"),
+            '\'' | '’' | '-' => {}
+            c if c.is_whitespace() => {}
+            c => folded.push(c),
+        }
+    }
+
+    folded
+}
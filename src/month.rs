@@ -2,246 +2,53 @@
 
 use chrono::{DateTime, Datelike, Months, NaiveTime, Utc};
 use derive_more::Display;
+#[cfg(feature = "schemars")]
 use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    OutOfRange,
+    exact::ExactMonth,
     language::Language,
+    macros::keywords,
     traits::{FromLanguage, WithLanguage},
 };
 
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum January {
-    #[default]
-    January,
-    #[cfg(feature = "swedish")]
-    Januari,
-}
-
-impl WithLanguage for January {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Januari,
-            Language::English(_) => Self::January,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum February {
-    #[default]
-    February,
-    #[cfg(feature = "swedish")]
-    Februari,
-}
-
-impl WithLanguage for February {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Februari,
-            Language::English(_) => Self::February,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum March {
-    #[default]
-    March,
-    #[cfg(feature = "swedish")]
-    Mars,
-}
-
-impl WithLanguage for March {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Mars,
-            Language::English(_) => Self::March,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum April {
-    #[default]
-    April,
-}
-
-impl WithLanguage for April {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::April,
-            Language::English(_) => Self::April,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum May {
-    #[default]
-    May,
-    #[cfg(feature = "swedish")]
-    Maj,
-}
-
-impl WithLanguage for May {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Maj,
-            Language::English(_) => Self::May,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum June {
-    #[default]
-    June,
-    #[cfg(feature = "swedish")]
-    Juni,
-}
-
-impl WithLanguage for June {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Juni,
-            Language::English(_) => Self::June,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum July {
-    #[default]
-    July,
-    #[cfg(feature = "swedish")]
-    Juli,
-}
-impl WithLanguage for July {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Juli,
-            Language::English(_) => Self::July,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum August {
-    #[default]
-    August,
-    #[cfg(feature = "swedish")]
-    Augusti,
-}
-impl WithLanguage for August {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Augusti,
-            Language::English(_) => Self::August,
-        }
-    }
-}
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum September {
-    #[default]
-    September,
-}
-
-impl WithLanguage for September {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::September,
-            Language::English(_) => Self::September,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum October {
-    #[default]
-    October,
-    #[cfg(feature = "swedish")]
-    Oktober,
-}
-
-impl WithLanguage for October {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Oktober,
-            Language::English(_) => Self::October,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum November {
-    #[default]
-    November,
-}
-
-impl WithLanguage for November {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::November,
-            Language::English(_) => Self::November,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum December {
-    #[default]
-    December,
-}
-
-impl WithLanguage for December {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::December,
-            Language::English(_) => Self::December,
-        }
-    }
+keywords! {
+    January as "Jan" {
+        "swedish": Januari,
+        "norwegian" | "danish": Januar,
+        "spanish": Enero,
+        "french": Janvier,
+    },
+    February as "Feb" {
+        "swedish": Februari,
+        "norwegian" | "danish": Februar,
+        "spanish": Febrero,
+        "french": Février,
+    },
+    March as "Mar" { "swedish" | "norwegian" | "french": Mars, "danish": Marts, "spanish": Marzo },
+    April as "Apr" { "spanish": Abril, "french": Avril },
+    May { "swedish" | "danish": Maj, "norwegian" | "french": Mai, "spanish": Mayo },
+    June as "Jun" { "swedish" | "norwegian" | "danish": Juni, "spanish": Junio, "french": Juin },
+    July as "Jul" { "swedish" | "norwegian" | "danish": Juli, "spanish": Julio, "french": Juillet },
+    August as "Aug" { "swedish": Augusti, "spanish": Agosto, "french": Août },
+    September as "Sep" { "spanish": Septiembre, "french": Septembre },
+    October as "Oct" {
+        "swedish" | "norwegian" | "danish": Oktober as "Okt",
+        "spanish": Octubre,
+        "french": Octobre,
+    },
+    November as "Nov" { "spanish": Noviembre, "french": Novembre },
+    December as "Dec" { "norwegian": Desember as "Des", "spanish": Diciembre, "french": Décembre },
 }
 
 /// A month with language-specific representations.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-#[serde(untagged)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Month {
     January(January),
     February(February),
@@ -277,41 +84,41 @@ impl WithLanguage for Month {
 }
 
 impl Month {
-    pub fn january() -> Self {
-        Self::January(January::default())
+    pub const fn january() -> Self {
+        Self::January(January::January)
     }
-    pub fn february() -> Self {
-        Self::February(February::default())
+    pub const fn february() -> Self {
+        Self::February(February::February)
     }
-    pub fn march() -> Self {
-        Self::March(March::default())
+    pub const fn march() -> Self {
+        Self::March(March::March)
     }
-    pub fn april() -> Self {
-        Self::April(April::default())
+    pub const fn april() -> Self {
+        Self::April(April::April)
     }
-    pub fn may() -> Self {
-        Self::May(May::default())
+    pub const fn may() -> Self {
+        Self::May(May::May)
     }
-    pub fn june() -> Self {
-        Self::June(June::default())
+    pub const fn june() -> Self {
+        Self::June(June::June)
     }
-    pub fn july() -> Self {
-        Self::July(July::default())
+    pub const fn july() -> Self {
+        Self::July(July::July)
     }
-    pub fn august() -> Self {
-        Self::August(August::default())
+    pub const fn august() -> Self {
+        Self::August(August::August)
     }
-    pub fn september() -> Self {
-        Self::September(September::default())
+    pub const fn september() -> Self {
+        Self::September(September::September)
     }
-    pub fn october() -> Self {
-        Self::October(October::default())
+    pub const fn october() -> Self {
+        Self::October(October::October)
     }
-    pub fn november() -> Self {
-        Self::November(November::default())
+    pub const fn november() -> Self {
+        Self::November(November::November)
     }
-    pub fn december() -> Self {
-        Self::December(December::default())
+    pub const fn december() -> Self {
+        Self::December(December::December)
     }
     /// Converts to a chrono month.
     pub fn to_chrono(self) -> chrono::Month {
@@ -331,6 +138,24 @@ impl Month {
         }
     }
 
+    /// The number of the month, from 1 for January to 12 for December.
+    pub const fn number(self) -> u8 {
+        match self {
+            Month::January(_) => 1,
+            Month::February(_) => 2,
+            Month::March(_) => 3,
+            Month::April(_) => 4,
+            Month::May(_) => 5,
+            Month::June(_) => 6,
+            Month::July(_) => 7,
+            Month::August(_) => 8,
+            Month::September(_) => 9,
+            Month::October(_) => 10,
+            Month::November(_) => 11,
+            Month::December(_) => 12,
+        }
+    }
+
     /// Extracts the month from a timestamp in the specified language.
     ///
     /// When `first_midnight_means_month_before` is true, midnight on the first of the month
@@ -350,6 +175,12 @@ impl Month {
             month_number
         };
 
+        Self::from_number(month_number, language)
+    }
+
+    /// The month with the given number, from 1 for January, in the specified language. Zero
+    /// wraps to December.
+    pub(crate) fn from_number(month_number: u32, language: Language) -> Self {
         match month_number {
             1 => Self::January(January::from_language(language)),
             2 => Self::February(February::from_language(language)),
@@ -367,6 +198,29 @@ impl Month {
         }
     }
 
+    /// Converts to midnight on the first of this month, relative to the given time.
+    ///
+    /// When `skip_self` is true, finds the next occurrence even if the current month matches.
+    pub fn to_chrono_min(self, relative_to: DateTime<Utc>, skip_self: bool) -> DateTime<Utc> {
+        self.to_chrono_max(relative_to, skip_self)
+            .checked_sub_months(Months::new(1))
+            .unwrap()
+    }
+
+    /// Midnight on the first of each occurrence of this month that has not ended by the given
+    /// time, in order.
+    ///
+    /// The reference month is included when it matches.
+    pub fn occurrences(self, relative_to: DateTime<Utc>) -> impl Iterator<Item = DateTime<Utc>> {
+        let first = self
+            .to_chrono_max(relative_to, false)
+            .checked_sub_months(Months::new(1));
+
+        core::iter::successors(first, |previous| {
+            previous.checked_add_months(Months::new(12))
+        })
+    }
+
     /// Converts to midnight on the first of the following month, relative to the given time.
     ///
     /// When `skip_self` is true, finds the next occurrence even if the current month matches.
@@ -396,4 +250,83 @@ impl Month {
             .with_time(NaiveTime::MIN)
             .unwrap()
     }
+
+    /// Converts to midnight on the first of the most recent occurrence of this month, relative
+    /// to the given time.
+    ///
+    /// When `skip_self` is true, finds the previous occurrence even if the current month matches.
+    pub fn to_chrono_min_past(self, relative_to: DateTime<Utc>, skip_self: bool) -> DateTime<Utc> {
+        let current_month = relative_to.month();
+        let target_month = self.to_chrono().number_from_month();
+
+        let months_since = (current_month + 12 - target_month) % 12;
+        let months_to_subtract = if months_since == 0 && skip_self {
+            12
+        } else {
+            months_since
+        };
+
+        relative_to
+            .with_day(1)
+            .unwrap()
+            .checked_sub_months(Months::new(months_to_subtract))
+            .unwrap()
+            .with_time(NaiveTime::MIN)
+            .unwrap()
+    }
+
+    /// Converts to midnight on the first of the month following the most recent occurrence of
+    /// this month, relative to the given time.
+    ///
+    /// When `skip_self` is true, finds the previous occurrence even if the current month matches.
+    pub fn to_chrono_max_past(self, relative_to: DateTime<Utc>, skip_self: bool) -> DateTime<Utc> {
+        self.to_chrono_min_past(relative_to, skip_self)
+            .checked_add_months(Months::new(1))
+            .unwrap()
+    }
+
+    /// Time left until this month next starts, as with [`Month::to_chrono_min`], negative once it
+    /// has started.
+    pub fn time_until(self, relative_to: DateTime<Utc>, skip_self: bool) -> chrono::Duration {
+        self.to_chrono_min(relative_to, skip_self) - relative_to
+    }
+
+    /// Time elapsed since this month last started, as with [`Month::to_chrono_min_past`].
+    pub fn time_since(self, relative_to: DateTime<Utc>, skip_self: bool) -> chrono::Duration {
+        relative_to - self.to_chrono_min_past(relative_to, skip_self)
+    }
+}
+
+impl From<chrono::Month> for Month {
+    fn from(month: chrono::Month) -> Self {
+        Self::from_number(month.number_from_month(), Language::default())
+    }
+}
+
+impl From<Month> for chrono::Month {
+    fn from(month: Month) -> Self {
+        month.to_chrono()
+    }
+}
+
+impl From<ExactMonth> for Month {
+    fn from(month: ExactMonth) -> Self {
+        Self::from_number(u8::from(month).into(), Language::default())
+    }
+}
+
+impl From<Month> for u8 {
+    fn from(month: Month) -> Self {
+        month.number()
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = OutOfRange;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        let month = ExactMonth::try_new(number).map_err(|_| OutOfRange(number))?;
+
+        Ok(month.into())
+    }
 }
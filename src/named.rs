@@ -0,0 +1,357 @@
+//! Application-defined named recurring dates, such as "Payday" on the 25th.
+//!
+//! Dates are registered once at startup with [`register`]. From then on their labels parse,
+//! deserialise and display like built-in keywords, as [`Time::Named`](crate::Time::Named).
+//!
+//! The registry needs the `std` feature. Without it nothing is registered, so no labels resolve.
+
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem::discriminant,
+    str::FromStr,
+};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{holiday::Holiday, language::Language, parse::ParseError, traits::WithLanguage};
+
+#[cfg(feature = "std")]
+static REGISTRY: RwLock<Vec<Arc<NamedDate>>> = RwLock::new(Vec::new());
+
+/// When a named date recurs.
+///
+/// Days past the end of a month fall on its last day, so `DayOfMonth(31)` is April 30th in April
+/// and `Date { month: 2, day: 29 }` is February 28th outside leap years.
+#[derive(Debug, Clone, Copy)]
+pub enum Recurrence {
+    /// A day of every month.
+    DayOfMonth(u32),
+    /// A day of every week.
+    Weekday(chrono::Weekday),
+    /// A day of every year.
+    Date { month: u32, day: u32 },
+    /// A day of every year on a date computed per year, such as Easter.
+    Yearly(&'static dyn Holiday),
+}
+
+impl PartialEq for Recurrence {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::DayOfMonth(a), Self::DayOfMonth(b)) => a == b,
+            (Self::Weekday(a), Self::Weekday(b)) => a == b,
+            (Self::Date { month, day }, Self::Date { month: m, day: d }) => (month, day) == (m, d),
+            (Self::Yearly(a), Self::Yearly(b)) => {
+                a.name(Language::default()) == b.name(Language::default())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Recurrence {}
+
+impl Hash for Recurrence {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        discriminant(self).hash(state);
+
+        match self {
+            Self::DayOfMonth(day) => day.hash(state),
+            Self::Weekday(weekday) => weekday.hash(state),
+            Self::Date { month, day } => (month, day).hash(state),
+            Self::Yearly(holiday) => holiday.name(Language::default()).hash(state),
+        }
+    }
+}
+
+impl Recurrence {
+    /// The first date on or after the given one on which the date recurs.
+    pub(crate) fn next(&self, from: NaiveDate) -> NaiveDate {
+        match *self {
+            Recurrence::DayOfMonth(day) => (0..)
+                .map(|months| clamped(first_of_month(from) + Months::new(months), day))
+                .find(|date| *date >= from)
+                .unwrap(),
+            Recurrence::Weekday(weekday) => {
+                let days = weekday.days_since(from.weekday());
+                from + Days::new(days.into())
+            }
+            Recurrence::Date { month, day } => (0..)
+                .filter_map(|years| {
+                    NaiveDate::from_ymd_opt(from.year() + years, month.clamp(1, 12), 1)
+                })
+                .map(|first| clamped(first, day))
+                .find(|date| *date >= from)
+                .unwrap(),
+            Recurrence::Yearly(holiday) => (0..)
+                .filter_map(|years| holiday.date_in(from.year() + years))
+                .find(|date| *date >= from)
+                .unwrap(),
+        }
+    }
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap()
+}
+
+/// The day in the month of `first`, clamped to the month's length.
+fn clamped(first: NaiveDate, day: u32) -> NaiveDate {
+    let last = (first + Months::new(1)).pred_opt().unwrap().day();
+    first.with_day(day.clamp(1, last)).unwrap()
+}
+
+/// A recurring date defined by the application, with a label per language.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamedDate {
+    name: String,
+    recurrence: Recurrence,
+    labels: Vec<(Language, String)>,
+}
+
+impl NamedDate {
+    /// Defines a date whose name doubles as its label in every language without one.
+    pub fn new(name: impl Into<String>, recurrence: Recurrence) -> Self {
+        Self {
+            name: name.into(),
+            recurrence,
+            labels: Vec::new(),
+        }
+    }
+
+    /// Labels the date in the given language, e.g. `"Lönedag"` in Swedish.
+    pub fn with_label(mut self, language: Language, label: impl Into<String>) -> Self {
+        self.labels
+            .retain(|(other, _)| discriminant(other) != discriminant(&language));
+        self.labels.push((language, label.into()));
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn recurrence(&self) -> Recurrence {
+        self.recurrence
+    }
+
+    /// The label in the given language, falling back to the name.
+    pub fn label(&self, language: Language) -> &str {
+        self.labels
+            .iter()
+            .find(|(other, _)| discriminant(other) == discriminant(&language))
+            .map_or(&self.name, |(_, label)| label)
+    }
+}
+
+/// Registers a date, replacing any registered under the same name.
+#[cfg(feature = "std")]
+pub fn register(date: NamedDate) {
+    let mut registry = REGISTRY.write().unwrap();
+
+    registry.retain(|other| other.name != date.name);
+    registry.push(Arc::new(date));
+}
+
+/// Removes the date registered under the name, returning whether there was one.
+#[cfg(feature = "std")]
+pub fn unregister(name: &str) -> bool {
+    let mut registry = REGISTRY.write().unwrap();
+    let count = registry.len();
+
+    registry.retain(|other| other.name != name);
+    registry.len() != count
+}
+
+/// Unregisters the named date when dropped, so a test's registration never leaks into the other
+/// tests sharing the registry, even when it fails.
+#[cfg(all(test, feature = "std"))]
+pub(crate) struct Unregister<'a>(pub(crate) &'a str);
+
+#[cfg(all(test, feature = "std"))]
+impl Drop for Unregister<'_> {
+    fn drop(&mut self) {
+        unregister(self.0);
+    }
+}
+
+/// Every registered date, in registration order.
+#[cfg(feature = "std")]
+pub fn registered() -> Vec<Arc<NamedDate>> {
+    REGISTRY.read().unwrap().clone()
+}
+
+/// Every registered date, always none without the registry.
+#[cfg(not(feature = "std"))]
+pub fn registered() -> Vec<Arc<NamedDate>> {
+    Vec::new()
+}
+
+/// The registered date labelled `label` in any enabled language, in that language.
+///
+/// Labels are compared ignoring case and whitespace, the way [`parse`](crate::parse::parse)
+/// matches keywords.
+pub fn lookup(label: &str) -> Option<NamedTime> {
+    lookup_in(label, &Language::all())
+}
+
+/// The registered date labelled `label` in any of the languages, in that language.
+pub(crate) fn lookup_in(label: &str, languages: &[Language]) -> Option<NamedTime> {
+    let phrase = normalize(label);
+
+    registered().into_iter().find_map(|date| {
+        languages
+            .iter()
+            .copied()
+            .find(|language| normalize(date.label(*language)) == phrase)
+            .map(|language| NamedTime { date, language })
+    })
+}
+
+fn normalize(label: &str) -> String {
+    label.split_whitespace().collect::<String>().to_lowercase()
+}
+
+/// A registered date in the language it was written in.
+///
+/// Resolves to the whole day of its next occurrence, with the reference day matching. Serialises
+/// as its label, e.g. `"Payday"`, and deserialises from any registered label.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamedTime {
+    date: Arc<NamedDate>,
+    language: Language,
+}
+
+impl NamedTime {
+    pub fn date(&self) -> &NamedDate {
+        &self.date
+    }
+
+    /// Converts to midnight starting the next occurrence, relative to the given time.
+    pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.date
+            .recurrence
+            .next(relative_to.date_naive())
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+    }
+
+    /// Converts to midnight ending the next occurrence, relative to the given time.
+    pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_min(relative_to) + Days::new(1)
+    }
+}
+
+impl WithLanguage for NamedTime {
+    fn with_language(&self, language: Language) -> Self {
+        Self {
+            date: self.date.clone(),
+            language,
+        }
+    }
+}
+
+impl fmt::Display for NamedTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.date.label(self.language))
+    }
+}
+
+impl FromStr for NamedTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lookup(s).ok_or_else(|| ParseError::Unrecognised(s.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for NamedTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for NamedTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for NamedTime {
+    fn schema_name() -> Cow<'static, str> {
+        "NamedTime".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "The label of a date registered by the application, e.g. \"Payday\".",
+            "type": "string",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn resolves_recurrences() {
+        // Tuesday July 29th, 2025
+        let from = at("2025-07-29T10:30:05Z").date_naive();
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+
+        assert_eq!(Recurrence::DayOfMonth(25).next(from), date("2025-08-25"));
+        assert_eq!(Recurrence::DayOfMonth(31).next(from), date("2025-07-31"));
+        assert_eq!(
+            Recurrence::Weekday(chrono::Weekday::Tue).next(from),
+            date("2025-07-29")
+        );
+        assert_eq!(
+            Recurrence::Date { month: 2, day: 29 }.next(from),
+            date("2026-02-28")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn registers_labels() {
+        let _registration = Unregister("TestPayday");
+        register(
+            NamedDate::new("TestPayday", Recurrence::DayOfMonth(25))
+                .with_label(Language::default(), "Test payday"),
+        );
+
+        let named: NamedTime = "testpayday".parse().unwrap();
+        let relative_to = at("2025-07-29T10:30:05Z");
+
+        assert_eq!(named.to_string(), "Test payday");
+        assert_eq!(
+            named.clone().to_chrono_min(relative_to),
+            at("2025-08-25T00:00:00Z")
+        );
+        assert_eq!(named.to_chrono_max(relative_to), at("2025-08-26T00:00:00Z"));
+        assert!(unregister("TestPayday"));
+        assert!("Test payday".parse::<NamedTime>().is_err());
+    }
+}
@@ -0,0 +1,303 @@
+//! Offsets from the current day, week, month or year, such as "in 3 days".
+
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{language::Language, macros::fold, parse::ParseError, traits::WithLanguage};
+
+/// The calendar unit an [`Offset`] counts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Days,
+    /// Days skipping weekends and the holidays in the
+    /// [`BusinessCalendar`](crate::business::BusinessCalendar).
+    BusinessDays,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Unit {
+    const ALL: [Unit; 5] = [
+        Unit::Days,
+        Unit::BusinessDays,
+        Unit::Weeks,
+        Unit::Months,
+        Unit::Years,
+    ];
+
+    /// The largest amount an [`Offset`] may count in the unit, about ten thousand years, so every
+    /// offset resolves within the range chrono represents. Larger amounts are rejected when
+    /// parsing.
+    pub const fn max_amount(self) -> u32 {
+        match self {
+            Unit::Days | Unit::BusinessDays => 3_660_000,
+            Unit::Weeks => 522_000,
+            Unit::Months => 120_000,
+            Unit::Years => 10_000,
+        }
+    }
+
+    /// The name of the unit in the given language, singular for an amount of one.
+    fn name(self, amount: u32, language: Language) -> &'static str {
+        let singular = amount == 1;
+
+        match language {
+            #[cfg(feature = "swedish")]
+            Language::Swedish(_) => match (self, singular) {
+                (Unit::Days, true) => "Dag",
+                (Unit::Days, false) => "Dagar",
+                (Unit::BusinessDays, true) => "Arbetsdag",
+                (Unit::BusinessDays, false) => "Arbetsdagar",
+                (Unit::Weeks, true) => "Vecka",
+                (Unit::Weeks, false) => "Veckor",
+                (Unit::Months, true) => "Månad",
+                (Unit::Months, false) => "Månader",
+                (Unit::Years, _) => "År",
+            },
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => match (self, singular) {
+                (Unit::Days, true) => "Dag",
+                (Unit::Days, false) => "Dager",
+                (Unit::BusinessDays, true) => "Virkedag",
+                (Unit::BusinessDays, false) => "Virkedager",
+                (Unit::Weeks, true) => "Uke",
+                (Unit::Weeks, false) => "Uker",
+                (Unit::Months, true) => "Måned",
+                (Unit::Months, false) => "Måneder",
+                (Unit::Years, _) => "År",
+            },
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => match (self, singular) {
+                (Unit::Days, true) => "Dag",
+                (Unit::Days, false) => "Dage",
+                (Unit::BusinessDays, true) => "Arbejdsdag",
+                (Unit::BusinessDays, false) => "Arbejdsdage",
+                (Unit::Weeks, true) => "Uge",
+                (Unit::Weeks, false) => "Uger",
+                (Unit::Months, true) => "Måned",
+                (Unit::Months, false) => "Måneder",
+                (Unit::Years, _) => "År",
+            },
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => match (self, singular) {
+                (Unit::Days, true) => "Día",
+                (Unit::Days, false) => "Días",
+                (Unit::BusinessDays, true) => "DíaHábil",
+                (Unit::BusinessDays, false) => "DíasHábiles",
+                (Unit::Weeks, true) => "Semana",
+                (Unit::Weeks, false) => "Semanas",
+                (Unit::Months, true) => "Mes",
+                (Unit::Months, false) => "Meses",
+                (Unit::Years, true) => "Año",
+                (Unit::Years, false) => "Años",
+            },
+            #[cfg(feature = "french")]
+            Language::French(_) => match (self, singular) {
+                (Unit::Days, true) => "Jour",
+                (Unit::Days, false) => "Jours",
+                (Unit::BusinessDays, true) => "JourOuvré",
+                (Unit::BusinessDays, false) => "JoursOuvrés",
+                (Unit::Weeks, true) => "Semaine",
+                (Unit::Weeks, false) => "Semaines",
+                (Unit::Months, _) => "Mois",
+                (Unit::Years, true) => "An",
+                (Unit::Years, false) => "Ans",
+            },
+            Language::English(_) => match (self, singular) {
+                (Unit::Days, true) => "Day",
+                (Unit::Days, false) => "Days",
+                (Unit::BusinessDays, true) => "BusinessDay",
+                (Unit::BusinessDays, false) => "BusinessDays",
+                (Unit::Weeks, true) => "Week",
+                (Unit::Weeks, false) => "Weeks",
+                (Unit::Months, true) => "Month",
+                (Unit::Months, false) => "Months",
+                (Unit::Years, true) => "Year",
+                (Unit::Years, false) => "Years",
+            },
+        }
+    }
+}
+
+/// The word introducing an offset in the given language.
+fn prefix(language: Language) -> &'static str {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => "Om",
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => "Om",
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => "Om",
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => "En",
+        #[cfg(feature = "french")]
+        Language::French(_) => "Dans",
+        Language::English(_) => "In",
+    }
+}
+
+/// A whole day, week, month or year a number of units after the current one.
+///
+/// "In 1 week" is the same window as "next week", and "in 0 days" is today. Serialises as a
+/// single word in the language it was written in, e.g. `"In3Days"` or `"Om3Dagar"`, and parses
+/// ignoring case, whitespace and accents, so `"in 3 days"` is accepted too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Offset {
+    amount: u32,
+    unit: Unit,
+    language: Language,
+}
+
+impl Offset {
+    /// Creates an offset. Amounts reaching past the range chrono represents resolve to its latest
+    /// timestamp.
+    pub fn new(amount: u32, unit: Unit) -> Self {
+        Self {
+            amount,
+            unit,
+            language: Language::default(),
+        }
+    }
+
+    pub fn amount(&self) -> u32 {
+        self.amount
+    }
+
+    pub fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    /// Parses an offset written in any of the languages, e.g. `"in3days"`.
+    pub(crate) fn parse_in(input: &str, languages: &[Language]) -> Option<Self> {
+        let input = fold(input);
+
+        languages.iter().copied().find_map(|language| {
+            let rest = input.strip_prefix(&fold(prefix(language)))?;
+            let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+            let (amount, unit) = rest.split_at(digits);
+            let amount = amount.parse().ok()?;
+
+            Unit::ALL
+                .into_iter()
+                .find(|candidate| {
+                    [1, 2]
+                        .into_iter()
+                        .any(|count| fold(candidate.name(count, language)) == unit)
+                })
+                .filter(|unit| amount <= unit.max_amount())
+                .map(|unit| Self {
+                    amount,
+                    unit,
+                    language,
+                })
+        })
+    }
+}
+
+impl WithLanguage for Offset {
+    fn with_language(&self, language: Language) -> Self {
+        Self { language, ..*self }
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = self.unit.name(self.amount, self.language);
+
+        write!(f, "{}{}{unit}", prefix(self.language), self.amount)
+    }
+}
+
+impl FromStr for Offset {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_in(s, &Language::all()).ok_or_else(|| ParseError::Unrecognised(s.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Offset {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Offset {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Offset {
+    fn schema_name() -> Cow<'static, str> {
+        "Offset".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A day, week, month or year a number of units from now, e.g. \"In3Days\".",
+            "type": "string",
+            "pattern": "^(In|Om)[0-9]+[A-Za-zÅåÄäÖö]+$",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_and_parses() {
+        let offset = Offset::new(3, Unit::Days);
+
+        assert_eq!(offset.to_string(), "In3Days");
+        assert_eq!(Offset::new(1, Unit::Weeks).to_string(), "In1Week");
+        assert_eq!("in 3 days".parse(), Ok(offset));
+        assert_eq!("In3Days".parse(), Ok(offset));
+        assert_eq!(
+            "in 5 business days".parse(),
+            Ok(Offset::new(5, Unit::BusinessDays))
+        );
+        assert!("In3Fortnights".parse::<Offset>().is_err());
+        assert!("InDays".parse::<Offset>().is_err());
+        assert_eq!("In10000Years".parse(), Ok(Offset::new(10_000, Unit::Years)));
+        assert!("In10001Years".parse::<Offset>().is_err());
+        assert!("In4294967295Days".parse::<Offset>().is_err());
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(serde_json::to_string(&offset).unwrap(), r#""In3Days""#);
+            assert!(serde_json::from_str::<Offset>(r#""In4294967295Years""#).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn formats_in_swedish() {
+        use crate::language::Swedish;
+
+        let swedish = Language::Swedish(Swedish::default());
+        let offset: Offset = "om 6 månader".parse().unwrap();
+
+        assert_eq!(offset.amount(), 6);
+        assert_eq!(offset.unit(), Unit::Months);
+        assert_eq!(offset.to_string(), "Om6Månader");
+        assert_eq!(
+            Offset::new(1, Unit::Years)
+                .with_language(swedish)
+                .to_string(),
+            "Om1År"
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! Caller-controlled conventions for resolving values.
+
+use alloc::vec::Vec;
+
+use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{business::BusinessCalendar, candidate::Candidate, day_part::Part};
+
+/// Conventions applied when resolving values, e.g. which day weeks start on.
+///
+/// Pass to the `_with` conversions such as [`Time::to_chrono_min_with`](crate::Time). The
+/// plain conversions use the defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Options {
+    /// The first day of `ThisWeek` and `NextWeek`, Monday by default.
+    pub week_start: chrono::Weekday,
+    /// The hours day parts like `Morning` start at.
+    pub day_parts: DayParts,
+    /// The holidays `NextBusinessDay` and business day offsets skip, none by default.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub business_calendar: BusinessCalendar,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            week_start: chrono::Weekday::Mon,
+            day_parts: DayParts::default(),
+            business_calendar: BusinessCalendar::default(),
+        }
+    }
+}
+
+impl Options {
+    pub fn with_week_start(self, week_start: chrono::Weekday) -> Self {
+        Self { week_start, ..self }
+    }
+
+    pub fn with_day_parts(self, day_parts: DayParts) -> Self {
+        Self { day_parts, ..self }
+    }
+
+    pub fn with_business_calendar(self, business_calendar: BusinessCalendar) -> Self {
+        Self {
+            business_calendar,
+            ..self
+        }
+    }
+}
+
+/// The hour each day part starts at, each ending where the next one starts.
+///
+/// Night ends at the start of the following morning. Hours are expected to be ascending, with
+/// the default parts starting at 06:00, 12:00, 18:00 and 22:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DayParts {
+    pub morning: u8,
+    pub afternoon: u8,
+    pub evening: u8,
+    pub night: u8,
+}
+
+impl Default for DayParts {
+    fn default() -> Self {
+        Self {
+            morning: 6,
+            afternoon: 12,
+            evening: 18,
+            night: 22,
+        }
+    }
+}
+
+impl DayParts {
+    /// The hour the part starts at.
+    pub fn start(&self, part: Part) -> u8 {
+        match part {
+            Part::Morning => self.morning,
+            Part::Afternoon => self.afternoon,
+            Part::Evening => self.evening,
+            Part::Night => self.night,
+        }
+    }
+
+    /// The hour the part ends at, on the following day for the night.
+    pub fn end(&self, part: Part) -> u8 {
+        match part {
+            Part::Morning => self.afternoon,
+            Part::Afternoon => self.evening,
+            Part::Evening => self.night,
+            Part::Night => self.morning,
+        }
+    }
+}
+
+/// Which representation [`Time::from_max_chrono_with`](crate::Time) and
+/// [`Time::from_min_chrono_with`](crate::Time) return when a timestamp matches several.
+///
+/// The defaults give the same results as the plain conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FromChronoOptions {
+    /// Prefer keywords like `ThisWeek`, `NextMonth` and `ThisYear` over the weekday or month
+    /// ending with them, e.g. "ThisWeek" rather than "Sunday". Off by default.
+    pub prefer_relative: bool,
+    /// Prefer weekday names over `Today`, `Tomorrow` and `Yesterday`, e.g. "Wednesday" rather
+    /// than "Tomorrow". Off by default.
+    pub prefer_weekday: bool,
+    /// Whether month names like "August" may be returned, on by default.
+    pub allow_months: bool,
+    /// How many days ahead of the reference time timestamps may be expressed as keywords.
+    /// Later ones stay timestamps. Unlimited by default.
+    pub max_lookahead_days: Option<u32>,
+}
+
+impl Default for FromChronoOptions {
+    fn default() -> Self {
+        Self {
+            prefer_relative: false,
+            prefer_weekday: false,
+            allow_months: true,
+            max_lookahead_days: None,
+        }
+    }
+}
+
+impl FromChronoOptions {
+    pub fn with_prefer_relative(self, prefer_relative: bool) -> Self {
+        Self {
+            prefer_relative,
+            ..self
+        }
+    }
+
+    pub fn with_prefer_weekday(self, prefer_weekday: bool) -> Self {
+        Self {
+            prefer_weekday,
+            ..self
+        }
+    }
+
+    pub fn with_allow_months(self, allow_months: bool) -> Self {
+        Self {
+            allow_months,
+            ..self
+        }
+    }
+
+    pub fn with_max_lookahead_days(self, max_lookahead_days: Option<u32>) -> Self {
+        Self {
+            max_lookahead_days,
+            ..self
+        }
+    }
+
+    /// Whether a timestamp is close enough to the reference time to be expressed as a keyword.
+    pub(crate) fn allows(&self, date_time: DateTime<Utc>, relative_to: DateTime<Utc>) -> bool {
+        self.max_lookahead_days
+            .is_none_or(|days| date_time - relative_to <= Duration::days(days.into()))
+    }
+
+    /// The groups of keywords to try, in order of preference.
+    pub fn candidates(&self) -> Vec<Candidate> {
+        let relative = [
+            Candidate::ThisMonth,
+            Candidate::Quarter,
+            Candidate::Week,
+            Candidate::Weekend,
+            Candidate::NextMonth,
+            Candidate::Year,
+            Candidate::Last,
+        ];
+        let mut candidates = Vec::with_capacity(Candidate::DEFAULT_ORDER.len());
+
+        if !self.prefer_weekday {
+            candidates.push(Candidate::Day);
+        }
+
+        if self.prefer_relative {
+            candidates.extend(relative);
+        }
+
+        candidates.push(Candidate::Weekday);
+
+        if self.prefer_weekday {
+            candidates.push(Candidate::Day);
+        }
+
+        if !self.prefer_relative {
+            candidates.extend([Candidate::ThisMonth, Candidate::Quarter]);
+        }
+
+        if self.allow_months {
+            candidates.push(Candidate::Month);
+        }
+
+        if !self.prefer_relative {
+            candidates.extend(&relative[2..]);
+        }
+
+        candidates.extend([Candidate::DayPart, Candidate::Hour]);
+        candidates
+    }
+}
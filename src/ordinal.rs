@@ -0,0 +1,363 @@
+//! Ordinal weekdays within a month, such as "first Monday of March".
+
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+use alloc::{string::ToString, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{
+    language::Language,
+    month::Month,
+    parse::{ParseError, parse_ordinal_weekday},
+    traits::WithLanguage,
+    weekday::Weekday,
+};
+
+/// Which occurrence of a weekday within a month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ordinal {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+    Last,
+}
+
+impl Ordinal {
+    pub(crate) const ALL: [Ordinal; 6] = [
+        Ordinal::First,
+        Ordinal::Second,
+        Ordinal::Third,
+        Ordinal::Fourth,
+        Ordinal::Fifth,
+        Ordinal::Last,
+    ];
+
+    /// The name of the ordinal in the given language.
+    pub(crate) fn name(self, language: Language) -> &'static str {
+        match language {
+            #[cfg(feature = "swedish")]
+            Language::Swedish(_) => match self {
+                Ordinal::First => "Första",
+                Ordinal::Second => "Andra",
+                Ordinal::Third => "Tredje",
+                Ordinal::Fourth => "Fjärde",
+                Ordinal::Fifth => "Femte",
+                Ordinal::Last => "Sista",
+            },
+            #[cfg(feature = "norwegian")]
+            Language::Norwegian(_) => match self {
+                Ordinal::First => "Første",
+                Ordinal::Second => "Andre",
+                Ordinal::Third => "Tredje",
+                Ordinal::Fourth => "Fjerde",
+                Ordinal::Fifth => "Femte",
+                Ordinal::Last => "Siste",
+            },
+            #[cfg(feature = "danish")]
+            Language::Danish(_) => match self {
+                Ordinal::First => "Første",
+                Ordinal::Second => "Anden",
+                Ordinal::Third => "Tredje",
+                Ordinal::Fourth => "Fjerde",
+                Ordinal::Fifth => "Femte",
+                Ordinal::Last => "Sidste",
+            },
+            #[cfg(feature = "spanish")]
+            Language::Spanish(_) => match self {
+                Ordinal::First => "Primer",
+                Ordinal::Second => "Segundo",
+                Ordinal::Third => "Tercer",
+                Ordinal::Fourth => "Cuarto",
+                Ordinal::Fifth => "Quinto",
+                Ordinal::Last => "Último",
+            },
+            #[cfg(feature = "french")]
+            Language::French(_) => match self {
+                Ordinal::First => "Premier",
+                Ordinal::Second => "Deuxième",
+                Ordinal::Third => "Troisième",
+                Ordinal::Fourth => "Quatrième",
+                Ordinal::Fifth => "Cinquième",
+                Ordinal::Last => "Dernier",
+            },
+            Language::English(_) => match self {
+                Ordinal::First => "First",
+                Ordinal::Second => "Second",
+                Ordinal::Third => "Third",
+                Ordinal::Fourth => "Fourth",
+                Ordinal::Fifth => "Fifth",
+                Ordinal::Last => "Last",
+            },
+        }
+    }
+
+    /// The date of this occurrence of the weekday in the month starting on `first`, if the month
+    /// has one.
+    fn in_month(self, weekday: chrono::Weekday, first: NaiveDate) -> Option<NaiveDate> {
+        let weeks = match self {
+            Ordinal::First => 0,
+            Ordinal::Second => 1,
+            Ordinal::Third => 2,
+            Ordinal::Fourth => 3,
+            Ordinal::Fifth => 4,
+            Ordinal::Last => {
+                let last = (first + Months::new(1)).pred_opt()?;
+                return last.checked_sub_days(Days::new(last.weekday().days_since(weekday).into()));
+            }
+        };
+
+        let date = first + Days::new((weekday.days_since(first.weekday()) + weeks * 7).into());
+        (date.month() == first.month()).then_some(date)
+    }
+}
+
+/// The word joining an ordinal weekday to its month in the given language.
+fn connector(language: Language) -> &'static str {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => "i",
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => "i",
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => "i",
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => "de",
+        #[cfg(feature = "french")]
+        Language::French(_) => "de",
+        Language::English(_) => "of",
+    }
+}
+
+/// The phrase standing for every month after an ordinal weekday in the given language.
+fn every_month(language: Language) -> &'static str {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => "i månaden",
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => "i måneden",
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => "i måneden",
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => "del mes",
+        #[cfg(feature = "french")]
+        Language::French(_) => "du mois",
+        Language::English(_) => "of the month",
+    }
+}
+
+/// An occurrence of a weekday within a month, e.g. `"First Monday of March"` or
+/// `"Last Friday of the month"`.
+///
+/// Without a month, every month matches, and the month is spelled as "the month" in the
+/// weekday's language. A bare `"Last Friday"` is a [`LastWeekday`](crate::weekday::LastWeekday)
+/// instead. Resolves to the whole day of the next occurrence, with
+/// the reference day matching, and months lacking a fifth occurrence are skipped. Serialises as a
+/// single string in the weekday's language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrdinalWeekday {
+    ordinal: Ordinal,
+    weekday: Weekday,
+    month: Option<Month>,
+}
+
+impl OrdinalWeekday {
+    pub fn new(ordinal: Ordinal, weekday: Weekday, month: Option<Month>) -> Self {
+        Self {
+            ordinal,
+            weekday,
+            month,
+        }
+    }
+
+    pub fn ordinal(&self) -> Ordinal {
+        self.ordinal
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    pub fn month(&self) -> Option<Month> {
+        self.month
+    }
+
+    /// Converts to midnight starting the next occurrence, relative to the given time.
+    pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        let today = relative_to.date_naive();
+        let first = today.with_day(1).unwrap();
+        let weekday = self.weekday.to_chrono();
+
+        (0..)
+            .map(|months| first + Months::new(months))
+            .filter(|first| {
+                self.month
+                    .is_none_or(|month| month.to_chrono().number_from_month() == first.month())
+            })
+            .filter_map(|first| self.ordinal.in_month(weekday, first))
+            .find(|date| *date >= today)
+            .unwrap()
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+    }
+
+    /// Converts to midnight ending the next occurrence, relative to the given time.
+    pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_min(relative_to) + Days::new(1)
+    }
+}
+
+impl WithLanguage for OrdinalWeekday {
+    fn with_language(&self, language: Language) -> Self {
+        Self {
+            ordinal: self.ordinal,
+            weekday: self.weekday.with_language(language),
+            month: self.month.map(|month| month.with_language(language)),
+        }
+    }
+}
+
+impl fmt::Display for OrdinalWeekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let language = self.weekday.language();
+
+        write!(f, "{} {}", self.ordinal.name(language), self.weekday)?;
+
+        match self.month {
+            Some(month) => write!(f, " {} {month}", connector(language)),
+            None => write!(f, " {}", every_month(language)),
+        }
+    }
+}
+
+impl FromStr for OrdinalWeekday {
+    type Err = ParseError;
+
+    /// Parses an ordinal, a weekday and a month or the whole month in any enabled language, e.g.
+    /// `"last friday of the month"` or `"första måndagen i mars"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.to_lowercase();
+        let tokens: Vec<&str> = lowercase.split_whitespace().collect();
+
+        parse_ordinal_weekday(&tokens, &Language::all())
+            .ok_or_else(|| ParseError::Unrecognised(s.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for OrdinalWeekday {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for OrdinalWeekday {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for OrdinalWeekday {
+    fn schema_name() -> Cow<'static, str> {
+        "OrdinalWeekday".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "An occurrence of a weekday within a month, e.g. \"First Monday of March\" or \"Last Friday of the month\".",
+            "type": "string",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn resolves_occurrences() {
+        // Tuesday July 29th, 2025
+        let relative_to = at("2025-07-29T10:30:05Z");
+        let min = |ordinal, weekday, month| {
+            OrdinalWeekday::new(ordinal, weekday, month).to_chrono_min(relative_to)
+        };
+
+        assert_eq!(
+            min(Ordinal::First, Weekday::monday(), Some(Month::march())),
+            at("2026-03-02T00:00:00Z")
+        );
+        assert_eq!(
+            min(Ordinal::Last, Weekday::tuesday(), None),
+            at("2025-07-29T00:00:00Z")
+        );
+        assert_eq!(
+            min(Ordinal::Last, Weekday::friday(), None),
+            at("2025-08-29T00:00:00Z")
+        );
+        // August 2025 has no fifth Monday, but September does
+        assert_eq!(
+            min(Ordinal::Fifth, Weekday::monday(), None),
+            at("2025-09-29T00:00:00Z")
+        );
+        assert_eq!(
+            OrdinalWeekday::new(Ordinal::Second, Weekday::sunday(), None)
+                .to_chrono_max(relative_to),
+            at("2025-08-11T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn formats_and_parses() {
+        let first_monday =
+            OrdinalWeekday::new(Ordinal::First, Weekday::monday(), Some(Month::march()));
+
+        assert_eq!(first_monday.to_string(), "First Monday of March");
+        assert_eq!("first monday of march".parse(), Ok(first_monday));
+        assert_eq!(
+            "last friday of the month".parse(),
+            Ok(OrdinalWeekday::new(Ordinal::Last, Weekday::friday(), None))
+        );
+        assert_eq!(
+            OrdinalWeekday::new(Ordinal::Last, Weekday::friday(), None).to_string(),
+            "Last Friday of the month"
+        );
+        assert!("last friday".parse::<OrdinalWeekday>().is_err());
+        assert!("sixth monday of march".parse::<OrdinalWeekday>().is_err());
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::to_string(&first_monday).unwrap(),
+            r#""First Monday of March""#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn formats_in_swedish() {
+        use crate::language::Swedish;
+
+        let swedish = Language::Swedish(Swedish::default());
+        let last_friday: OrdinalWeekday = "sista fredagen i mars".parse().unwrap();
+
+        assert_eq!(last_friday.to_string(), "Sista Fredag i Mars");
+        assert_eq!(
+            OrdinalWeekday::new(Ordinal::First, Weekday::monday(), None)
+                .with_language(swedish)
+                .to_string(),
+            "Första Måndag i månaden"
+        );
+    }
+}
@@ -0,0 +1,1179 @@
+//! Natural language parsing of time expressions.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+
+use crate::{
+    Time,
+    day_part::DayPart,
+    dynamic,
+    exact::{ExactDate, ExactDateTime, ExactTime, Zone},
+    language::Language,
+    macros::fold,
+    month::{
+        April, August, December, February, January, July, June, March, May, Month, November,
+        October, September,
+    },
+    named,
+    offset::Offset,
+    ordinal::{Ordinal, OrdinalWeekday},
+    period::PeriodEdge,
+    quarter::Quarter,
+    relative::{
+        EvenWeek, LastMonth, LastWeek, LastYear, Next15Minutes, NextBusinessDay, NextHour,
+        NextMonth, NextQuarter, NextWeek, NextWeekend, NextYear, OddWeek, Older, Relative,
+        ThisHour, ThisMonth, ThisQuarter, ThisWeek, ThisWeekend, ThisYear, Today, Tomorrow,
+        Yesterday,
+    },
+    traits::FromLanguage,
+    week_number::WeekNumber,
+    weekday::{
+        Friday, LastWeekday, Monday, Saturday, Sunday, Thursday, Tuesday, Wednesday, Weekday,
+        WeekdayTime, last_word,
+    },
+};
+
+/// Words that may precede a time of day, e.g. "at 14:00" or "kl 14".
+const TIME_PREFIXES: &[&str] = &["at", "@", "kl", "kl.", "klockan", "klokka", "klokken", "à"];
+
+/// Phrases collapsing the rest of the expression to its start, e.g. "start of next week".
+const START_PREFIXES: &[&str] = &[
+    "start of",
+    "beginning of",
+    "början av",
+    "början på",
+    "starten av",
+    "begynnelsen av",
+    "starten af",
+    "begyndelsen af",
+    "inicio de la",
+    "inicio del",
+    "inicio de",
+    "principio de la",
+    "principio del",
+    "principio de",
+    "début de la",
+    "début du",
+    "début de",
+];
+
+/// Phrases collapsing the rest of the expression to its end, e.g. "slutet av juli".
+const END_PREFIXES: &[&str] = &[
+    "end of",
+    "slutet av",
+    "slutet på",
+    "slut på",
+    "slutten av",
+    "slutten på",
+    "slutningen af",
+    "fin de la",
+    "fin del",
+    "fin du",
+    "fin de",
+    "final de la",
+    "final del",
+    "final de",
+];
+
+/// Words carrying no meaning in long-form dates, e.g. "den 29 juli", "the 29th of July" or
+/// "29 de julio".
+const LONG_DATE_FILLERS: &[&str] = &["den", "the", "of", "de", "le"];
+
+/// Suffixes of ordinal days, e.g. "29th", "29:e", "29." or "1er".
+const ORDINAL_SUFFIXES: &[&str] = &["st", "nd", "rd", "th", ":e", ":a", ".", "er", "º"];
+
+/// Words that may precede a weekday or month, e.g. "next friday" or "i juli".
+const DAY_PREFIXES: &[&str] = &[
+    "on", "in", "next", "this", "på", "i", "nästa", "denna", "neste", "næste", "denne", "en",
+    "este", "esta", "próximo", "próxima", "ce", "cette",
+];
+
+/// Articles that may precede any keyword, e.g. "la semaine prochaine" or "el próximo lunes".
+const ARTICLES: &[&str] = &["el", "la", "los", "las", "le", "les"];
+
+/// Errors produced when parsing a natural language time expression.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
+pub enum ParseError {
+    #[display("empty time expression")]
+    Empty,
+    #[display("unrecognised time expression '{_0}'")]
+    Unrecognised(String),
+    #[display("'{_0}' cannot be combined with a time of day")]
+    Unsupported(String),
+}
+
+impl core::error::Error for ParseError {}
+
+/// Parses a natural language time expression in any enabled language.
+///
+/// Matching is case-insensitive and ignores whitespace, apostrophes and Spanish and French accents
+/// within keywords, so `"this week"`, `"ThisWeek"`, `"denna vecka"` and `"aujourdhui"` are all
+/// accepted. Supported forms are:
+///
+/// - Relative keywords, weekdays and months, optionally prefixed (`"next friday"`, `"i juli"`)
+/// - Labels of [registered](crate::named::register) named dates (`"payday"`, `"next payday"`)
+/// - Weekdays followed by a time (`"monday 14:00"`, `"fredag kl 9"`)
+/// - Other keywords followed by a time, applying it to the keyword's days (`"tomorrow 09:00"`,
+///   `"this week at 17:30"`)
+/// - Times of day (`"14:30"`, `"14:30:05"`, `"3pm"`, `"at 9:15am"`, `"kl 14"`), optionally
+///   followed by a zone (`"09:00 local"`, `"14:00 utc"`)
+/// - Dates (`"25/12"`, `"25/12/2025"`, `"2025-12-25"`, `"den 29 juli"`, `"July 29th, 2025"`),
+///   optionally followed by a time
+/// - RFC 3339 timestamps
+/// - Any of the above prefixed by "start of" or "end of" (`"end of next week"`, `"slutet av juli"`)
+/// - Edges of the current period (`"end of month"`, `"början av året"`)
+///
+/// Expressions in no enabled language are tried in each [registered](crate::dynamic::register)
+/// dynamic language.
+pub fn parse(input: &str) -> Result<Time, ParseError> {
+    parse_in_languages(input, &Language::all()).or_else(|error| {
+        dynamic::registered()
+            .iter()
+            .find_map(|language| language.parse(input).ok())
+            .ok_or(error)
+    })
+}
+
+/// Parses a natural language time expression, matching names only in the given language.
+///
+/// Accepts the same forms as [`parse`]. Numbers and connecting words like "at" or "next" are
+/// accepted in any enabled language.
+pub fn parse_in(input: &str, language: Language) -> Result<Time, ParseError> {
+    parse_in_languages(input, &[language])
+}
+
+fn parse_in_languages(input: &str, languages: &[Language]) -> Result<Time, ParseError> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(input) {
+        return Ok(Time::DateTime(date_time.with_timezone(&Utc)));
+    }
+
+    let lowercase = input.to_lowercase();
+
+    if let Some(edge) = PeriodEdge::parse_in(&lowercase, languages) {
+        return Ok(Time::Relative(Relative::PeriodEdge(edge)));
+    }
+
+    for (prefixes, boundary) in [
+        (START_PREFIXES, Time::start_of as fn(Time) -> Time),
+        (END_PREFIXES, Time::end_of),
+    ] {
+        if let Some(rest) = prefixes
+            .iter()
+            .find_map(|prefix| lowercase.strip_prefix(prefix)?.strip_prefix(' '))
+        {
+            return parse_in_languages(rest, languages).map(boundary);
+        }
+    }
+
+    let mut tokens: Vec<&str> = lowercase.split_whitespace().collect();
+
+    let time = take_time(&mut tokens).ok_or_else(|| ParseError::Unrecognised(input.to_string()))?;
+
+    if tokens.is_empty() {
+        return match time {
+            Some(time) => Ok(Time::Relative(Relative::Time(time))),
+            None => Err(ParseError::Unrecognised(input.to_string())),
+        };
+    }
+
+    let day = tokens.join(" ");
+
+    if let Some(date) = parse_date(&day, languages) {
+        return Ok(Time::Relative(match time {
+            Some(time) => Relative::DateTime(ExactDateTime::new(date, time)),
+            None => Relative::Date(date),
+        }));
+    }
+
+    if let Some(last_weekday) = parse_last_weekday(&tokens, languages) {
+        return Ok(match time {
+            Some(time) => Time::LastWeekday(last_weekday).at(time),
+            None => Time::LastWeekday(last_weekday),
+        });
+    }
+
+    if let (Some(ordinal_weekday), None) = (parse_ordinal_weekday(&tokens, languages), time) {
+        return Ok(Time::OrdinalWeekday(ordinal_weekday));
+    }
+
+    match (parse_keyword(&tokens, languages), time) {
+        (Some(keyword), None) => Ok(keyword),
+        (Some(Time::Weekday(weekday)), Some(time)) => {
+            Ok(Time::WeekdayTime(WeekdayTime::new(weekday, time)))
+        }
+        (Some(keyword), Some(time)) => Ok(keyword.at(time)),
+        (None, _) => Err(ParseError::Unrecognised(input.to_string())),
+    }
+}
+
+/// Keywords in any enabled language spelled closely to the input, closest first.
+///
+/// Intended for "did you mean" hints when [`parse`] fails. A leading word like "next" or "i" is
+/// ignored, as are case, whitespace and accents.
+pub fn suggest(input: &str) -> Vec<Time> {
+    let lowercase = input.to_lowercase();
+    let tokens: Vec<&str> = lowercase.split_whitespace().collect();
+    let tokens = match tokens[..] {
+        [prefix, ref rest @ ..] if DAY_PREFIXES.contains(&prefix) && !rest.is_empty() => rest,
+        ref tokens => tokens,
+    };
+    let phrase = fold(&tokens.concat());
+
+    let mut candidates: Vec<(usize, Time)> = Language::all()
+        .into_iter()
+        .flat_map(vocabulary)
+        .map(|time| (distance(&phrase, &fold(&time.to_string())), time))
+        .filter(|(distance, _)| *distance <= 2 && *distance < phrase.chars().count())
+        .collect();
+
+    candidates.sort_by_key(|(distance, _)| *distance);
+
+    let mut suggestions: Vec<Time> = Vec::new();
+
+    for (_, time) in candidates {
+        if !suggestions
+            .iter()
+            .any(|x| x.to_string() == time.to_string())
+        {
+            suggestions.push(time);
+        }
+    }
+
+    suggestions
+}
+
+/// The Levenshtein distance between two strings, counted in characters.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, x) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, y) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(x != *y);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Removes a time of day, and any word introducing it, from the tokens.
+///
+/// Returns `None` when a time was introduced but could not be parsed.
+fn take_time(tokens: &mut Vec<&str>) -> Option<Option<ExactTime>> {
+    for index in (0..tokens.len()).rev() {
+        let introduced = index > 0 && TIME_PREFIXES.contains(&tokens[index - 1]);
+
+        let (time, consumed) = match tokens.get(index + 1) {
+            Some(&meridiem @ ("am" | "pm")) => (
+                parse_time(&format!("{}{meridiem}", tokens[index]), introduced),
+                2,
+            ),
+            _ => (parse_time(tokens[index], introduced), 1),
+        };
+
+        let Some(time) = time else {
+            continue;
+        };
+
+        let (time, consumed) = match tokens.get(index + consumed).copied().and_then(parse_zone) {
+            Some(zone) => (time.with_zone(zone), consumed + 1),
+            None => (time, consumed),
+        };
+
+        let start = if introduced { index - 1 } else { index };
+
+        tokens.drain(start..index + consumed);
+
+        return Some(Some(time));
+    }
+
+    match tokens.last() {
+        Some(last) if TIME_PREFIXES.contains(last) => None,
+        _ => Some(None),
+    }
+}
+
+/// Parses `"14:30"`, `"14:30:05"`, `"14:30:05.250"`, `"14.30"` or `"3pm"`, plus bare hours when
+/// `introduced` by a word like "at" or "kl".
+pub(crate) fn parse_time(token: &str, introduced: bool) -> Option<ExactTime> {
+    let (token, meridiem) = match token.strip_suffix("am") {
+        Some(token) => (token, Some(0)),
+        None => match token.strip_suffix("pm") {
+            Some(token) => (token, Some(12)),
+            None => (token, None),
+        },
+    };
+
+    // Offsets like "+02:00" are zones rather than times
+    if !token.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut parts = token.split([':', '.']);
+    let hour: u8 = parts.next()?.parse().ok()?;
+    let minute: Option<u8> = parts.next().map(str::parse).transpose().ok()?;
+    let second: Option<u8> = parts.next().map(str::parse).transpose().ok()?;
+    let millisecond = match parts.next() {
+        Some(digits) => Some(parse_fraction(digits)?),
+        None => None,
+    };
+
+    if parts.next().is_some() || (minute.is_none() && meridiem.is_none() && !introduced) {
+        return None;
+    }
+
+    let hour = match meridiem {
+        Some(offset) if (1..=12).contains(&hour) => hour % 12 + offset,
+        Some(_) => return None,
+        None => hour,
+    };
+
+    let time = (hour <= 23 && minute.unwrap_or(0) <= 59 && second.unwrap_or(0) <= 59)
+        .then(|| ExactTime::new(hour, minute.unwrap_or(0), second))?;
+
+    match millisecond {
+        Some(millisecond) => Some(time.with_millisecond(millisecond)),
+        None => Some(time),
+    }
+}
+
+/// Parses up to three digits following a decimal point as milliseconds, e.g. `"25"` as 250.
+fn parse_fraction(digits: &str) -> Option<u16> {
+    if digits.is_empty() || digits.len() > 3 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let millisecond: u16 = digits.parse().ok()?;
+
+    Some(millisecond * 10u16.pow(3 - digits.len() as u32))
+}
+
+/// Parses a zone following a time of day, e.g. `"utc"`, `"local"` or `"+02:00"`, or with the
+/// `chrono-tz` feature an IANA name like `"Europe/Stockholm"`.
+pub(crate) fn parse_zone(token: &str) -> Option<Zone> {
+    match token.to_lowercase().as_str() {
+        "utc" | "z" | "gmt" => Some(Zone::Utc),
+        "local" | "lokal" | "lokaltid" => Some(Zone::Local),
+        #[cfg(feature = "chrono-tz")]
+        token if token.contains('/') => parse_named_zone(token),
+        token => parse_offset(token),
+    }
+}
+
+/// Parses an IANA zone name ignoring case, e.g. `"europe/stockholm"`.
+#[cfg(feature = "chrono-tz")]
+fn parse_named_zone(token: &str) -> Option<Zone> {
+    chrono_tz::TZ_VARIANTS
+        .into_iter()
+        .find(|tz| tz.name().eq_ignore_ascii_case(token))
+        .map(Zone::Named)
+}
+
+/// Parses a fixed offset from UTC, e.g. `"+02:00"`, `"+0200"` or `"-05"`.
+fn parse_offset(token: &str) -> Option<Zone> {
+    let (sign, digits) = match token.split_at_checked(1)? {
+        ("+", digits) => (1, digits),
+        ("-", digits) => (-1, digits),
+        _ => return None,
+    };
+    let digits: String = digits.chars().filter(|c| *c != ':').collect();
+
+    if !matches!(digits.len(), 2 | 4) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let hours: i32 = digits[..2].parse().ok()?;
+    let minutes: i32 = digits
+        .get(2..)
+        .filter(|x| !x.is_empty())
+        .map_or(Ok(0), str::parse)
+        .ok()?;
+
+    (hours <= 23 && minutes <= 59).then(|| Zone::Offset(sign * (hours * 3600 + minutes * 60)))
+}
+
+/// Parses `"25/12"`, `"25/12/2025"` or `"2025-12-25"`, falling back to long-form dates.
+fn parse_date(day: &str, languages: &[Language]) -> Option<ExactDate> {
+    if let Some(date) = parse_long_date(day, languages) {
+        return Some(date);
+    }
+
+    let numbers = |separator| {
+        day.split(separator)
+            .map(str::parse::<i16>)
+            .collect::<Result<Vec<_>, _>>()
+            .ok()
+    };
+
+    let (year, month, day) = if let Some(parts) = numbers('/') {
+        match parts[..] {
+            [day, month] => (None, month, day),
+            [day, month, year] => (Some(year), month, day),
+            _ => return None,
+        }
+    } else if let Some([year, month, day]) = numbers('-').as_deref() {
+        (Some(*year), *month, *day)
+    } else {
+        return None;
+    };
+
+    ((1..=12).contains(&month) && (1..=31).contains(&day))
+        .then(|| ExactDate::new(year, month as u8, day as u8))
+}
+
+/// Parses long-form dates naming the month in any enabled language, such as `"den 29 juli"`,
+/// `"29 juli 2025"`, `"July 29th"` or `"the 29th of July, 2025"`.
+fn parse_long_date(day: &str, languages: &[Language]) -> Option<ExactDate> {
+    let day = day.replace(',', " ");
+    let tokens: Vec<&str> = day
+        .split_whitespace()
+        .filter(|token| !LONG_DATE_FILLERS.contains(token))
+        .collect();
+
+    let (day, month, year) = match tokens[..] {
+        [first, second, ref year @ ..] if year.len() <= 1 => {
+            match (
+                parse_month(first, languages),
+                parse_month(second, languages),
+            ) {
+                (Some(month), None) => (second, month, year.first()),
+                (None, Some(month)) => (first, month, year.first()),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    let day: u8 = ORDINAL_SUFFIXES
+        .iter()
+        .find_map(|suffix| day.strip_suffix(suffix))
+        .unwrap_or(day)
+        .parse()
+        .ok()?;
+    let year: Option<i16> = year.map(|year| year.parse()).transpose().ok()?;
+
+    (1..=31)
+        .contains(&day)
+        .then(|| ExactDate::new(year, month, day))
+}
+
+/// The number of a month named in any of the languages.
+fn parse_month(token: &str, languages: &[Language]) -> Option<u8> {
+    languages.iter().copied().find_map(|language| {
+        vocabulary(language)
+            .into_iter()
+            .find_map(|time| match time {
+                Time::Month(month) if fold(&month.to_string()) == fold(token) => {
+                    Some(month.number())
+                }
+                _ => None,
+            })
+    })
+}
+
+/// Words introducing the month of an ordinal weekday, e.g. "of" in "first monday of march".
+const ORDINAL_CONNECTORS: &[&str] = &["of", "in", "i", "de", "del", "du"];
+
+/// Phrases standing for every month after an ordinal weekday, e.g. "last friday of the month".
+const EVERY_MONTH: &[&[&str]] = &[
+    &["the", "month"],
+    &["every", "month"],
+    &["månaden"],
+    &["måneden"],
+    &["mes"],
+    &["cada", "mes"],
+    &["mois"],
+    &["chaque", "mois"],
+];
+
+/// Matches an ordinal, a weekday and a month or the whole month in any of the languages, e.g.
+/// "last friday of the month" or "första måndagen i mars".
+///
+/// Without a month, e.g. "last friday", the phrase is left to [`parse_last_weekday`].
+pub(crate) fn parse_ordinal_weekday(
+    tokens: &[&str],
+    languages: &[Language],
+) -> Option<OrdinalWeekday> {
+    let [ordinal, weekday, rest @ ..] = tokens else {
+        return None;
+    };
+
+    let ordinal = languages.iter().find_map(|language| {
+        Ordinal::ALL
+            .into_iter()
+            .find(|candidate| fold(candidate.name(*language)) == fold(ordinal))
+    })?;
+
+    // Swedish ordinals take the definite form, e.g. "första måndagen"
+    let weekday = [*weekday, weekday.strip_suffix("en").unwrap_or(weekday)]
+        .into_iter()
+        .find_map(|token| match parse_keyword(&[token], languages)? {
+            Time::Weekday(weekday) => Some(weekday),
+            _ => None,
+        })?;
+
+    let month = match rest {
+        [connector, rest @ ..] if ORDINAL_CONNECTORS.contains(connector) => {
+            if EVERY_MONTH.contains(&rest) {
+                None
+            } else {
+                match parse_keyword(rest, languages)? {
+                    Time::Month(month) => Some(month),
+                    _ => return None,
+                }
+            }
+        }
+        _ => return None,
+    };
+
+    Some(OrdinalWeekday::new(ordinal, weekday, month))
+}
+
+/// Matches a weekday with the word for "last" in any of the languages, e.g. "last friday",
+/// "förra fredagen" or "el viernes pasado".
+pub(crate) fn parse_last_weekday(tokens: &[&str], languages: &[Language]) -> Option<LastWeekday> {
+    let tokens = match tokens {
+        [article, rest @ ..] if ARTICLES.contains(article) => rest,
+        tokens => tokens,
+    };
+
+    languages.iter().find_map(|language| {
+        let weekday = match (tokens, last_word(*language)) {
+            ([last, weekday], (word, false)) | ([weekday, last], (word, true))
+                if fold(last) == fold(word) =>
+            {
+                *weekday
+            }
+            _ => return None,
+        };
+
+        // Swedish takes the definite form, e.g. "förra fredagen"
+        [weekday, weekday.strip_suffix("en").unwrap_or(weekday)]
+            .into_iter()
+            .find_map(
+                |token| match parse_keyword(&[token], core::slice::from_ref(language))? {
+                    Time::Weekday(weekday) => Some(LastWeekday::new(weekday)),
+                    _ => None,
+                },
+            )
+    })
+}
+
+/// Matches relative keywords, weekdays, months and named dates in any of the languages.
+fn parse_keyword(tokens: &[&str], languages: &[Language]) -> Option<Time> {
+    let matching = |tokens: &[&str]| {
+        let phrase = tokens.concat();
+        let folded = fold(&phrase);
+
+        languages
+            .iter()
+            .copied()
+            .find_map(|language| {
+                vocabulary(language)
+                    .into_iter()
+                    .find(|candidate| fold(&candidate.to_string()) == folded)
+            })
+            .or_else(|| named::lookup_in(&phrase, languages).map(Time::Named))
+            .or_else(|| {
+                Offset::parse_in(&phrase, languages).map(|x| Time::Relative(Relative::In(x)))
+            })
+            .or_else(|| {
+                DayPart::parse_in(&phrase, languages).map(|x| Time::Relative(Relative::DayPart(x)))
+            })
+            .or_else(|| WeekNumber::parse(&phrase).map(|x| Time::Relative(Relative::Week(x))))
+            .or_else(|| Quarter::parse(&phrase).map(|x| Time::Relative(Relative::Quarter(x))))
+    };
+
+    matching(tokens)
+        .or_else(|| match tokens {
+            [prefix, rest @ ..] if DAY_PREFIXES.contains(prefix) => matching(rest)
+                .filter(|time| matches!(time, Time::Weekday(_) | Time::Month(_) | Time::Named(_))),
+            _ => None,
+        })
+        .or_else(|| match tokens {
+            [article, rest @ ..] if ARTICLES.contains(article) => parse_keyword(rest, languages),
+            _ => None,
+        })
+}
+
+/// Every keyword representable in the given language.
+pub(crate) fn vocabulary(language: Language) -> [Time; 42] {
+    [
+        Time::Relative(Relative::Today(Today::from_language(language))),
+        Time::Relative(Relative::Tomorrow(Tomorrow::from_language(language))),
+        Time::Relative(Relative::ThisWeek(ThisWeek::from_language(language))),
+        Time::Relative(Relative::NextWeek(NextWeek::from_language(language))),
+        Time::Relative(Relative::ThisMonth(ThisMonth::from_language(language))),
+        Time::Relative(Relative::Yesterday(Yesterday::from_language(language))),
+        Time::Relative(Relative::Older(Older::from_language(language))),
+        Time::Relative(Relative::EvenWeek(EvenWeek::from_language(language))),
+        Time::Relative(Relative::OddWeek(OddWeek::from_language(language))),
+        Time::Relative(Relative::NextMonth(NextMonth::from_language(language))),
+        Time::Relative(Relative::ThisYear(ThisYear::from_language(language))),
+        Time::Relative(Relative::NextYear(NextYear::from_language(language))),
+        Time::Relative(Relative::ThisWeekend(ThisWeekend::from_language(language))),
+        Time::Relative(Relative::NextWeekend(NextWeekend::from_language(language))),
+        Time::Relative(Relative::ThisQuarter(ThisQuarter::from_language(language))),
+        Time::Relative(Relative::NextQuarter(NextQuarter::from_language(language))),
+        Time::Relative(Relative::NextBusinessDay(NextBusinessDay::from_language(
+            language,
+        ))),
+        Time::Relative(Relative::ThisHour(ThisHour::from_language(language))),
+        Time::Relative(Relative::NextHour(NextHour::from_language(language))),
+        Time::Relative(Relative::Next15Minutes(Next15Minutes::from_language(
+            language,
+        ))),
+        Time::Relative(Relative::LastWeek(LastWeek::from_language(language))),
+        Time::Relative(Relative::LastMonth(LastMonth::from_language(language))),
+        Time::Relative(Relative::LastYear(LastYear::from_language(language))),
+        Time::Weekday(Weekday::Monday(Monday::from_language(language))),
+        Time::Weekday(Weekday::Tuesday(Tuesday::from_language(language))),
+        Time::Weekday(Weekday::Wednesday(Wednesday::from_language(language))),
+        Time::Weekday(Weekday::Thursday(Thursday::from_language(language))),
+        Time::Weekday(Weekday::Friday(Friday::from_language(language))),
+        Time::Weekday(Weekday::Saturday(Saturday::from_language(language))),
+        Time::Weekday(Weekday::Sunday(Sunday::from_language(language))),
+        Time::Month(Month::January(January::from_language(language))),
+        Time::Month(Month::February(February::from_language(language))),
+        Time::Month(Month::March(March::from_language(language))),
+        Time::Month(Month::April(April::from_language(language))),
+        Time::Month(Month::May(May::from_language(language))),
+        Time::Month(Month::June(June::from_language(language))),
+        Time::Month(Month::July(July::from_language(language))),
+        Time::Month(Month::August(August::from_language(language))),
+        Time::Month(Month::September(September::from_language(language))),
+        Time::Month(Month::October(October::from_language(language))),
+        Time::Month(Month::November(November::from_language(language))),
+        Time::Month(Month::December(December::from_language(language))),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "swedish")]
+    use crate::Boundary;
+
+    #[test]
+    fn parses_keywords_case_insensitively() {
+        assert_eq!(parse("today"), Ok(Time::Relative(Relative::today())));
+        assert_eq!(
+            parse("This Week"),
+            Ok(Time::Relative(Relative::this_week()))
+        );
+        assert_eq!(parse("NEXTWEEK"), Ok(Time::Relative(Relative::next_week())));
+        assert_eq!(parse("next friday"), Ok(Time::Weekday(Weekday::friday())));
+        assert_eq!(parse("in March"), Ok(Time::Month(Month::march())));
+    }
+
+    #[test]
+    fn parses_times() {
+        let expected = Ok(Time::Relative(Relative::Time(ExactTime::new(15, 0, None))));
+
+        assert_eq!(parse("15:00"), expected);
+        assert_eq!(parse("3pm"), expected);
+        assert_eq!(parse("at 3 pm"), expected);
+        assert_eq!(
+            parse("12:30:15"),
+            Ok(Time::Relative(Relative::Time(ExactTime::new(
+                12,
+                30,
+                Some(15)
+            ))))
+        );
+        assert_eq!(
+            parse("12am"),
+            Ok(Time::Relative(Relative::Time(ExactTime::new(0, 0, None))))
+        );
+        assert_eq!(
+            parse("14:30:45.25"),
+            Ok(Time::Relative(Relative::Time(
+                ExactTime::new(14, 30, Some(45)).with_millisecond(250)
+            )))
+        );
+        assert!(parse("25:00").is_err());
+    }
+
+    #[test]
+    fn parses_dates() {
+        assert_eq!(
+            parse("25/12"),
+            Ok(Time::Relative(Relative::Date(ExactDate::new(None, 12, 25))))
+        );
+        assert_eq!(
+            parse("2025-12-25 18:30"),
+            Ok(Time::Relative(Relative::DateTime(ExactDateTime::new(
+                ExactDate::new(Some(2025), 12, 25),
+                ExactTime::new(18, 30, None)
+            ))))
+        );
+        assert!(parse("13/13").is_err());
+    }
+
+    #[test]
+    fn parses_rfc3339() {
+        let date_time = DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc();
+
+        assert_eq!(parse("2025-07-29T10:30:05Z"), Ok(Time::DateTime(date_time)));
+    }
+
+    #[test]
+    fn parses_zones() {
+        assert_eq!(
+            parse("09:00 local"),
+            Ok(Time::Relative(Relative::Time(
+                ExactTime::new(9, 0, None).with_zone(Zone::Local)
+            )))
+        );
+        assert_eq!(
+            parse("kl 14 UTC"),
+            Ok(Time::Relative(Relative::Time(
+                ExactTime::new(14, 0, None).with_zone(Zone::Utc)
+            )))
+        );
+        assert_eq!(
+            parse("10:30 +02:00"),
+            Ok(Time::Relative(Relative::Time(
+                ExactTime::new(10, 30, None).with_zone(Zone::Offset(7200))
+            )))
+        );
+        assert_eq!(
+            parse("09:00 -0530"),
+            Ok(Time::Relative(Relative::Time(
+                ExactTime::new(9, 0, None).with_zone(Zone::Offset(-19800))
+            )))
+        );
+    }
+
+    #[test]
+    fn parses_week_parity() {
+        assert_eq!(
+            parse("even week"),
+            Ok(Time::Relative(Relative::even_week()))
+        );
+        #[cfg(feature = "swedish")]
+        assert!(matches!(
+            parse("udda vecka"),
+            Ok(Time::Relative(Relative::OddWeek(_)))
+        ));
+    }
+
+    #[cfg(all(feature = "swedish", feature = "std"))]
+    #[test]
+    fn parses_named_dates() {
+        #[cfg(feature = "serde")]
+        use crate::traits::WithLanguage;
+        use crate::{
+            language::Swedish,
+            named::{NamedDate, Recurrence, Unregister, register},
+        };
+
+        let _registration = Unregister("Standup day");
+        register(
+            NamedDate::new("Standup day", Recurrence::Weekday(chrono::Weekday::Tue))
+                .with_label(Language::Swedish(Swedish::default()), "Mötesdag"),
+        );
+
+        let Ok(Time::Named(named)) = parse("next mötesdag") else {
+            panic!("expected a named date");
+        };
+        assert_eq!(named.to_string(), "Mötesdag");
+        assert_eq!(named.date().name(), "Standup day");
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::from_str::<Time>(r#""Standup day""#).unwrap(),
+            Time::Named(named.with_language(Language::default()))
+        );
+    }
+
+    #[test]
+    fn parses_long_form_dates() {
+        let date = |year| Ok(Time::Relative(Relative::Date(ExactDate::new(year, 7, 29))));
+
+        #[cfg(feature = "swedish")]
+        assert_eq!(parse("den 29 juli"), date(None));
+        #[cfg(feature = "swedish")]
+        assert_eq!(parse("29 juli 2025"), date(Some(2025)));
+        assert_eq!(parse("July 29th"), date(None));
+        assert_eq!(parse("the 29th of July, 2025"), date(Some(2025)));
+        assert_eq!(parse("July 29, 2025"), date(Some(2025)));
+        #[cfg(feature = "swedish")]
+        assert_eq!(
+            parse("den 29:e juli kl 14"),
+            Ok(Time::Relative(Relative::DateTime(ExactDateTime::new(
+                ExactDate::new(None, 7, 29),
+                ExactTime::new(14, 0, None)
+            ))))
+        );
+        assert!(parse("32 july").is_err());
+        assert!(parse("juli juli").is_err());
+    }
+
+    #[test]
+    fn parses_boundaries() {
+        assert_eq!(
+            parse("end of next week"),
+            Ok(Time::Relative(Relative::next_week()).end_of())
+        );
+        assert_eq!(
+            parse("Start of July"),
+            Ok(Time::Month(Month::july()).start_of())
+        );
+        #[cfg(feature = "swedish")]
+        assert!(matches!(
+            parse("början av juli"),
+            Ok(Time::Boundary(Boundary::StartOf(_)))
+        ));
+    }
+
+    #[test]
+    fn parses_weekdays_with_times() {
+        assert_eq!(
+            parse("next tuesday at 3pm"),
+            Ok(Time::WeekdayTime(WeekdayTime::new(
+                Weekday::tuesday(),
+                ExactTime::new(15, 0, None)
+            )))
+        );
+    }
+
+    #[test]
+    fn parses_keywords_with_times() {
+        assert_eq!(
+            parse("this week at 3pm"),
+            Ok(Time::Relative(Relative::this_week()).at(ExactTime::new(15, 0, None)))
+        );
+        assert_eq!(
+            parse("tomorrow 09:00"),
+            Ok(Time::Relative(Relative::tomorrow()).at(ExactTime::new(9, 0, None)))
+        );
+        assert_eq!(parse(""), Err(ParseError::Empty));
+        assert!(matches!(parse("someday"), Err(ParseError::Unrecognised(_))));
+    }
+
+    #[test]
+    fn suggests_close_keywords() {
+        assert_eq!(suggest("fridya"), vec![Time::Weekday(Weekday::friday())]);
+        assert_eq!(
+            suggest("next tuesdya"),
+            vec![Time::Weekday(Weekday::tuesday())]
+        );
+        assert_eq!(suggest("tomorow")[0], Time::Relative(Relative::tomorrow()));
+        assert!(suggest("someday").is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn parses_swedish() {
+        use crate::{language::Swedish, traits::WithLanguage};
+
+        let swedish = Language::Swedish(Swedish::default());
+
+        assert_eq!(
+            parse("i morgon"),
+            Ok(Time::Relative(Relative::tomorrow().with_language(swedish)))
+        );
+        assert_eq!(
+            parse("denna vecka"),
+            Ok(Time::Relative(Relative::this_week().with_language(swedish)))
+        );
+        assert_eq!(
+            parse("nästa måndag"),
+            Ok(Time::Weekday(Weekday::monday().with_language(swedish)))
+        );
+        assert_eq!(
+            parse("kl 14"),
+            Ok(Time::Relative(Relative::Time(ExactTime::new(14, 0, None))))
+        );
+        assert_eq!(
+            parse("klockan 14:15"),
+            Ok(Time::Relative(Relative::Time(ExactTime::new(14, 15, None))))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "norwegian")]
+    fn parses_norwegian() {
+        use crate::{language::Norwegian, offset::Unit, traits::WithLanguage};
+
+        let norwegian = Language::Norwegian(Norwegian::default());
+
+        assert_eq!(
+            parse("i morgen"),
+            Ok(Time::Relative(
+                Relative::tomorrow().with_language(norwegian)
+            ))
+        );
+        assert_eq!(
+            parse("neste uke"),
+            Ok(Time::Relative(
+                Relative::next_week().with_language(norwegian)
+            ))
+        );
+        assert_eq!(
+            parse("tirsdag klokken 14"),
+            Ok(Time::WeekdayTime(WeekdayTime::new(
+                Weekday::tuesday().with_language(norwegian),
+                ExactTime::new(14, 0, None)
+            )))
+        );
+        assert_eq!(
+            parse("om 3 dager"),
+            Ok(Time::Relative(
+                Relative::offset(3, Unit::Days).with_language(norwegian)
+            ))
+        );
+        assert_eq!(
+            parse("29. desember 2025"),
+            Ok(Time::Relative(Relative::Date(ExactDate::new(
+                Some(2025),
+                12,
+                29
+            ))))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "danish")]
+    fn parses_danish() {
+        use crate::{
+            language::Danish,
+            period::{Edge, Period},
+            traits::WithLanguage,
+        };
+
+        let danish = Language::Danish(Danish::default());
+
+        assert_eq!(
+            parse_in("i dag", danish),
+            Ok(Time::Relative(Relative::today().with_language(danish)))
+        );
+        assert_eq!(
+            parse("næste uge"),
+            Ok(Time::Relative(Relative::next_week().with_language(danish)))
+        );
+        assert_eq!(
+            parse("i marts"),
+            Ok(Time::Month(Month::march().with_language(danish)))
+        );
+        assert_eq!(
+            parse("slutningen af måneden"),
+            Ok(Time::Relative(Relative::PeriodEdge(
+                PeriodEdge::new(Edge::End, Period::Month).with_language(danish)
+            )))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "spanish")]
+    fn parses_spanish() {
+        use crate::{
+            language::Spanish,
+            ordinal::Ordinal,
+            period::{Edge, Period},
+            traits::WithLanguage,
+        };
+
+        let spanish = Language::Spanish(Spanish::default());
+
+        assert_eq!(
+            parse("hoy"),
+            Ok(Time::Relative(Relative::today().with_language(spanish)))
+        );
+        // Accents may be left out
+        assert_eq!(
+            parse("manana"),
+            Ok(Time::Relative(Relative::tomorrow().with_language(spanish)))
+        );
+        assert_eq!(
+            parse("el próximo miercoles"),
+            Ok(Time::Weekday(Weekday::wednesday().with_language(spanish)))
+        );
+        assert_eq!(
+            parse("la próxima semana"),
+            Ok(Time::Relative(Relative::next_week().with_language(spanish)))
+        );
+        assert_eq!(
+            parse("fin del mes"),
+            Ok(Time::Relative(Relative::PeriodEdge(
+                PeriodEdge::new(Edge::End, Period::Month).with_language(spanish)
+            )))
+        );
+        assert_eq!(
+            parse("29 de julio de 2025"),
+            Ok(Time::Relative(Relative::Date(ExactDate::new(
+                Some(2025),
+                7,
+                29
+            ))))
+        );
+        assert_eq!(
+            parse("último viernes del mes"),
+            Ok(Time::OrdinalWeekday(OrdinalWeekday::new(
+                Ordinal::Last,
+                Weekday::friday().with_language(spanish),
+                None
+            )))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "french")]
+    fn parses_french() {
+        use crate::{language::French, traits::WithLanguage};
+
+        let french = Language::French(French::default());
+        let today = Ok(Time::Relative(Relative::today().with_language(french)));
+
+        assert_eq!(parse("aujourd'hui"), today);
+        assert_eq!(parse("Aujourd’hui"), today);
+        assert_eq!(parse("aujourdhui"), today);
+        assert_eq!(
+            parse("demain"),
+            Ok(Time::Relative(Relative::tomorrow().with_language(french)))
+        );
+        assert_eq!(
+            parse("lundi"),
+            Ok(Time::Weekday(Weekday::monday().with_language(french)))
+        );
+        assert_eq!(
+            parse("fevrier"),
+            Ok(Time::Month(Month::february().with_language(french)))
+        );
+        assert_eq!(
+            parse("la semaine prochaine"),
+            Ok(Time::Relative(Relative::next_week().with_language(french)))
+        );
+        assert_eq!(
+            parse("le 1er août"),
+            Ok(Time::Relative(Relative::Date(ExactDate::new(None, 8, 1))))
+        );
+    }
+
+    #[test]
+    fn parses_offsets() {
+        use crate::offset::Unit;
+
+        assert_eq!(
+            parse("in 3 days"),
+            Ok(Time::Relative(Relative::offset(3, Unit::Days)))
+        );
+        assert_eq!(
+            parse("In2Weeks"),
+            Ok(Time::Relative(Relative::offset(2, Unit::Weeks)))
+        );
+        assert!(parse("in days").is_err());
+    }
+
+    #[test]
+    fn parses_ordinal_weekdays() {
+        use crate::ordinal::{Ordinal, OrdinalWeekday};
+
+        assert_eq!(
+            parse("first monday of march"),
+            Ok(Time::OrdinalWeekday(OrdinalWeekday::new(
+                Ordinal::First,
+                Weekday::monday(),
+                Some(Month::march())
+            )))
+        );
+        assert_eq!(
+            parse("Last Friday of the month"),
+            Ok(Time::OrdinalWeekday(OrdinalWeekday::new(
+                Ordinal::Last,
+                Weekday::friday(),
+                None
+            )))
+        );
+        assert!(parse("first monday of tomorrow").is_err());
+    }
+
+    #[test]
+    fn parses_last_weekdays() {
+        use chrono::DateTime;
+
+        use crate::weekday::LastWeekday;
+
+        let last_friday = Time::LastWeekday(LastWeekday::new(Weekday::friday()));
+
+        assert_eq!(parse("last friday"), Ok(last_friday.clone()));
+        assert_eq!(parse("Last Friday"), Ok(last_friday.clone()));
+        assert_eq!(
+            parse("last friday 15:00"),
+            Ok(last_friday.clone().at(ExactTime::new(15, 0, None)))
+        );
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::from_str::<Time>(r#""Last Friday""#).unwrap(),
+            last_friday
+        );
+
+        // Tuesday July 29th, 2025
+        let relative_to = DateTime::parse_from_rfc3339("2025-07-29T10:30:00Z")
+            .unwrap()
+            .to_utc();
+        let day = |s| DateTime::parse_from_rfc3339(s).unwrap().to_utc();
+
+        assert_eq!(
+            last_friday.clone().to_chrono_min(relative_to),
+            day("2025-07-25T00:00:00Z")
+        );
+        assert_eq!(
+            last_friday.to_chrono_max(relative_to),
+            day("2025-07-26T00:00:00Z")
+        );
+        assert_eq!(
+            parse("last tuesday").unwrap().to_chrono_min(relative_to),
+            day("2025-07-22T00:00:00Z")
+        );
+        #[cfg(feature = "swedish")]
+        assert_eq!(
+            parse("förra fredagen").unwrap().to_chrono_min(relative_to),
+            day("2025-07-25T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn parses_day_parts() {
+        use crate::day_part::{Day, Part};
+
+        assert_eq!(
+            parse("tomorrow morning"),
+            Ok(Time::Relative(Relative::day_part(
+                Part::Morning,
+                Some(Day::Tomorrow)
+            )))
+        );
+        assert_eq!(parse("Tonight"), Ok(Time::Relative(Relative::tonight())));
+        assert_eq!(
+            parse("evening"),
+            Ok(Time::Relative(Relative::day_part(Part::Evening, None)))
+        );
+        assert_eq!(
+            parse("tomorrow evening at 19:30"),
+            Ok(
+                Time::Relative(Relative::day_part(Part::Evening, Some(Day::Tomorrow)))
+                    .at(ExactTime::new(19, 30, None))
+            )
+        );
+    }
+}
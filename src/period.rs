@@ -0,0 +1,333 @@
+//! Edges of the current day, week, month, quarter or year, such as "end of month".
+
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+#[cfg(feature = "schemars")]
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{
+    language::Language, macros::fold, options::Options, parse::ParseError, relative::Relative,
+    traits::WithLanguage,
+};
+
+/// A calendar period containing the reference time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Period {
+    pub(crate) const ALL: [Period; 5] = [
+        Period::Day,
+        Period::Week,
+        Period::Month,
+        Period::Quarter,
+        Period::Year,
+    ];
+
+    /// The keyword whose window is the current period.
+    fn current(self) -> Relative {
+        match self {
+            Period::Day => Relative::today(),
+            Period::Week => Relative::this_week(),
+            Period::Month => Relative::this_month(),
+            Period::Quarter => Relative::this_quarter(),
+            Period::Year => Relative::this_year(),
+        }
+    }
+}
+
+/// Which end of a [`Period`] a [`PeriodEdge`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    Start,
+    End,
+}
+
+/// The name of an edge of a period in the given language, e.g. "EndOfMonth" or
+/// "SlutetAvMånaden".
+fn name(edge: Edge, period: Period, language: Language) -> (&'static str, &'static str) {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => (
+            match edge {
+                Edge::Start => "BörjanAv",
+                Edge::End => "SlutetAv",
+            },
+            match period {
+                Period::Day => "Dagen",
+                Period::Week => "Veckan",
+                Period::Month => "Månaden",
+                Period::Quarter => "Kvartalet",
+                Period::Year => "Året",
+            },
+        ),
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => (
+            match edge {
+                Edge::Start => "StartenAv",
+                Edge::End => "SluttenAv",
+            },
+            match period {
+                Period::Day => "Dagen",
+                Period::Week => "Uken",
+                Period::Month => "Måneden",
+                Period::Quarter => "Kvartalet",
+                Period::Year => "Året",
+            },
+        ),
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => (
+            match edge {
+                Edge::Start => "StartenAf",
+                Edge::End => "SlutningenAf",
+            },
+            match period {
+                Period::Day => "Dagen",
+                Period::Week => "Ugen",
+                Period::Month => "Måneden",
+                Period::Quarter => "Kvartalet",
+                Period::Year => "Året",
+            },
+        ),
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => (
+            match edge {
+                Edge::Start => "Inicio",
+                Edge::End => "Fin",
+            },
+            match period {
+                Period::Day => "DelDía",
+                Period::Week => "DeLaSemana",
+                Period::Month => "DelMes",
+                Period::Quarter => "DelTrimestre",
+                Period::Year => "DelAño",
+            },
+        ),
+        #[cfg(feature = "french")]
+        Language::French(_) => (
+            match edge {
+                Edge::Start => "Début",
+                Edge::End => "Fin",
+            },
+            match period {
+                Period::Day => "DeLaJournée",
+                Period::Week => "DeLaSemaine",
+                Period::Month => "DuMois",
+                Period::Quarter => "DuTrimestre",
+                Period::Year => "DeLAnnée",
+            },
+        ),
+        Language::English(_) => (
+            match edge {
+                Edge::Start => "StartOf",
+                Edge::End => "EndOf",
+            },
+            match period {
+                Period::Day => "Day",
+                Period::Week => "Week",
+                Period::Month => "Month",
+                Period::Quarter => "Quarter",
+                Period::Year => "Year",
+            },
+        ),
+    }
+}
+
+/// The start or end of the current period as a single instant, e.g. `"EndOfMonth"` or
+/// `"StartOfYear"`.
+///
+/// Both the earliest and latest timestamps are the edge, the same instant as wrapping the
+/// period's keyword in a [`Boundary`](crate::Boundary), so `"EndOfMonth"` is `"end of this
+/// month"`. Weeks start on the day set by [`Options`]. Serialises as a single word in the
+/// language it was written in, and parses ignoring case, whitespace and accents, so
+/// `"end of month"` is accepted too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PeriodEdge {
+    edge: Edge,
+    period: Period,
+    language: Language,
+}
+
+impl PeriodEdge {
+    pub fn new(edge: Edge, period: Period) -> Self {
+        Self {
+            edge,
+            period,
+            language: Language::default(),
+        }
+    }
+
+    pub fn edge(&self) -> Edge {
+        self.edge
+    }
+
+    pub fn period(&self) -> Period {
+        self.period
+    }
+
+    /// Both edges of every period, in the given language.
+    pub(crate) fn all(language: Language) -> impl Iterator<Item = Self> {
+        [Edge::Start, Edge::End].into_iter().flat_map(move |edge| {
+            Period::ALL.map(|period| Self {
+                edge,
+                period,
+                language,
+            })
+        })
+    }
+
+    /// Parses an edge written in any of the languages, e.g. `"endofmonth"`.
+    pub(crate) fn parse_in(input: &str, languages: &[Language]) -> Option<Self> {
+        let input = fold(input);
+
+        languages.iter().copied().find_map(|language| {
+            Self::all(language).find(|candidate| fold(&candidate.to_string()) == input)
+        })
+    }
+
+    /// Converts to the edge, relative to the given time and following the given conventions.
+    pub fn to_chrono_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
+        match self.edge {
+            Edge::Start => self
+                .period
+                .current()
+                .to_chrono_min_with(relative_to, options),
+            Edge::End => self
+                .period
+                .current()
+                .to_chrono_max_with(relative_to, options),
+        }
+    }
+}
+
+impl WithLanguage for PeriodEdge {
+    fn with_language(&self, language: Language) -> Self {
+        Self { language, ..*self }
+    }
+}
+
+impl fmt::Display for PeriodEdge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (edge, period) = name(self.edge, self.period, self.language);
+
+        write!(f, "{edge}{period}")
+    }
+}
+
+impl FromStr for PeriodEdge {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_in(s, &Language::all()).ok_or_else(|| ParseError::Unrecognised(s.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for PeriodEdge {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for PeriodEdge {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for PeriodEdge {
+    fn schema_name() -> Cow<'static, str> {
+        "PeriodEdge".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let names: Vec<String> = Language::all()
+            .into_iter()
+            .flat_map(Self::all)
+            .map(|edge| edge.to_string())
+            .collect();
+
+        json_schema!({
+            "description": "The start or end of the current period, e.g. \"EndOfMonth\" or \"StartOfYear\".",
+            "type": "string",
+            "enum": names,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn resolves_edges() {
+        let relative_to = at("2025-07-29T10:30:05Z");
+        let edge = |edge, period| {
+            PeriodEdge::new(edge, period).to_chrono_with(relative_to, Options::default())
+        };
+
+        assert_eq!(edge(Edge::Start, Period::Day), at("2025-07-29T00:00:00Z"));
+        assert_eq!(edge(Edge::End, Period::Week), at("2025-08-04T00:00:00Z"));
+        assert_eq!(edge(Edge::End, Period::Month), at("2025-08-01T00:00:00Z"));
+        assert_eq!(
+            edge(Edge::Start, Period::Quarter),
+            at("2025-07-01T00:00:00Z")
+        );
+        assert_eq!(edge(Edge::End, Period::Year), at("2026-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn formats_and_parses() {
+        let end_of_month = PeriodEdge::new(Edge::End, Period::Month);
+
+        assert_eq!(end_of_month.to_string(), "EndOfMonth");
+        assert_eq!("end of month".parse(), Ok(end_of_month));
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::to_string(&end_of_month).unwrap(),
+            r#""EndOfMonth""#
+        );
+        assert!("end of fortnight".parse::<PeriodEdge>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn formats_in_swedish() {
+        use crate::language::Swedish;
+
+        let start_of_year: PeriodEdge = "början av året".parse().unwrap();
+
+        assert_eq!(start_of_year.period(), Period::Year);
+        assert_eq!(start_of_year.to_string(), "BörjanAvÅret");
+        assert_eq!(
+            start_of_year.with_language(Language::default()).to_string(),
+            "StartOfYear"
+        );
+        assert_eq!(
+            PeriodEdge::new(Edge::End, Period::Quarter)
+                .with_language(Language::Swedish(Swedish::default()))
+                .to_string(),
+            "SlutetAvKvartalet"
+        );
+    }
+}
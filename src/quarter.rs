@@ -0,0 +1,263 @@
+//! Quarters of the year, such as "Q3" or "kvartal 3".
+
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::{fmt, str::FromStr};
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::parse::ParseError;
+
+/// Words introducing a quarter, e.g. "quarter 3", "kvartal 3" or "Q3", longest first.
+const PREFIXES: &[&str] = &["kvartal", "quarter", "q"];
+
+/// A quarter of the year, from midnight on the first of January, April, July or October to the
+/// first of the month three months later, optionally in a given year.
+///
+/// Without a year, resolves to the current quarter when its number matches and the next year's
+/// otherwise. Serialises as `"Q3"` or `"2025-Q3"`, deserialises from
+/// `{"quarter": 3, "year": 2025}` too, and parses from e.g. `"quarter 3"` or `"kvartal 3"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Quarter {
+    quarter: u8,
+    year: Option<i32>,
+}
+
+impl Quarter {
+    /// Creates a quarter, clamping the number to 1-4.
+    pub fn new(quarter: u8, year: Option<i32>) -> Self {
+        Self {
+            quarter: quarter.clamp(1, 4),
+            year,
+        }
+    }
+
+    pub fn quarter(&self) -> u8 {
+        self.quarter
+    }
+
+    pub fn year(&self) -> Option<i32> {
+        self.year
+    }
+
+    /// The quarter containing the given date.
+    pub fn from_chrono(date_time: DateTime<Utc>) -> Self {
+        Self::new(date_time.month0() as u8 / 3 + 1, Some(date_time.year()))
+    }
+
+    /// Parses a quarter written as `"2025-Q3"`, or a number introduced by a word like `"Q"`.
+    pub(crate) fn parse(input: &str) -> Option<Self> {
+        let input: String = input.split_whitespace().collect::<String>().to_lowercase();
+
+        let (year, rest) = match input.split_once("-q") {
+            Some((year, quarter)) if year.len() == 4 => (Some(year.parse().ok()?), quarter),
+            Some(_) => return None,
+            None => (None, input.as_str()),
+        };
+
+        let digits = match year {
+            Some(_) => rest,
+            None => PREFIXES
+                .iter()
+                .find_map(|prefix| rest.strip_prefix(prefix))?,
+        };
+
+        let quarter = match digits {
+            "1" => 1,
+            "2" => 2,
+            "3" => 3,
+            "4" => 4,
+            _ => return None,
+        };
+
+        Some(Self { quarter, year })
+    }
+
+    /// The first day of the quarter, relative to the given date.
+    fn start(self, today: NaiveDate) -> NaiveDate {
+        let first = |year| NaiveDate::from_ymd_opt(year, u32::from(self.quarter) * 3 - 2, 1);
+
+        match self.year {
+            Some(year) => first(year).unwrap(),
+            None => {
+                let this_year = first(today.year()).unwrap();
+
+                if this_year + Months::new(3) > today {
+                    this_year
+                } else {
+                    first(today.year() + 1).unwrap()
+                }
+            }
+        }
+    }
+
+    /// Converts to midnight starting the quarter, relative to the given time.
+    pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.start(relative_to.date_naive())
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+    }
+
+    /// Converts to midnight starting the quarter after, relative to the given time.
+    pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_min(relative_to) + Months::new(3)
+    }
+}
+
+impl fmt::Display for Quarter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.year {
+            Some(year) => write!(f, "{year}-Q{}", self.quarter),
+            None => write!(f, "Q{}", self.quarter),
+        }
+    }
+}
+
+impl FromStr for Quarter {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| ParseError::Unrecognised(s.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Quarter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// The accepted shapes of a serialised [`Quarter`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr<'a> {
+    #[serde(borrow)]
+    Text(Cow<'a, str>),
+    Object {
+        quarter: u8,
+        #[serde(default)]
+        year: Option<i32>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Quarter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Repr::deserialize(deserializer)? {
+            Repr::Text(s) => s.parse().map_err(de::Error::custom),
+            Repr::Object { quarter, year } if (1..=4).contains(&quarter) => {
+                Ok(Self { quarter, year })
+            }
+            Repr::Object { quarter, .. } => Err(de::Error::custom(format_args!(
+                "quarter {quarter} is out of range 1-4"
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Quarter {
+    fn schema_name() -> Cow<'static, str> {
+        "Quarter".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A quarter of the year, optionally in a given year, e.g. \"Q3\" or \"2025-Q3\".",
+            "anyOf": [
+                {
+                    "type": "string",
+                    "pattern": "^([0-9]{4}-)?Q[1-4]$",
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "quarter": { "type": "integer", "minimum": 1, "maximum": 4 },
+                        "year": { "type": "integer" },
+                    },
+                    "required": ["quarter"],
+                },
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn resolves_quarters() {
+        let relative_to = at("2025-07-29T10:30:05Z");
+        let window = |quarter: Quarter| {
+            (
+                quarter.to_chrono_min(relative_to),
+                quarter.to_chrono_max(relative_to),
+            )
+        };
+
+        assert_eq!(
+            window(Quarter::new(3, None)),
+            (at("2025-07-01T00:00:00Z"), at("2025-10-01T00:00:00Z"))
+        );
+        assert_eq!(
+            window(Quarter::new(4, None)),
+            (at("2025-10-01T00:00:00Z"), at("2026-01-01T00:00:00Z"))
+        );
+        assert_eq!(
+            Quarter::new(2, None).to_chrono_min(relative_to),
+            at("2026-04-01T00:00:00Z")
+        );
+        assert_eq!(
+            Quarter::new(1, Some(2024)).to_chrono_min(relative_to),
+            at("2024-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            Quarter::from_chrono(relative_to),
+            Quarter::new(3, Some(2025))
+        );
+    }
+
+    #[test]
+    fn formats_and_parses() {
+        let quarter = Quarter::new(3, None);
+        let with_year = Quarter::new(1, Some(2025));
+
+        assert_eq!(quarter.to_string(), "Q3");
+        assert_eq!(with_year.to_string(), "2025-Q1");
+        assert_eq!("q3".parse(), Ok(quarter));
+        assert_eq!("quarter 3".parse(), Ok(quarter));
+        assert_eq!("kvartal 3".parse(), Ok(quarter));
+        assert_eq!("2025-Q1".parse(), Ok(with_year));
+        assert!("Q5".parse::<Quarter>().is_err());
+        assert!("quarterly".parse::<Quarter>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serializes() {
+        let quarter = Quarter::new(3, Some(2025));
+
+        assert_eq!(serde_json::to_string(&quarter).unwrap(), r#""2025-Q3""#);
+        assert_eq!(
+            serde_json::from_str::<Quarter>(r#""2025-Q3""#).unwrap(),
+            quarter
+        );
+        assert_eq!(
+            serde_json::from_str::<Quarter>(r#"{"quarter":3,"year":2025}"#).unwrap(),
+            quarter
+        );
+        assert!(serde_json::from_str::<Quarter>(r#"{"quarter":5}"#).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+//! Recurring days and iteration over their occurrences.
+
+use core::num::NonZeroU32;
+
+use chrono::{DateTime, Days, NaiveDate, NaiveTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    exact::{ExactDay, ExactMonth},
+    named::Recurrence,
+    weekday::Weekday,
+};
+
+/// A day that recurs on a regular schedule.
+///
+/// Days past the end of a month fall on its last day, so `Monthly(31)` is April 30th in April.
+/// Serialises externally tagged, e.g. `{"Weekly": "Monday"}`, `{"Yearly": [12, 24]}` or
+/// `{"EveryNDays": {"days": 3, "from": "2025-07-01"}}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Recurring {
+    /// Every week on the weekday.
+    Weekly(Weekday),
+    /// Every month on the day.
+    Monthly(ExactDay),
+    /// Every year on the month and day.
+    Yearly(ExactMonth, ExactDay),
+    /// Every `days` days, counting from and including `from`.
+    EveryNDays { days: NonZeroU32, from: NaiveDate },
+}
+
+impl Recurring {
+    /// The first occurrence on or after the given date, if it is within the range chrono
+    /// represents.
+    fn next_on_or_after(self, date: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Recurring::Weekly(weekday) => Some(Recurrence::Weekday(weekday.to_chrono()).next(date)),
+            Recurring::Monthly(day) => {
+                Some(Recurrence::DayOfMonth(u8::from(day).into()).next(date))
+            }
+            Recurring::Yearly(month, day) => Some(
+                Recurrence::Date {
+                    month: u8::from(month).into(),
+                    day: u8::from(day).into(),
+                }
+                .next(date),
+            ),
+            Recurring::EveryNDays { days, from } => {
+                let elapsed = (date - from).num_days().max(0) as u64;
+                let periods = elapsed.div_ceil(days.get().into());
+
+                from.checked_add_days(Days::new(periods.checked_mul(days.get().into())?))
+            }
+        }
+    }
+
+    /// Midnight starting each occurrence that has not ended by the given time, in order.
+    ///
+    /// The reference day is included when it matches. Ends at the last occurrence within the
+    /// range chrono represents.
+    pub fn occurrences(self, relative_to: DateTime<Utc>) -> impl Iterator<Item = DateTime<Utc>> {
+        let first = self.next_on_or_after(relative_to.date_naive());
+
+        core::iter::successors(first, move |previous| {
+            previous
+                .succ_opt()
+                .and_then(|date| self.next_on_or_after(date))
+        })
+        .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+    }
+
+    /// Midnight starting the first occurrence strictly after the given time, if there is one
+    /// within the range chrono represents.
+    pub fn next_after(self, date_time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.occurrences(date_time).find(|start| *start > date_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn iterates_occurrences() {
+        // Tuesday July 29th, 2025
+        let relative_to = at("2025-07-29T10:30:05Z");
+        let take = |recurring: Recurring| -> Vec<_> {
+            recurring.occurrences(relative_to).take(3).collect()
+        };
+
+        assert_eq!(
+            take(Recurring::Weekly(Weekday::tuesday())),
+            [
+                at("2025-07-29T00:00:00Z"),
+                at("2025-08-05T00:00:00Z"),
+                at("2025-08-12T00:00:00Z"),
+            ]
+        );
+        assert_eq!(
+            take(Recurring::Monthly(ExactDay::new(31))),
+            [
+                at("2025-07-31T00:00:00Z"),
+                at("2025-08-31T00:00:00Z"),
+                at("2025-09-30T00:00:00Z"),
+            ]
+        );
+        assert_eq!(
+            take(Recurring::Yearly(ExactMonth::new(2), ExactDay::new(29))),
+            [
+                at("2026-02-28T00:00:00Z"),
+                at("2027-02-28T00:00:00Z"),
+                at("2028-02-29T00:00:00Z"),
+            ]
+        );
+
+        let every_3_days = Recurring::EveryNDays {
+            days: NonZeroU32::new(3).unwrap(),
+            from: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
+        };
+        assert_eq!(
+            take(every_3_days),
+            [
+                at("2025-07-31T00:00:00Z"),
+                at("2025-08-03T00:00:00Z"),
+                at("2025-08-06T00:00:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_next_after() {
+        let weekly = Recurring::Weekly(Weekday::tuesday());
+
+        assert_eq!(
+            weekly.next_after(at("2025-07-29T10:30:05Z")),
+            Some(at("2025-08-05T00:00:00Z"))
+        );
+        assert_eq!(
+            weekly.next_after(at("2025-07-28T23:59:59Z")),
+            Some(at("2025-07-29T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn ends_past_the_representable_range() {
+        let rarely = Recurring::EveryNDays {
+            days: NonZeroU32::MAX,
+            from: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
+        };
+        let relative_to = at("2025-07-29T10:30:05Z");
+
+        assert_eq!(rarely.occurrences(relative_to).next(), None);
+        assert_eq!(rarely.next_after(relative_to), None);
+        assert_eq!(
+            rarely
+                .occurrences(at("2025-07-01T00:00:00Z"))
+                .collect::<Vec<_>>(),
+            [at("2025-07-01T00:00:00Z")]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialises_externally_tagged() {
+        let yearly = Recurring::Yearly(ExactMonth::new(12), ExactDay::new(24));
+        let json = serde_json::to_string(&yearly).unwrap();
+
+        assert_eq!(json, r#"{"Yearly":[12,24]}"#);
+        assert_eq!(serde_json::from_str::<Recurring>(&json).unwrap(), yearly);
+        assert!(serde_json::from_str::<Recurring>(r#"{"Monthly":32}"#).is_err());
+        assert!(
+            serde_json::from_str::<Recurring>(r#"{"EveryNDays":{"days":0,"from":"2025-07-01"}}"#)
+                .is_err()
+        );
+    }
+}
@@ -1,111 +1,214 @@
 //! Relative time expressions with language support.
 
-use chrono::{DateTime, Days, Months, NaiveTime, Utc};
+use chrono::{DateTime, Datelike, Days, Months, NaiveTime, TimeDelta, Timelike, Utc};
 use derive_more::Display;
+#[cfg(feature = "schemars")]
 use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    exact::{ExactDate, ExactDateTime, ExactTime},
+    day_part::{Day, DayPart, Part},
+    exact::{ExactDate, ExactDateTime, ExactTime, in_offset},
     language::Language,
+    macros::keywords,
     month::Month,
+    offset::{Offset, Unit},
+    options::Options,
+    period::{Edge, Period, PeriodEdge},
+    quarter::Quarter,
     traits::WithLanguage,
-    weekday::{Sunday, Weekday},
+    week_number::WeekNumber,
 };
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-pub enum Today {
-    #[default]
-    Today,
-    #[cfg(feature = "swedish")]
-    Idag,
-}
+keywords! {
+    Today {
+        "swedish": Idag,
+        "norwegian" | "danish": IDag,
+        "spanish": Hoy,
+        "french": Aujourdhui = "Aujourd'hui",
+    },
+    Tomorrow {
+        "swedish": Imorgon,
+        "norwegian" | "danish": IMorgen,
+        "spanish": Mañana,
+        "french": Demain,
+    },
+    ThisWeek {
+        "swedish": DennaVecka,
+        "norwegian": DenneUken,
+        "danish": DenneUge,
+        "spanish": EstaSemana,
+        "french": CetteSemaine,
+    },
+    NextWeek {
+        "swedish": NästaVecka,
+        "norwegian": NesteUke,
+        "danish": NæsteUge,
+        "spanish": PróximaSemana,
+        "french": SemaineProchaine,
+    },
+    ThisMonth {
+        "swedish": DennaMånad,
+        "norwegian": DenneMåneden,
+        "danish": DenneMåned,
+        "spanish": EsteMes,
+        "french": CeMois,
+    },
+    NextMonth {
+        "swedish": NästaMånad,
+        "norwegian": NesteMåned,
+        "danish": NæsteMåned,
+        "spanish": PróximoMes,
+        "french": MoisProchain,
+    },
+    ThisYear {
+        "swedish": DettaÅr,
+        "norwegian" | "danish": IÅr,
+        "spanish": EsteAño,
+        "french": CetteAnnée,
+    },
+    NextYear {
+        "swedish": NästaÅr,
+        "norwegian": NesteÅr,
+        "danish": NæsteÅr,
+        "spanish": PróximoAño,
+        "french": AnnéeProchaine,
+    },
+    Yesterday { "swedish": Igår, "norwegian" | "danish": IGår, "spanish": Ayer, "french": Hier },
 
-impl WithLanguage for Today {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Idag,
-            Language::English(_) => Self::Today,
-        }
-    }
-}
+    /// Saturday and Sunday of the current weekend, or the upcoming one on weekdays.
+    ThisWeekend {
+        "swedish": DennaHelg,
+        "norwegian": DenneHelgen,
+        "danish": DenneWeekend,
+        "spanish": EsteFinDeSemana,
+        "french": CeWeekEnd,
+    },
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-pub enum Tomorrow {
-    #[default]
-    Tomorrow,
-    #[cfg(feature = "swedish")]
-    Imorgon,
-}
+    /// The weekend after [`ThisWeekend`].
+    NextWeekend {
+        "swedish": NästaHelg,
+        "norwegian": NesteHelg,
+        "danish": NæsteWeekend,
+        "spanish": PróximoFinDeSemana,
+        "french": WeekEndProchain,
+    },
 
-impl WithLanguage for Tomorrow {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Imorgon,
-            Language::English(_) => Self::Tomorrow,
-        }
-    }
-}
+    /// Anything before the current month, e.g. for grouping old items in a list.
+    Older {
+        "swedish": Äldre,
+        "norwegian": Eldre,
+        "danish": Ældre,
+        "spanish": MásAntiguo,
+        "french": PlusAncien,
+    },
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-pub enum ThisWeek {
-    #[default]
-    ThisWeek,
-    #[cfg(feature = "swedish")]
-    DennaVecka,
-}
+    /// The next week with an even ISO week number, or the current week when it has one.
+    EvenWeek {
+        "swedish": JämnVecka,
+        "norwegian": PartallsUke,
+        "danish": LigeUge,
+        "spanish": SemanaPar,
+        "french": SemainePaire,
+    },
 
-impl WithLanguage for ThisWeek {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::DennaVecka,
-            Language::English(_) => Self::ThisWeek,
-        }
-    }
-}
+    /// The next week with an odd ISO week number, or the current week when it has one.
+    OddWeek {
+        "swedish": UddaVecka,
+        "norwegian": OddetallsUke,
+        "danish": UligeUge,
+        "spanish": SemanaImpar,
+        "french": SemaineImpaire,
+    },
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-pub enum NextWeek {
-    #[default]
-    NextWeek,
-    #[cfg(feature = "swedish")]
-    NästaVecka,
-}
+    /// The quarter of the year containing the current month.
+    ThisQuarter {
+        "swedish": DettaKvartal,
+        "norwegian": DetteKvartalet,
+        "danish": DetteKvartal,
+        "spanish": EsteTrimestre,
+        "french": CeTrimestre,
+    },
 
-impl WithLanguage for NextWeek {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::NästaVecka,
-            Language::English(_) => Self::NextWeek,
-        }
-    }
-}
+    /// The quarter after [`ThisQuarter`].
+    NextQuarter {
+        "swedish": NästaKvartal,
+        "norwegian": NesteKvartal,
+        "danish": NæsteKvartal,
+        "spanish": PróximoTrimestre,
+        "french": TrimestreProchain,
+    },
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-pub enum ThisMonth {
-    #[default]
-    ThisMonth,
-    #[cfg(feature = "swedish")]
-    DennaMånad,
-}
+    /// The first business day after today, skipping weekends and the holidays in the
+    /// [`BusinessCalendar`](crate::business::BusinessCalendar).
+    NextBusinessDay {
+        "swedish": NästaArbetsdag,
+        "norwegian": NesteVirkedag,
+        "danish": NæsteArbejdsdag,
+        "spanish": PróximoDíaHábil,
+        "french": ProchainJourOuvré,
+    },
 
-impl WithLanguage for ThisMonth {
-    fn with_language(&self, language: Language) -> Self {
-        match language {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::DennaMånad,
-            Language::English(_) => Self::ThisMonth,
-        }
-    }
+    /// The current clock hour, from its first minute until the next hour starts.
+    ThisHour {
+        "swedish": DennaTimme,
+        "norwegian": DenneTimen,
+        "danish": DenneTime,
+        "spanish": EstaHora,
+        "french": CetteHeure,
+    },
+
+    /// The clock hour after [`ThisHour`].
+    NextHour {
+        "swedish": NästaTimme,
+        "norwegian": NesteTime,
+        "danish": NæsteTime,
+        "spanish": PróximaHora,
+        "french": HeureProchaine,
+    },
+
+    /// The quarter of an hour from the start of the current minute.
+    Next15Minutes {
+        "swedish": Nästa15Minuter,
+        "norwegian": Neste15Minutter,
+        "danish": Næste15Minutter,
+        "spanish": Próximos15Minutos,
+        "french": Prochaines15Minutes,
+    },
+
+    /// The week before [`ThisWeek`].
+    LastWeek {
+        "swedish": FörraVeckan,
+        "norwegian": ForrigeUke,
+        "danish": ForrigeUge,
+        "spanish": SemanaPasada,
+        "french": SemaineDernière,
+    },
+
+    /// The month before [`ThisMonth`].
+    LastMonth {
+        "swedish": FörraMånaden,
+        "norwegian" | "danish": ForrigeMåned,
+        "spanish": MesPasado,
+        "french": MoisDernier,
+    },
+
+    /// The year before [`ThisYear`].
+    LastYear {
+        "swedish": FörraÅret,
+        "norwegian": IFjor,
+        "danish": SidsteÅr,
+        "spanish": AñoPasado,
+        "french": AnnéeDernière,
+    },
 }
 
 /// A relative time expression, from exact times to rolling time windows.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-#[serde(untagged)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Relative {
     Time(ExactTime),
     Date(ExactDate),
@@ -115,6 +218,29 @@ pub enum Relative {
     ThisWeek(ThisWeek),
     NextWeek(NextWeek),
     ThisMonth(ThisMonth),
+    Yesterday(Yesterday),
+    Older(Older),
+    EvenWeek(EvenWeek),
+    OddWeek(OddWeek),
+    NextMonth(NextMonth),
+    ThisYear(ThisYear),
+    NextYear(NextYear),
+    ThisWeekend(ThisWeekend),
+    NextWeekend(NextWeekend),
+    ThisQuarter(ThisQuarter),
+    NextQuarter(NextQuarter),
+    NextBusinessDay(NextBusinessDay),
+    ThisHour(ThisHour),
+    NextHour(NextHour),
+    Next15Minutes(Next15Minutes),
+    LastWeek(LastWeek),
+    LastMonth(LastMonth),
+    LastYear(LastYear),
+    In(Offset),
+    DayPart(DayPart),
+    Week(WeekNumber),
+    Quarter(Quarter),
+    PeriodEdge(PeriodEdge),
 }
 
 impl WithLanguage for Relative {
@@ -128,6 +254,29 @@ impl WithLanguage for Relative {
             Relative::ThisWeek(x) => Relative::ThisWeek(x.with_language(language)),
             Relative::NextWeek(x) => Relative::NextWeek(x.with_language(language)),
             Relative::ThisMonth(x) => Relative::ThisMonth(x.with_language(language)),
+            Relative::Yesterday(x) => Relative::Yesterday(x.with_language(language)),
+            Relative::Older(x) => Relative::Older(x.with_language(language)),
+            Relative::EvenWeek(x) => Relative::EvenWeek(x.with_language(language)),
+            Relative::OddWeek(x) => Relative::OddWeek(x.with_language(language)),
+            Relative::NextMonth(x) => Relative::NextMonth(x.with_language(language)),
+            Relative::ThisYear(x) => Relative::ThisYear(x.with_language(language)),
+            Relative::NextYear(x) => Relative::NextYear(x.with_language(language)),
+            Relative::ThisWeekend(x) => Relative::ThisWeekend(x.with_language(language)),
+            Relative::NextWeekend(x) => Relative::NextWeekend(x.with_language(language)),
+            Relative::ThisQuarter(x) => Relative::ThisQuarter(x.with_language(language)),
+            Relative::NextQuarter(x) => Relative::NextQuarter(x.with_language(language)),
+            Relative::NextBusinessDay(x) => Relative::NextBusinessDay(x.with_language(language)),
+            Relative::ThisHour(x) => Relative::ThisHour(x.with_language(language)),
+            Relative::NextHour(x) => Relative::NextHour(x.with_language(language)),
+            Relative::Next15Minutes(x) => Relative::Next15Minutes(x.with_language(language)),
+            Relative::LastWeek(x) => Relative::LastWeek(x.with_language(language)),
+            Relative::LastMonth(x) => Relative::LastMonth(x.with_language(language)),
+            Relative::LastYear(x) => Relative::LastYear(x.with_language(language)),
+            Relative::In(x) => Relative::In(x.with_language(language)),
+            Relative::DayPart(x) => Relative::DayPart(x.with_language(language)),
+            Relative::Week(x) => Relative::Week(*x),
+            Relative::Quarter(x) => Relative::Quarter(*x),
+            Relative::PeriodEdge(x) => Relative::PeriodEdge(x.with_language(language)),
         }
     }
 }
@@ -148,16 +297,100 @@ impl Relative {
     pub fn this_month() -> Self {
         Self::ThisMonth(ThisMonth::default())
     }
+    pub fn yesterday() -> Self {
+        Self::Yesterday(Yesterday::default())
+    }
+    pub fn older() -> Self {
+        Self::Older(Older::default())
+    }
+    pub fn even_week() -> Self {
+        Self::EvenWeek(EvenWeek::default())
+    }
+    pub fn odd_week() -> Self {
+        Self::OddWeek(OddWeek::default())
+    }
+    pub fn next_month() -> Self {
+        Self::NextMonth(NextMonth::default())
+    }
+    pub fn this_year() -> Self {
+        Self::ThisYear(ThisYear::default())
+    }
+    pub fn next_year() -> Self {
+        Self::NextYear(NextYear::default())
+    }
+    pub fn this_weekend() -> Self {
+        Self::ThisWeekend(ThisWeekend::default())
+    }
+    pub fn next_weekend() -> Self {
+        Self::NextWeekend(NextWeekend::default())
+    }
+    pub fn this_quarter() -> Self {
+        Self::ThisQuarter(ThisQuarter::default())
+    }
+    pub fn next_quarter() -> Self {
+        Self::NextQuarter(NextQuarter::default())
+    }
+    pub fn next_business_day() -> Self {
+        Self::NextBusinessDay(NextBusinessDay::default())
+    }
+    pub fn this_hour() -> Self {
+        Self::ThisHour(ThisHour::default())
+    }
+    pub fn next_hour() -> Self {
+        Self::NextHour(NextHour::default())
+    }
+    pub fn next_15_minutes() -> Self {
+        Self::Next15Minutes(Next15Minutes::default())
+    }
+    pub fn last_week() -> Self {
+        Self::LastWeek(LastWeek::default())
+    }
+    pub fn last_month() -> Self {
+        Self::LastMonth(LastMonth::default())
+    }
+    pub fn last_year() -> Self {
+        Self::LastYear(LastYear::default())
+    }
+    pub fn offset(amount: u32, unit: Unit) -> Self {
+        Self::In(Offset::new(amount, unit))
+    }
+    pub fn day_part(part: Part, day: Option<Day>) -> Self {
+        Self::DayPart(DayPart::new(part, day))
+    }
+    pub fn tonight() -> Self {
+        Self::DayPart(DayPart::tonight())
+    }
+    pub fn week(week: u8, year: Option<i32>) -> Self {
+        Self::Week(WeekNumber::new(week, year))
+    }
+    pub fn quarter(quarter: u8, year: Option<i32>) -> Self {
+        Self::Quarter(Quarter::new(quarter, year))
+    }
+    pub fn start_of(period: Period) -> Self {
+        Self::PeriodEdge(PeriodEdge::new(Edge::Start, period))
+    }
+    pub fn end_of(period: Period) -> Self {
+        Self::PeriodEdge(PeriodEdge::new(Edge::End, period))
+    }
 
     /// Converts to the earliest possible timestamp, relative to the current time.
+    #[cfg(feature = "std")]
     pub fn to_chrono_min_now(self) -> DateTime<Utc> {
         self.to_chrono_min(Utc::now())
     }
 
     /// Converts to the earliest possible timestamp, relative to the given time.
     pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_min_with(relative_to, Options::default())
+    }
+
+    /// Converts to the earliest possible timestamp, relative to the given time and following
+    /// the given conventions.
+    pub fn to_chrono_min_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
         match self {
-            Relative::Time(x) => relative_to.with_time(x.to_chrono()).unwrap(),
+            Relative::Time(x) => in_offset(x.zone(), relative_to, |relative_to| {
+                relative_to.with_time(x.to_chrono()).unwrap()
+            }),
             Relative::Date(x) => x
                 .to_chrono_min(relative_to)
                 .and_time(NaiveTime::MIN)
@@ -169,28 +402,66 @@ impl Relative {
                 .unwrap()
                 .with_time(NaiveTime::MIN)
                 .unwrap(),
-            Relative::ThisWeek(_) => Weekday::Sunday(Sunday::default())
-                .to_chrono_max(relative_to.checked_sub_days(Days::new(7)).unwrap(), false),
-            Relative::NextWeek(_) => {
-                Weekday::Sunday(Sunday::default()).to_chrono_max(relative_to, false)
-            }
+            Relative::ThisWeek(_) => week_start(relative_to, options.week_start, 0),
+            Relative::NextWeek(_) => week_start(relative_to, options.week_start, 1),
             Relative::ThisMonth(_) => Month::from_chrono(relative_to, false, Language::default())
-                .to_chrono_max(
-                    relative_to.checked_sub_months(Months::new(1)).unwrap(),
-                    false,
-                ),
+                .to_chrono_min(relative_to, false),
+            Relative::Yesterday(_) => relative_to
+                .checked_sub_days(Days::new(1))
+                .unwrap()
+                .with_time(NaiveTime::MIN)
+                .unwrap(),
+            Relative::Older(_) => DateTime::<Utc>::MIN_UTC,
+            Relative::EvenWeek(_) => week_with_parity(relative_to, 0),
+            Relative::OddWeek(_) => week_with_parity(relative_to, 1),
+            Relative::NextMonth(_) => month_start(relative_to, 1),
+            Relative::ThisYear(_) => month_start(relative_to, 0).with_month(1).unwrap(),
+            Relative::ThisWeekend(_) => weekend_start(relative_to, 0),
+            Relative::NextWeekend(_) => weekend_start(relative_to, 1),
+            Relative::NextYear(_) => month_start(relative_to, 12).with_month(1).unwrap(),
+            Relative::ThisQuarter(_) => quarter_start(relative_to, 0),
+            Relative::NextQuarter(_) => quarter_start(relative_to, 1),
+            Relative::NextBusinessDay(_) => {
+                offset_start(relative_to, 1, Unit::BusinessDays, options)
+            }
+            Relative::ThisHour(_) => hour_start(relative_to, 0),
+            Relative::NextHour(_) => hour_start(relative_to, 1),
+            Relative::Next15Minutes(_) => minute_start(relative_to),
+            Relative::LastWeek(_) => week_start(relative_to, options.week_start, 0)
+                .checked_sub_days(Days::new(7))
+                .unwrap(),
+            Relative::LastMonth(_) => month_start(relative_to, 0)
+                .checked_sub_months(Months::new(1))
+                .unwrap(),
+            Relative::LastYear(_) => month_start(relative_to, 0)
+                .with_month(1)
+                .unwrap()
+                .checked_sub_months(Months::new(12))
+                .unwrap(),
+            Relative::In(x) => offset_start(relative_to, x.amount(), x.unit(), options),
+            Relative::DayPart(x) => x.to_chrono_min_with(relative_to, options),
+            Relative::Week(x) => x.to_chrono_min(relative_to),
+            Relative::Quarter(x) => x.to_chrono_min(relative_to),
+            Relative::PeriodEdge(x) => x.to_chrono_with(relative_to, options),
         }
     }
 
     /// Converts to the latest possible timestamp, relative to the current time.
+    #[cfg(feature = "std")]
     pub fn to_chrono_max_now(self) -> DateTime<Utc> {
         self.to_chrono_max(Utc::now())
     }
 
     /// Converts to the latest possible timestamp, relative to the given time.
     pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_max_with(relative_to, Options::default())
+    }
+
+    /// Converts to the latest possible timestamp, relative to the given time and following the
+    /// given conventions.
+    pub fn to_chrono_max_with(self, relative_to: DateTime<Utc>, options: Options) -> DateTime<Utc> {
         match self {
-            Relative::Time(x) => {
+            Relative::Time(x) => in_offset(x.zone(), relative_to, |relative_to| {
                 let x = x.to_chrono();
 
                 if x < relative_to.time() {
@@ -202,7 +473,7 @@ impl Relative {
                 } else {
                     relative_to.with_time(x).unwrap()
                 }
-            }
+            }),
             Relative::Date(x) => x
                 .to_chrono_max(relative_to)
                 .and_time(NaiveTime::MIN)
@@ -218,13 +489,198 @@ impl Relative {
                 .unwrap()
                 .with_time(NaiveTime::MIN)
                 .unwrap(),
-            Relative::ThisWeek(_) => {
-                Weekday::Sunday(Sunday::default()).to_chrono_max(relative_to, false)
-            }
-            Relative::NextWeek(_) => Weekday::Sunday(Sunday::default())
-                .to_chrono_max(relative_to.checked_add_days(Days::new(7)).unwrap(), false),
+            Relative::ThisWeek(_) => week_start(relative_to, options.week_start, 1),
+            Relative::NextWeek(_) => week_start(relative_to, options.week_start, 2),
             Relative::ThisMonth(_) => Month::from_chrono(relative_to, false, Language::default())
                 .to_chrono_max(relative_to, false),
+            Relative::Yesterday(_) => relative_to.with_time(NaiveTime::MIN).unwrap(),
+            Relative::Older(_) => {
+                Relative::ThisMonth(ThisMonth::default()).to_chrono_min(relative_to)
+            }
+            Relative::EvenWeek(_) => week_with_parity(relative_to, 0)
+                .checked_add_days(Days::new(7))
+                .unwrap(),
+            Relative::OddWeek(_) => week_with_parity(relative_to, 1)
+                .checked_add_days(Days::new(7))
+                .unwrap(),
+            Relative::NextMonth(_) => month_start(relative_to, 2),
+            Relative::ThisYear(_) => month_start(relative_to, 12).with_month(1).unwrap(),
+            Relative::ThisWeekend(_) => weekend_start(relative_to, 0)
+                .checked_add_days(Days::new(2))
+                .unwrap(),
+            Relative::NextWeekend(_) => weekend_start(relative_to, 1)
+                .checked_add_days(Days::new(2))
+                .unwrap(),
+            Relative::NextYear(_) => month_start(relative_to, 24).with_month(1).unwrap(),
+            Relative::ThisQuarter(_) => quarter_start(relative_to, 1),
+            Relative::NextQuarter(_) => quarter_start(relative_to, 2),
+            Relative::NextBusinessDay(_) => {
+                offset_start(relative_to, 1, Unit::BusinessDays, options) + Days::new(1)
+            }
+            Relative::ThisHour(_) => hour_start(relative_to, 1),
+            Relative::NextHour(_) => hour_start(relative_to, 2),
+            Relative::Next15Minutes(_) => minute_start(relative_to) + TimeDelta::minutes(15),
+            Relative::LastWeek(_) => week_start(relative_to, options.week_start, 0),
+            Relative::LastMonth(_) => month_start(relative_to, 0),
+            Relative::LastYear(_) => month_start(relative_to, 0).with_month(1).unwrap(),
+            Relative::In(x) if x.unit() == Unit::BusinessDays => {
+                offset_start(relative_to, x.amount(), x.unit(), options)
+                    .checked_add_days(Days::new(1))
+                    .unwrap_or(DateTime::<Utc>::MAX_UTC)
+            }
+            Relative::In(x) => {
+                offset_start(relative_to, x.amount().saturating_add(1), x.unit(), options)
+            }
+            Relative::DayPart(x) => x.to_chrono_max_with(relative_to, options),
+            Relative::Week(x) => x.to_chrono_max(relative_to),
+            Relative::Quarter(x) => x.to_chrono_max(relative_to),
+            Relative::PeriodEdge(x) => x.to_chrono_with(relative_to, options),
         }
     }
+
+    /// Time left until the earliest timestamp, negative once it has passed.
+    pub fn time_until(self, relative_to: DateTime<Utc>) -> chrono::Duration {
+        self.to_chrono_min(relative_to) - relative_to
+    }
+
+    /// Time elapsed since the earliest timestamp, negative while it is still ahead.
+    pub fn time_since(self, relative_to: DateTime<Utc>) -> chrono::Duration {
+        relative_to - self.to_chrono_min(relative_to)
+    }
+
+    /// Whether the whole value has ended by the given time, following the rules of
+    /// [`Time::is_past`](crate::Time::is_past).
+    pub fn is_past(self, relative_to: DateTime<Utc>) -> bool {
+        self.to_chrono_max(relative_to) <= relative_to && !self.is_current(relative_to)
+    }
+
+    /// Whether the whole value is still ahead of the given time, following the rules of
+    /// [`Time::is_future`](crate::Time::is_future).
+    pub fn is_future(self, relative_to: DateTime<Utc>) -> bool {
+        relative_to < self.to_chrono_min(relative_to)
+    }
+
+    /// Whether the value contains the given time, following the rules of
+    /// [`Time::is_current`](crate::Time::is_current).
+    pub fn is_current(self, relative_to: DateTime<Utc>) -> bool {
+        let min = self.to_chrono_min(relative_to);
+        let max = self.to_chrono_max(relative_to);
+
+        min <= relative_to && (relative_to < max || relative_to == min)
+    }
+}
+
+/// Midnight starting the first week from the current one whose ISO week number has the parity.
+///
+/// Years with 53 weeks are followed by week 1, so two odd weeks can be adjacent.
+fn week_with_parity(relative_to: DateTime<Utc>, parity: u32) -> DateTime<Utc> {
+    let mut start = week_start(relative_to, chrono::Weekday::Mon, 0);
+
+    while start.iso_week().week() % 2 != parity {
+        start = start.checked_add_days(Days::new(7)).unwrap();
+    }
+
+    start
+}
+
+/// The start of the clock hour the given number of hours after the current one.
+fn hour_start(relative_to: DateTime<Utc>, hours: i64) -> DateTime<Utc> {
+    let hour = NaiveTime::from_hms_opt(relative_to.hour(), 0, 0).unwrap();
+
+    relative_to.with_time(hour).unwrap() + TimeDelta::hours(hours)
+}
+
+/// The start of the current minute.
+fn minute_start(relative_to: DateTime<Utc>) -> DateTime<Utc> {
+    let minute = NaiveTime::from_hms_opt(relative_to.hour(), relative_to.minute(), 0).unwrap();
+
+    relative_to.with_time(minute).unwrap()
+}
+
+/// Midnight starting the month the given number of months after the current one.
+fn month_start(relative_to: DateTime<Utc>, months: u32) -> DateTime<Utc> {
+    relative_to
+        .with_day(1)
+        .unwrap()
+        .with_time(NaiveTime::MIN)
+        .unwrap()
+        .checked_add_months(Months::new(months))
+        .unwrap()
+}
+
+/// Midnight starting the quarter the given number of quarters after the current one.
+fn quarter_start(relative_to: DateTime<Utc>, quarters: u32) -> DateTime<Utc> {
+    let current = month_start(relative_to, 0);
+    let months_into_quarter = current.month0() % 3;
+
+    current
+        .checked_sub_months(Months::new(months_into_quarter))
+        .unwrap()
+        .checked_add_months(Months::new(quarters * 3))
+        .unwrap()
+}
+
+/// Midnight starting the week the given number of weeks after the current one.
+fn week_start(relative_to: DateTime<Utc>, first_day: chrono::Weekday, weeks: u64) -> DateTime<Utc> {
+    let days_since_start = relative_to.weekday().days_since(first_day);
+
+    relative_to
+        .with_time(NaiveTime::MIN)
+        .unwrap()
+        .checked_sub_days(Days::new(days_since_start.into()))
+        .unwrap()
+        .checked_add_days(Days::new(weeks * 7))
+        .unwrap()
+}
+
+/// Midnight starting Saturday of the weekend the given number of weekends after the current or
+/// upcoming one.
+fn weekend_start(relative_to: DateTime<Utc>, weekends: u64) -> DateTime<Utc> {
+    let days_since_saturday = relative_to.weekday().days_since(chrono::Weekday::Sat);
+    let midnight = relative_to.with_time(NaiveTime::MIN).unwrap();
+
+    let saturday = match days_since_saturday {
+        0 | 1 => midnight.checked_sub_days(Days::new(days_since_saturday.into())),
+        days => midnight.checked_add_days(Days::new((7 - days).into())),
+    };
+
+    saturday
+        .unwrap()
+        .checked_add_days(Days::new(weekends * 7))
+        .unwrap()
+}
+
+/// Midnight starting the day, week, month or year the given number of units after the current
+/// one, or the latest timestamp chrono represents when that is out of range.
+fn offset_start(
+    relative_to: DateTime<Utc>,
+    amount: u32,
+    unit: Unit,
+    options: Options,
+) -> DateTime<Utc> {
+    let start = match unit {
+        Unit::Days => relative_to
+            .with_time(NaiveTime::MIN)
+            .unwrap()
+            .checked_add_days(Days::new(amount.into())),
+        // Counted a day at a time, so bounded before counting
+        Unit::BusinessDays => (amount <= unit.max_amount()).then(|| {
+            options
+                .business_calendar
+                .add(relative_to.date_naive(), amount)
+                .and_time(NaiveTime::MIN)
+                .and_utc()
+        }),
+        Unit::Weeks => week_start(relative_to, options.week_start, 0)
+            .checked_add_days(Days::new(u64::from(amount) * 7)),
+        Unit::Months => month_start(relative_to, 0).checked_add_months(Months::new(amount)),
+        Unit::Years => amount.checked_mul(12).and_then(|months| {
+            month_start(relative_to, 0)
+                .with_month(1)
+                .unwrap()
+                .checked_add_months(Months::new(months))
+        }),
+    };
+
+    start.unwrap_or(DateTime::<Utc>::MAX_UTC)
 }
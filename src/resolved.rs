@@ -0,0 +1,306 @@
+//! Snapshots of time values pinned to the window they resolved to.
+
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveTime, TimeDelta, Timelike, Utc};
+use derive_more::Display;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Time, language::Language, relative::Relative, traits::WithLanguage};
+
+/// A time value together with the window it resolved to at a given moment.
+///
+/// Relative values drift as time passes, so `"Tomorrow"` read back next week means a different
+/// day. Storing a `ResolvedTime` keeps both the symbolic intent and the concrete window it meant
+/// when it was written.
+///
+/// Orders by window like [`Time::cmp_at`], so resolving a mixed list against the same moment
+/// makes it sortable. Ties fall back to the moment resolved at, then the written value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ResolvedTime {
+    pub original: Time,
+    pub resolved_at: DateTime<Utc>,
+    pub min: DateTime<Utc>,
+    pub max: DateTime<Utc>,
+}
+
+impl ResolvedTime {
+    /// Resolves the value relative to the current time.
+    #[cfg(feature = "std")]
+    pub fn now(original: Time) -> Self {
+        Self::new(original, Utc::now())
+    }
+
+    /// Resolves the value relative to the given time.
+    pub fn new(original: Time, resolved_at: DateTime<Utc>) -> Self {
+        Self {
+            min: original.clone().to_chrono_min(resolved_at),
+            max: original.clone().to_chrono_max(resolved_at),
+            original,
+            resolved_at,
+        }
+    }
+
+    /// Resolves the original value again relative to another time.
+    pub fn re_resolve(&self, resolved_at: DateTime<Utc>) -> Self {
+        Self::new(self.original.clone(), resolved_at)
+    }
+}
+
+impl Ord for ResolvedTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.min, self.max, self.resolved_at)
+            .cmp(&(other.min, other.max, other.resolved_at))
+            .then_with(|| self.original.to_string().cmp(&other.original.to_string()))
+    }
+}
+
+impl PartialOrd for ResolvedTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The calendar unit a resolved window spans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Granularity {
+    /// A single timestamp, such as a date and time.
+    Instant,
+    /// One clock hour.
+    Hour,
+    /// One day, from midnight to midnight.
+    Day,
+    /// Seven days from midnight.
+    Week,
+    /// A calendar month.
+    Month,
+    /// A quarter of the year.
+    Quarter,
+    /// A calendar year.
+    Year,
+    /// Any other window, such as a weekend, a part of a day or everything before this month.
+    Other,
+}
+
+impl Granularity {
+    /// The unit spanned from `start` to `end`.
+    fn of(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        let midnight = start.time() == NaiveTime::MIN;
+        let months = |months| {
+            midnight
+                && start.day() == 1
+                && start.checked_add_months(Months::new(months)) == Some(end)
+        };
+
+        if start == end {
+            Granularity::Instant
+        } else if start.minute() == 0 && start.second() == 0 && start + TimeDelta::hours(1) == end {
+            Granularity::Hour
+        } else if midnight && start.checked_add_days(Days::new(1)) == Some(end) {
+            Granularity::Day
+        } else if midnight && start.checked_add_days(Days::new(7)) == Some(end) {
+            Granularity::Week
+        } else if months(1) {
+            Granularity::Month
+        } else if start.month0().is_multiple_of(3) && months(3) {
+            Granularity::Quarter
+        } else if start.month() == 1 && months(12) {
+            Granularity::Year
+        } else {
+            Granularity::Other
+        }
+    }
+}
+
+/// Everything a value resolved to at a given moment, for code that would otherwise ask for its
+/// window, size and spelling separately.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Resolved {
+    /// The earliest timestamp, as given by [`Time::to_chrono_min`].
+    pub start: DateTime<Utc>,
+    /// The latest timestamp, as given by [`Time::to_chrono_max`].
+    pub end: DateTime<Utc>,
+    pub granularity: Granularity,
+    /// Whether the window depends on the moment resolved at, see [`Time::is_relative`].
+    pub relative: bool,
+    /// The value written out in the language resolved in, e.g. "Imorgon" or "July 29, 2025".
+    pub display: String,
+}
+
+impl Time {
+    /// Resolves the value relative to the given time, writing it out in the given language.
+    pub fn resolve(self, relative_to: DateTime<Utc>, language: Language) -> Resolved {
+        let start = self.clone().to_chrono_min(relative_to);
+        let end = self.clone().to_chrono_max(relative_to);
+
+        Resolved {
+            start,
+            end,
+            granularity: Granularity::of(start, end),
+            relative: self.is_relative(),
+            display: display(&self, language),
+        }
+    }
+
+    /// Whether the value's window depends on the time it is resolved relative to. Timestamps,
+    /// dates with a year and weeks or quarters of a given year are absolute.
+    pub fn is_relative(&self) -> bool {
+        match self {
+            Time::Relative(Relative::Date(x)) => x.year().is_none(),
+            Time::Relative(Relative::DateTime(x)) | Time::Exact(x) => x.date().year().is_none(),
+            Time::Relative(Relative::Week(x)) => x.year().is_none(),
+            Time::Relative(Relative::Quarter(x)) => x.year().is_none(),
+            Time::DateTime(_) => false,
+            Time::Boundary(boundary) => boundary.time().is_relative(),
+            Time::At(at) => at.day().is_relative(),
+            _ => true,
+        }
+    }
+}
+
+/// The value written out in the given language, with dates spelled as in running text.
+fn display(time: &Time, language: Language) -> String {
+    match time {
+        Time::Relative(Relative::Date(x)) => x.format(language),
+        Time::Relative(Relative::DateTime(x)) | Time::Exact(x) => x.format(language),
+        time => time.with_language(language).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{
+        exact::{ExactDate, ExactDateTime, ExactTime},
+        relative::Relative,
+        weekday::Weekday,
+    };
+
+    #[test]
+    fn pins_window() {
+        let resolved_at = DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc();
+        let resolved = ResolvedTime::new(Time::Relative(Relative::tomorrow()), resolved_at);
+
+        assert_eq!(resolved.min.to_rfc3339(), "2025-07-30T00:00:00+00:00");
+        assert_eq!(resolved.max.to_rfc3339(), "2025-07-31T00:00:00+00:00");
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&resolved).unwrap();
+
+            assert_eq!(
+                json,
+                r#"{"original":"Tomorrow","resolved_at":"2025-07-29T10:30:05Z","min":"2025-07-30T00:00:00Z","max":"2025-07-31T00:00:00Z"}"#
+            );
+            assert_eq!(
+                serde_json::from_str::<ResolvedTime>(&json).unwrap(),
+                resolved
+            );
+        }
+    }
+
+    #[test]
+    fn sorts_by_window() {
+        // Tuesday July 29th, 2025
+        let resolved_at = DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc();
+        let mut times: Vec<ResolvedTime> = [
+            Time::Weekday(Weekday::friday()),
+            Time::Relative(Relative::this_week()),
+            Time::Relative(Relative::tomorrow()),
+            Time::DateTime(resolved_at),
+        ]
+        .into_iter()
+        .map(|time| ResolvedTime::new(time, resolved_at))
+        .collect();
+
+        times.sort();
+
+        let sorted: Vec<String> = times.iter().map(|time| time.original.to_string()).collect();
+
+        assert_eq!(
+            sorted,
+            ["ThisWeek", "2025-07-29 10:30:05 UTC", "Tomorrow", "Friday"]
+        );
+    }
+
+    #[test]
+    fn resolves_everything_at_once() {
+        let relative_to = DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc();
+        let resolve = |time: Time| time.resolve(relative_to, Language::default());
+
+        let tomorrow = resolve(Time::Relative(Relative::tomorrow()));
+        assert_eq!(tomorrow.start.to_rfc3339(), "2025-07-30T00:00:00+00:00");
+        assert_eq!(tomorrow.end.to_rfc3339(), "2025-07-31T00:00:00+00:00");
+        assert_eq!(tomorrow.granularity, Granularity::Day);
+        assert!(tomorrow.relative);
+        assert_eq!(tomorrow.display, "Tomorrow");
+
+        let granularity = |time: Time| resolve(time).granularity;
+        assert_eq!(
+            granularity(Time::Relative(Relative::this_week())),
+            Granularity::Week
+        );
+        assert_eq!(
+            granularity(Time::Relative(Relative::next_month())),
+            Granularity::Month
+        );
+        assert_eq!(
+            granularity(Time::Relative(Relative::this_quarter())),
+            Granularity::Quarter
+        );
+        assert_eq!(
+            granularity(Time::Relative(Relative::next_year())),
+            Granularity::Year
+        );
+        assert_eq!(
+            granularity(Time::Relative(Relative::this_hour())),
+            Granularity::Hour
+        );
+        assert_eq!(
+            granularity(Time::Relative(Relative::this_weekend())),
+            Granularity::Other
+        );
+
+        let date = ExactDate::new(Some(2025), 12, 24);
+        let christmas_eve = resolve(Time::Exact(ExactDateTime::new(
+            date,
+            ExactTime::new(18, 0, None),
+        )));
+        assert_eq!(christmas_eve.granularity, Granularity::Instant);
+        assert!(!christmas_eve.relative);
+        assert_eq!(christmas_eve.display, "December 24, 2025 18:00");
+        assert!(resolve(Time::Relative(Relative::Date(ExactDate::new(None, 12, 24)))).relative);
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn displays_in_language() {
+        use crate::language::Swedish;
+
+        let relative_to = DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc();
+        let swedish = Language::Swedish(Swedish::default());
+        let resolved = Time::Relative(Relative::tomorrow()).resolve(relative_to, swedish);
+
+        assert_eq!(resolved.display, "Imorgon");
+    }
+}
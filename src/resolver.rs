@@ -0,0 +1,215 @@
+//! Resolving many values against the same reference time.
+
+use alloc::vec::Vec;
+use core::mem::{Discriminant, discriminant};
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    Time, candidate::Candidate, language::Language, month::Month, options::Options,
+    parse::vocabulary, relative::Relative, resolved::ResolvedTime, weekday::Weekday,
+};
+
+/// A keyword regardless of its language, which its window does not depend on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Keyword {
+    Relative(Discriminant<Relative>),
+    Weekday(Discriminant<Weekday>),
+    Month(Discriminant<Month>),
+}
+
+impl Keyword {
+    /// The keyword the value is spelled with, if it may be one. Values carrying more than a
+    /// language, such as offsets, never match a precomputed keyword.
+    fn of(time: &Time) -> Option<Self> {
+        match time {
+            Time::Relative(x) => Some(Self::Relative(discriminant(x))),
+            Time::Weekday(x) => Some(Self::Weekday(discriminant(x))),
+            Time::Month(x) => Some(Self::Month(discriminant(x))),
+            _ => None,
+        }
+    }
+}
+
+/// Resolves values against one reference time, computing the windows of every keyword once.
+///
+/// Resolving values one by one recomputes the same day, week, month and year boundaries for
+/// each, and [`Time::from_max_chrono`] converts every candidate keyword on each call. A resolver
+/// does both up front, so resolving or classifying large batches only looks the results up.
+/// Results match the unbatched conversions following the same [`Options`].
+#[derive(Debug, Clone)]
+pub struct Resolver {
+    relative_to: DateTime<Utc>,
+    options: Options,
+    windows: Vec<(Keyword, DateTime<Utc>, DateTime<Utc>)>,
+    min_candidates: Vec<(DateTime<Utc>, Time)>,
+    max_candidates: Vec<(DateTime<Utc>, Time)>,
+}
+
+impl Resolver {
+    /// Prepares to resolve relative to the given time, classifying timestamps in the given
+    /// language.
+    pub fn new(relative_to: DateTime<Utc>, language: Language) -> Self {
+        Self::new_with(relative_to, language, Options::default())
+    }
+
+    /// Prepares to resolve relative to the given time and following the given conventions.
+    pub fn new_with(relative_to: DateTime<Utc>, language: Language, options: Options) -> Self {
+        let min = |time: &Time| time.clone().to_chrono_min_with(relative_to, options);
+        let max = |time: &Time| time.clone().to_chrono_max_with(relative_to, options);
+
+        let windows = vocabulary(Language::default())
+            .iter()
+            .filter_map(|time| Some((Keyword::of(time)?, min(time), max(time))))
+            .collect();
+
+        let min_candidates = Candidate::keywords(&Candidate::DEFAULT_ORDER, language)
+            .map(|time| (min(&time), time))
+            .collect();
+
+        let max_candidates = Candidate::keywords(&Candidate::DEFAULT_ORDER, language)
+            .map(|time| (max(&time), time))
+            .collect();
+
+        Self {
+            relative_to,
+            options,
+            windows,
+            min_candidates,
+            max_candidates,
+        }
+    }
+
+    pub fn relative_to(&self) -> DateTime<Utc> {
+        self.relative_to
+    }
+
+    /// The precomputed window of the value, if it is a keyword.
+    fn window(&self, time: &Time) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let keyword = Keyword::of(time)?;
+
+        self.windows
+            .iter()
+            .find(|(other, ..)| *other == keyword)
+            .map(|(_, min, max)| (*min, *max))
+    }
+
+    /// The earliest timestamp of the value, like [`Time::to_chrono_min_with`].
+    pub fn resolve_min(&self, time: &Time) -> DateTime<Utc> {
+        match self.window(time) {
+            Some((min, _)) => min,
+            None => time
+                .clone()
+                .to_chrono_min_with(self.relative_to, self.options),
+        }
+    }
+
+    /// The latest timestamp of the value, like [`Time::to_chrono_max_with`].
+    pub fn resolve_max(&self, time: &Time) -> DateTime<Utc> {
+        match self.window(time) {
+            Some((_, max)) => max,
+            None => time
+                .clone()
+                .to_chrono_max_with(self.relative_to, self.options),
+        }
+    }
+
+    /// The value pinned to its window.
+    pub fn resolve(&self, time: Time) -> ResolvedTime {
+        ResolvedTime {
+            min: self.resolve_min(&time),
+            max: self.resolve_max(&time),
+            original: time,
+            resolved_at: self.relative_to,
+        }
+    }
+
+    /// The most natural representation of a timestamp ending a window, like
+    /// [`Time::from_max_chrono`].
+    pub fn classify(&self, date_time: DateTime<Utc>) -> Time {
+        Self::find(&self.max_candidates, date_time)
+    }
+
+    /// The most natural representation of a timestamp starting a window, like
+    /// [`Time::from_min_chrono`].
+    pub fn classify_min(&self, date_time: DateTime<Utc>) -> Time {
+        Self::find(&self.min_candidates, date_time)
+    }
+
+    fn find(candidates: &[(DateTime<Utc>, Time)], date_time: DateTime<Utc>) -> Time {
+        candidates
+            .iter()
+            .find(|(boundary, _)| *boundary == date_time)
+            .map_or(Time::DateTime(date_time), |(_, time)| time.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exact::ExactDate, offset::Unit};
+    use chrono::{Days, Duration};
+
+    fn relative_to() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc()
+    }
+
+    #[test]
+    fn resolves_like_values() {
+        let relative_to = relative_to();
+        let resolver = Resolver::new(relative_to, Language::default());
+        let mut values = vocabulary(Language::default()).to_vec();
+        values.extend([
+            Time::Relative(Relative::offset(3, Unit::Days)),
+            Time::Relative(Relative::Date(ExactDate::new(None, 12, 24))),
+            Time::DateTime(relative_to),
+        ]);
+
+        for time in values {
+            assert_eq!(
+                resolver.resolve(time.clone()),
+                ResolvedTime::new(time, relative_to)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn resolves_keywords_in_any_language() {
+        use crate::language::Swedish;
+
+        let resolver = Resolver::new(relative_to(), Language::default());
+
+        for time in vocabulary(Language::Swedish(Swedish::default())) {
+            assert_eq!(
+                resolver.resolve_max(&time),
+                time.to_chrono_max(relative_to())
+            );
+        }
+    }
+
+    #[test]
+    fn classifies_like_from_chrono() {
+        let relative_to = relative_to();
+        let resolver = Resolver::new(relative_to, Language::default());
+        let midnight = Time::Relative(Relative::today()).to_chrono_min(relative_to);
+        let timestamps = (0..800)
+            .map(|days| midnight + Days::new(days))
+            .chain((0..72).map(|hours| midnight + Duration::hours(hours)));
+
+        for date_time in timestamps {
+            let language = Language::default();
+
+            assert_eq!(
+                resolver.classify(date_time),
+                Time::from_max_chrono(date_time, Some(relative_to), language)
+            );
+            assert_eq!(
+                resolver.classify_min(date_time),
+                Time::from_min_chrono(date_time, Some(relative_to), language)
+            );
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Resolved windows as intervals between two instants.
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Time;
+
+/// The window a value resolves to, from `start` up to but excluding `end`.
+///
+/// Values without a window, like a timestamp, resolve to an empty span with `start == end`,
+/// which contains that single instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct TimeSpan {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl TimeSpan {
+    /// Constructs a span, swapping the ends if `end` is before `start`.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+        }
+    }
+
+    /// Whether the span holds a single instant rather than a window.
+    pub fn is_instant(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn duration(&self) -> chrono::Duration {
+        self.end - self.start
+    }
+
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        match self.is_instant() {
+            true => instant == self.start,
+            false => self.start <= instant && instant < self.end,
+        }
+    }
+
+    pub fn overlaps(&self, other: &TimeSpan) -> bool {
+        self.intersect(other).is_some()
+    }
+
+    /// The span both spans cover, or `None` when they don't overlap.
+    pub fn intersect(&self, other: &TimeSpan) -> Option<TimeSpan> {
+        if self.is_instant() {
+            return other.contains(self.start).then_some(*self);
+        }
+
+        if other.is_instant() {
+            return self.contains(other.start).then_some(*other);
+        }
+
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+
+        (start < end).then_some(TimeSpan { start, end })
+    }
+}
+
+impl Time {
+    /// Resolves the value to its window, relative to the given time.
+    pub fn to_span(self, relative_to: DateTime<Utc>) -> TimeSpan {
+        TimeSpan {
+            start: self.clone().to_chrono_min(relative_to),
+            end: self.to_chrono_max(relative_to),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relative::Relative;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn spans() {
+        // Tuesday July 29th, 2025
+        let now = at("2025-07-29T10:30:05Z");
+        let today = Time::Relative(Relative::today()).to_span(now);
+        let week = Time::Relative(Relative::this_week()).to_span(now);
+        let tomorrow = Time::Relative(Relative::tomorrow()).to_span(now);
+
+        assert_eq!(today.duration(), chrono::Duration::days(1));
+        assert!(today.contains(now));
+        assert!(!today.contains(tomorrow.start));
+        assert!(!today.overlaps(&tomorrow));
+        assert_eq!(week.intersect(&tomorrow), Some(tomorrow));
+
+        let instant = Time::DateTime(now).to_span(now);
+        assert!(instant.is_instant());
+        assert_eq!(today.intersect(&instant), Some(instant));
+        assert_eq!(tomorrow.intersect(&instant), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialises_ends() {
+        let span = TimeSpan::new(at("2025-07-30T00:00:00Z"), at("2025-07-29T00:00:00Z"));
+
+        assert_eq!(
+            serde_json::to_string(&span).unwrap(),
+            r#"{"start":"2025-07-29T00:00:00Z","end":"2025-07-30T00:00:00Z"}"#
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Chrono-style format patterns with names in any language, such as `"%A %d %B"`.
+
+use alloc::{format, string::String};
+use core::fmt::{self, Display, Write};
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    Time, language::Language, month::Month, relative::Relative, traits::WithLanguage,
+    weekday::Weekday,
+};
+
+/// A name spelled for running text, lowercase in every language but English.
+pub(crate) fn name(value: impl Display, language: Language) -> String {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => format!("{value}").to_lowercase(),
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => format!("{value}").to_lowercase(),
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => format!("{value}").to_lowercase(),
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => format!("{value}").to_lowercase(),
+        #[cfg(feature = "french")]
+        Language::French(_) => format!("{value}").to_lowercase(),
+        Language::English(_) => format!("{value}"),
+    }
+}
+
+/// The day of the timestamp as "Today", "Tomorrow" or "Yesterday", if it is one of them.
+fn relative_day(date_time: DateTime<Utc>, relative_to: DateTime<Utc>) -> Option<Relative> {
+    match (date_time.date_naive() - relative_to.date_naive()).num_days() {
+        0 => Some(Relative::today()),
+        1 => Some(Relative::tomorrow()),
+        -1 => Some(Relative::yesterday()),
+        _ => None,
+    }
+}
+
+impl Time {
+    /// Formats the earliest timestamp of the value with a chrono-style pattern, relative to the
+    /// given time.
+    ///
+    /// `%A` and `%B` write the weekday and month in the given language, and `%R` writes
+    /// "Today", "Tomorrow" or "Yesterday" when the day is one of them and the weekday otherwise.
+    /// Other specifiers follow [`chrono::format::strftime`], and patterns it rejects give an
+    /// error.
+    pub fn format(
+        &self,
+        pattern: &str,
+        relative_to: DateTime<Utc>,
+        language: Language,
+    ) -> Result<String, fmt::Error> {
+        let date_time = self.clone().to_chrono_min(relative_to);
+        let weekday = || name(Weekday::from_chrono(date_time, false, language), language);
+        let month = || name(Month::from_chrono(date_time, false, language), language);
+
+        let mut localized = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                localized.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('A') => localized.push_str(&weekday()),
+                Some('B') => localized.push_str(&month()),
+                Some('R') => match relative_day(date_time, relative_to) {
+                    Some(day) => localized.push_str(&name(day.with_language(language), language)),
+                    None => localized.push_str(&weekday()),
+                },
+                Some(specifier) => {
+                    localized.push('%');
+                    localized.push(specifier);
+                }
+                None => localized.push('%'),
+            }
+        }
+
+        let mut formatted = String::new();
+        write!(formatted, "{}", date_time.format(&localized))?;
+
+        Ok(formatted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exact::{ExactDate, ExactDateTime, ExactTime};
+
+    fn relative_to() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc()
+    }
+
+    #[test]
+    fn formats_in_english() {
+        let language = Language::default();
+        let format = |time: Time, pattern| time.format(pattern, relative_to(), language).unwrap();
+
+        let friday = Time::Weekday(Weekday::friday());
+        assert_eq!(format(friday.clone(), "%A %d %B"), "Friday 01 August");
+        assert_eq!(format(friday, "%R %H:%M"), "Friday 00:00");
+
+        let tomorrow = Time::Relative(Relative::tomorrow());
+        assert_eq!(format(tomorrow, "%R, %-d %b %Y"), "Tomorrow, 30 Jul 2025");
+
+        let date = ExactDate::new(Some(2025), 12, 24);
+        let christmas_eve = Time::Exact(ExactDateTime::new(date, ExactTime::new(18, 0, None)));
+        assert_eq!(format(christmas_eve, "%%%A %H:%M"), "%Wednesday 18:00");
+    }
+
+    #[test]
+    #[cfg(feature = "swedish")]
+    fn formats_in_swedish() {
+        use crate::language::Swedish;
+
+        let swedish = Language::Swedish(Swedish::default());
+        let today = Time::Relative(Relative::today());
+
+        assert_eq!(
+            today.format("%R %-d %B", relative_to(), swedish).unwrap(),
+            "idag 29 juli"
+        );
+        assert_eq!(
+            today.format("%A", relative_to(), swedish).unwrap(),
+            "tisdag"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let today = Time::Relative(Relative::today());
+        let formatted = today.format("%Q", relative_to(), Language::default());
+
+        assert!(formatted.is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! Named-field object representations of the exact types.
+//!
+//! By default [`ExactDate`], [`ExactTime`] and [`ExactDateTime`] serialise as externally tagged
+//! tuples such as `{"WithYear": [2025, 7, 29]}`, which some non-Rust consumers can't handle.
+//! Annotating a field with `#[serde(with = "reltime::structured")]` serialises it as an object
+//! with named fields instead:
+//!
+//! ```
+//! use reltime::exact::ExactDate;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "reltime::structured")]
+//!     date: ExactDate,
+//! }
+//!
+//! let event = Event { date: ExactDate::new(Some(2025), 7, 29) };
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&event).unwrap(),
+//!     r#"{"date":{"year":2025,"month":7,"day":29}}"#
+//! );
+//! ```
+//!
+//! Out of range components are clamped when deserialising, as by the types' constructors.
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
+
+use crate::exact::{ExactDate, ExactDateTime, ExactTime, Zone};
+
+/// An exact type with a named-field object representation.
+pub trait Structured: Sized {
+    /// The object representation, usable with `#[schemars(with = "...")]` for schemas.
+    type Repr: Serialize + DeserializeOwned;
+
+    fn to_structured(&self) -> Self::Repr;
+
+    fn from_structured(repr: Self::Repr) -> Self;
+}
+
+/// Serialises the value as its object representation.
+pub fn serialize<T: Structured, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.to_structured().serialize(serializer)
+}
+
+/// Deserialises the value from its object representation.
+pub fn deserialize<'de, T: Structured, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::Repr::deserialize(deserializer).map(T::from_structured)
+}
+
+/// An [`ExactDate`] as `{"year": 2025, "month": 7, "day": 29}`, without `year` when recurring.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct StructuredDate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<i16>,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// An [`ExactTime`] as `{"hour": 14, "minute": 30}`, with `second`, `millisecond` and `zone`
+/// when given.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct StructuredTime {
+    pub hour: u8,
+    pub minute: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub second: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub millisecond: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<Zone>,
+}
+
+/// An [`ExactDateTime`] as the fields of its date and time in one object.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct StructuredDateTime {
+    #[serde(flatten)]
+    pub date: StructuredDate,
+    #[serde(flatten)]
+    pub time: StructuredTime,
+}
+
+impl Structured for ExactDate {
+    type Repr = StructuredDate;
+
+    fn to_structured(&self) -> StructuredDate {
+        StructuredDate {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+        }
+    }
+
+    fn from_structured(repr: StructuredDate) -> Self {
+        ExactDate::new(repr.year, repr.month, repr.day)
+    }
+}
+
+impl Structured for ExactTime {
+    type Repr = StructuredTime;
+
+    fn to_structured(&self) -> StructuredTime {
+        StructuredTime {
+            hour: self.hour(),
+            minute: self.minute(),
+            second: self.has_second().then(|| self.second()),
+            millisecond: self.has_millisecond().then(|| self.millisecond()),
+            zone: self.zone(),
+        }
+    }
+
+    fn from_structured(repr: StructuredTime) -> Self {
+        let time = ExactTime::new(repr.hour, repr.minute, repr.second);
+        let time = match repr.millisecond {
+            Some(millisecond) => time.with_millisecond(millisecond),
+            None => time,
+        };
+
+        match repr.zone {
+            Some(zone) => time.with_zone(zone),
+            None => time,
+        }
+    }
+}
+
+impl Structured for ExactDateTime {
+    type Repr = StructuredDateTime;
+
+    fn to_structured(&self) -> StructuredDateTime {
+        StructuredDateTime {
+            date: self.date().to_structured(),
+            time: self.time().to_structured(),
+        }
+    }
+
+    fn from_structured(repr: StructuredDateTime) -> Self {
+        ExactDateTime::new(
+            ExactDate::from_structured(repr.date),
+            ExactTime::from_structured(repr.time),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Event {
+        #[serde(with = "crate::structured")]
+        at: ExactDateTime,
+    }
+
+    #[test]
+    fn round_trips_objects() {
+        let event = Event {
+            at: ExactDateTime::new(
+                ExactDate::new(None, 7, 29),
+                ExactTime::new(9, 0, None).with_zone(Zone::Local),
+            ),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"at":{"month":7,"day":29,"hour":9,"minute":0,"zone":"Local"}}"#
+        );
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+    }
+
+    #[test]
+    fn clamps_components() {
+        let event: Event = serde_json::from_str(
+            r#"{"at":{"year":2025,"month":13,"day":1,"hour":25,"minute":0,"second":5}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            event.at,
+            ExactDateTime::new(
+                ExactDate::new(Some(2025), 12, 1),
+                ExactTime::new(23, 0, Some(5))
+            )
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! An explicitly tagged representation of [`Time`].
+//!
+//! [`Time`] serialises untagged, e.g. `"Monday"`, which reads naturally but leaves consumers to
+//! guess the kind and produces vague errors when nothing matches. Annotating a field with
+//! `#[serde(with = "reltime::tagged")]`, or wrapping the value in [`Tagged`], names the kind
+//! instead:
+//!
+//! ```
+//! use reltime::{Time, weekday::Weekday};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "reltime::tagged")]
+//!     due: Time,
+//! }
+//!
+//! let event = Event { due: Time::Weekday(Weekday::monday()) };
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&event).unwrap(),
+//!     r#"{"due":{"type":"Weekday","value":"Monday"}}"#
+//! );
+//! ```
+//!
+//! The type is the name of the value's [`TimeKind`](crate::TimeKind) and the value holds the
+//! untagged form, so either form can be converted to the other.
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    At, Boundary, Time,
+    exact::ExactDateTime,
+    month::Month,
+    named::NamedTime,
+    ordinal::OrdinalWeekday,
+    relative::Relative,
+    weekday::{LastWeekday, Weekday, WeekdayTime},
+};
+
+/// Mirrors [`Time`], tagged by its [`TimeKind`](crate::TimeKind).
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(tag = "type", content = "value", rename = "TaggedTime")]
+enum Repr {
+    Relative(Relative),
+    Weekday(Weekday),
+    WeekdayTime(WeekdayTime),
+    Month(Month),
+    Exact(ExactDateTime),
+    #[serde(rename = "Instant")]
+    DateTime(DateTime<Utc>),
+    Named(NamedTime),
+    OrdinalWeekday(OrdinalWeekday),
+    Boundary(Boundary),
+    At(At),
+    LastWeekday(LastWeekday),
+}
+
+impl From<Time> for Repr {
+    fn from(time: Time) -> Self {
+        match time {
+            Time::Relative(x) => Repr::Relative(x),
+            Time::Weekday(x) => Repr::Weekday(x),
+            Time::WeekdayTime(x) => Repr::WeekdayTime(x),
+            Time::Month(x) => Repr::Month(x),
+            Time::Exact(x) => Repr::Exact(x),
+            Time::DateTime(x) => Repr::DateTime(x),
+            Time::Named(x) => Repr::Named(x),
+            Time::OrdinalWeekday(x) => Repr::OrdinalWeekday(x),
+            Time::Boundary(x) => Repr::Boundary(x),
+            Time::At(x) => Repr::At(x),
+            Time::LastWeekday(x) => Repr::LastWeekday(x),
+        }
+    }
+}
+
+impl From<Repr> for Time {
+    fn from(repr: Repr) -> Self {
+        match repr {
+            Repr::Relative(x) => Time::Relative(x),
+            Repr::Weekday(x) => Time::Weekday(x),
+            Repr::WeekdayTime(x) => Time::WeekdayTime(x),
+            Repr::Month(x) => Time::Month(x),
+            Repr::Exact(x) => Time::Exact(x),
+            Repr::DateTime(x) => Time::DateTime(x),
+            Repr::Named(x) => Time::Named(x),
+            Repr::OrdinalWeekday(x) => Time::OrdinalWeekday(x),
+            Repr::Boundary(x) => Time::Boundary(x),
+            Repr::At(x) => Time::At(x),
+            Repr::LastWeekday(x) => Time::LastWeekday(x),
+        }
+    }
+}
+
+/// Serialises the value tagged by kind, e.g. `{"type": "Weekday", "value": "Monday"}`.
+pub fn serialize<S: Serializer>(value: &Time, serializer: S) -> Result<S::Ok, S::Error> {
+    Repr::from(value.clone()).serialize(serializer)
+}
+
+/// Deserialises the value from its tagged form.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Time, D::Error> {
+    Repr::deserialize(deserializer).map(Time::from)
+}
+
+/// A [`Time`] that serialises tagged by kind, e.g. `{"type": "Weekday", "value": "Monday"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tagged(pub Time);
+
+impl From<Time> for Tagged {
+    fn from(time: Time) -> Self {
+        Self(time)
+    }
+}
+
+impl From<Tagged> for Time {
+    fn from(tagged: Tagged) -> Self {
+        tagged.0
+    }
+}
+
+impl Time {
+    /// Wraps the value to serialise tagged by kind. See [`tagged`](crate::tagged).
+    pub fn tagged(self) -> Tagged {
+        Tagged(self)
+    }
+}
+
+impl Serialize for Tagged {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Tagged {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Tagged)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Tagged {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        Repr::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        Repr::json_schema(generator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::exact::ExactTime;
+
+    #[test]
+    fn round_trips_with_untagged() {
+        let times = [
+            Time::Relative(Relative::tomorrow()),
+            Time::Relative(Relative::Time(ExactTime::new(9, 30, None))),
+            Time::Weekday(Weekday::monday()),
+            Time::Month(Month::july()),
+            "2025-07-29T10:30:05Z".parse().unwrap(),
+            Time::Relative(Relative::today()).at(ExactTime::new(9, 0, None)),
+        ];
+
+        for time in times {
+            let json = serde_json::to_string(&time.clone().tagged()).unwrap();
+            let tagged: Tagged = serde_json::from_str(&json).unwrap();
+            let untagged = serde_json::to_value(&time).unwrap();
+            let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+
+            assert_eq!(tagged.0, time);
+            assert_eq!(value["type"], time.kind().to_string());
+            assert_eq!(value["value"], untagged);
+        }
+    }
+
+    #[test]
+    fn reports_unknown_kinds() {
+        let error = serde_json::from_str::<Tagged>(r#"{"type":"Fortnight","value":"Monday"}"#)
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("unknown variant `Fortnight`"), "{error}");
+    }
+}
@@ -0,0 +1,435 @@
+//! Value generators for testing invariants, behind the `testing` feature.
+//!
+//! The generators are deterministic and cover small grids exhaustively, so downstream crates can
+//! check properties like `to_chrono_min <= to_chrono_max` across every keyword, language and
+//! reference time. For randomised tests, `Time`, `Relative`, `ExactDate`, `ExactTime`, `Weekday`
+//! and `Month` implement `proptest`'s `Arbitrary`, and [`any_reference_time`] draws the instants
+//! to resolve them against.
+
+use alloc::vec::Vec;
+use chrono::{DateTime, Duration, Utc};
+use proptest::{
+    arbitrary::{Arbitrary, any},
+    option, prop_oneof,
+    sample::select,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use crate::{
+    Time,
+    day_part::DayPart,
+    exact::{ExactDate, ExactDateTime, ExactTime, Zone},
+    language::Language,
+    month::Month,
+    offset::Unit,
+    ordinal::{Ordinal, OrdinalWeekday},
+    parse::vocabulary,
+    period::PeriodEdge,
+    relative::Relative,
+    weekday::Weekday,
+};
+
+/// Every keyword in every enabled language.
+pub fn keywords() -> Vec<Time> {
+    Language::all().into_iter().flat_map(vocabulary).collect()
+}
+
+/// Every weekday in every enabled language.
+pub fn weekdays() -> Vec<Weekday> {
+    keywords()
+        .into_iter()
+        .filter_map(|time| match time {
+            Time::Weekday(weekday) => Some(weekday),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Every month in every enabled language.
+pub fn months() -> Vec<Month> {
+    keywords()
+        .into_iter()
+        .filter_map(|time| match time {
+            Time::Month(month) => Some(month),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Every relative keyword in every enabled language.
+pub fn relatives() -> Vec<Relative> {
+    keywords()
+        .into_iter()
+        .filter_map(|time| match time {
+            Time::Relative(relative) => Some(relative),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Every date of a leap year without a year, and the first, middle and last days of each month
+/// in a leap and a common year.
+pub fn exact_dates() -> Vec<ExactDate> {
+    let without_year = (1..=12).flat_map(|month| {
+        (1..=31).filter_map(move |day| ExactDate::try_new(None, month, day).ok())
+    });
+
+    let with_year = [2024, 2025].into_iter().flat_map(|year| {
+        (1..=12).flat_map(move |month| {
+            [1, 15, 28, 29, 30, 31]
+                .into_iter()
+                .filter_map(move |day| ExactDate::try_new(Some(year), month, day).ok())
+        })
+    });
+
+    without_year.chain(with_year).collect()
+}
+
+/// Times of day around midnight, noon and the end of the day, with and without seconds and
+/// zones.
+pub fn exact_times() -> Vec<ExactTime> {
+    let times = [(0, 0), (0, 1), (9, 30), (12, 0), (23, 59)];
+
+    times
+        .into_iter()
+        .flat_map(|(hour, minute)| {
+            [
+                ExactTime::new(hour, minute, None),
+                ExactTime::new(hour, minute, Some(0)),
+                ExactTime::new(hour, minute, Some(59)),
+                ExactTime::new(hour, minute, Some(59)).with_millisecond(999),
+                ExactTime::new(hour, minute, None).with_zone(Zone::Utc),
+                ExactTime::new(hour, minute, None).with_zone(Zone::Local),
+            ]
+        })
+        .collect()
+}
+
+/// A mix of every kind of value: all keywords and day parts, a sample of exact dates, times and
+/// date-times, keywords at a time of day, timestamps, and the boundaries of each.
+pub fn times() -> Vec<Time> {
+    let dates = exact_dates().into_iter().step_by(17);
+    let date_times = dates
+        .clone()
+        .zip(exact_times().into_iter().cycle())
+        .map(|(date, time)| ExactDateTime::new(date, time));
+
+    let values: Vec<Time> = keywords()
+        .into_iter()
+        .chain(dates.map(|date| Time::Relative(Relative::Date(date))))
+        .chain(
+            exact_times()
+                .into_iter()
+                .map(|time| Time::Relative(Relative::Time(time))),
+        )
+        .chain(
+            date_times
+                .clone()
+                .map(|x| Time::Relative(Relative::DateTime(x))),
+        )
+        .chain(date_times.map(Time::Exact))
+        .chain(
+            keywords()
+                .into_iter()
+                .step_by(3)
+                .map(|time| time.at(ExactTime::new(17, 30, None))),
+        )
+        .chain(offsets().map(Time::Relative))
+        .chain(
+            Language::all()
+                .into_iter()
+                .flat_map(DayPart::all)
+                .map(|x| Time::Relative(Relative::DayPart(x))),
+        )
+        .chain([
+            Time::Relative(Relative::week(1, None)),
+            Time::Relative(Relative::week(32, None)),
+            Time::Relative(Relative::week(53, None)),
+            Time::Relative(Relative::week(53, Some(2026))),
+            Time::Relative(Relative::quarter(1, None)),
+            Time::Relative(Relative::quarter(3, None)),
+            Time::Relative(Relative::quarter(4, Some(2025))),
+        ])
+        .chain(
+            Language::all()
+                .into_iter()
+                .flat_map(PeriodEdge::all)
+                .map(|x| Time::Relative(Relative::PeriodEdge(x))),
+        )
+        .chain(ordinal_weekdays().map(Time::OrdinalWeekday))
+        .chain(
+            reference_times()
+                .into_iter()
+                .step_by(97)
+                .map(Time::DateTime),
+        )
+        .collect();
+
+    let boundaries: Vec<Time> = values
+        .iter()
+        .step_by(5)
+        .flat_map(|time| [time.clone().start_of(), time.clone().end_of()])
+        .collect();
+
+    values.into_iter().chain(boundaries).collect()
+}
+
+/// Offsets of zero, one and a dozen of each unit.
+fn offsets() -> impl Iterator<Item = Relative> {
+    [
+        Unit::Days,
+        Unit::BusinessDays,
+        Unit::Weeks,
+        Unit::Months,
+        Unit::Years,
+    ]
+    .into_iter()
+    .flat_map(|unit| [0, 1, 12].map(|amount| Relative::offset(amount, unit)))
+}
+
+/// Every ordinal of Monday, in every month and in February.
+fn ordinal_weekdays() -> impl Iterator<Item = OrdinalWeekday> {
+    Ordinal::ALL.into_iter().flat_map(|ordinal| {
+        [None, Some(Month::february())]
+            .map(|month| OrdinalWeekday::new(ordinal, Weekday::monday(), month))
+    })
+}
+
+/// Reference times every 31 hours through 2024 and 2025, covering every weekday, time of day
+/// bucket, month end and the leap day, plus both new year midnights.
+pub fn reference_times() -> Vec<DateTime<Utc>> {
+    let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+        .unwrap()
+        .to_utc();
+    let end = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+        .unwrap()
+        .to_utc();
+
+    (0..)
+        .map(|step| start + Duration::hours(31 * step))
+        .take_while(|time| *time < end)
+        .chain([end])
+        .collect()
+}
+
+/// Instants between 1900 and 2100, to resolve arbitrary values against.
+pub fn any_reference_time() -> impl Strategy<Value = DateTime<Utc>> {
+    (-2_208_988_800..4_102_444_800i64)
+        .prop_map(|seconds| DateTime::from_timestamp(seconds, 0).unwrap())
+}
+
+impl Arbitrary for Weekday {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        select(weekdays()).boxed()
+    }
+}
+
+impl Arbitrary for Month {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        select(months()).boxed()
+    }
+}
+
+impl Arbitrary for ExactDate {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (option::of(1900..=2100i16), 1..=12u8, 1..=31u8)
+            .prop_filter_map("invalid date", |(year, month, day)| {
+                ExactDate::try_new(year, month, day).ok()
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for ExactTime {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        let zone = prop_oneof![
+            Just(None),
+            Just(Some(Zone::Utc)),
+            Just(Some(Zone::Local)),
+            (-50_400..=50_400i32).prop_map(|seconds| Some(Zone::Offset(seconds / 900 * 900))),
+        ];
+
+        (
+            0..24u8,
+            0..60u8,
+            option::of(0..60u8),
+            option::of(0..1000u16),
+            zone,
+        )
+            .prop_map(|(hour, minute, second, millisecond, zone)| {
+                let mut time = ExactTime::new(hour, minute, second);
+                if let (Some(_), Some(millisecond)) = (second, millisecond) {
+                    time = time.with_millisecond(millisecond);
+                }
+                match zone {
+                    Some(zone) => time.with_zone(zone),
+                    None => time,
+                }
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Relative {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        let unit = select(Vec::from([
+            Unit::Days,
+            Unit::BusinessDays,
+            Unit::Weeks,
+            Unit::Months,
+            Unit::Years,
+        ]));
+
+        prop_oneof![
+            select(relatives()),
+            any::<ExactDate>().prop_map(Relative::Date),
+            any::<ExactTime>().prop_map(Relative::Time),
+            (any::<ExactDate>(), any::<ExactTime>())
+                .prop_map(|(date, time)| Relative::DateTime(ExactDateTime::new(date, time))),
+            (0..=1000u32, unit).prop_map(|(amount, unit)| Relative::offset(amount, unit)),
+            (1..=53u8, option::of(1900..=2100i32))
+                .prop_map(|(week, year)| Relative::week(week, year)),
+            (1..=4u8, option::of(1900..=2100i32))
+                .prop_map(|(quarter, year)| Relative::quarter(quarter, year)),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Time {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        let value = prop_oneof![
+            select(times()),
+            any::<Weekday>().prop_map(Time::Weekday),
+            any::<Month>().prop_map(Time::Month),
+            any::<Relative>().prop_map(Time::Relative),
+            (any::<ExactDate>(), any::<ExactTime>())
+                .prop_map(|(date, time)| Time::Exact(ExactDateTime::new(date, time))),
+            (select(keywords()), any::<ExactTime>()).prop_map(|(time, at)| time.at(at)),
+        ];
+
+        prop_oneof![
+            4 => value.clone(),
+            1 => value.clone().prop_map(Time::start_of),
+            1 => value.prop_map(Time::end_of),
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        relative::{Today, Yesterday},
+        traits::FromLanguage,
+    };
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn arbitrary_min_is_not_after_max(time in any::<Time>(), relative_to in any_reference_time()) {
+            let min = time.clone().to_chrono_min(relative_to);
+            let max = time.clone().to_chrono_max(relative_to);
+
+            prop_assert!(min <= max, "{time:?} relative to {relative_to}");
+        }
+
+        #[test]
+        fn arbitrary_keywords_round_trip_max(
+            (language, time) in select(Language::all()).prop_flat_map(|language| {
+                (Just(language), select(vocabulary(language).to_vec()))
+            }),
+            relative_to in any_reference_time(),
+        ) {
+            let max = time.clone().to_chrono_max(relative_to);
+            let from_max = Time::from_max_chrono(max, Some(relative_to), language);
+
+            prop_assert_eq!(from_max.to_chrono_max(relative_to), max);
+        }
+    }
+
+    #[test]
+    fn min_is_not_after_max() {
+        let times = times();
+
+        for relative_to in reference_times() {
+            for time in &times {
+                let min = time.clone().to_chrono_min(relative_to);
+                let max = time.clone().to_chrono_max(relative_to);
+
+                assert!(min <= max, "{time:?} relative to {relative_to}");
+            }
+        }
+    }
+
+    #[test]
+    fn from_chrono_preserves_bounds() {
+        let keywords: Vec<_> = Language::all()
+            .into_iter()
+            .flat_map(|language| vocabulary(language).map(|time| (language, time)))
+            .collect();
+
+        for relative_to in reference_times() {
+            for (language, time) in keywords.iter().cloned() {
+                let min = time.clone().to_chrono_min(relative_to);
+                let max = time.clone().to_chrono_max(relative_to);
+
+                let from_min = Time::from_min_chrono(min, Some(relative_to), language);
+                let from_max = Time::from_max_chrono(max, Some(relative_to), language);
+
+                assert_eq!(from_min.to_chrono_min(relative_to), min, "{time:?}");
+                assert_eq!(from_max.to_chrono_max(relative_to), max, "{time:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn from_max_chrono_round_trips_days() {
+        for relative_to in reference_times() {
+            for language in Language::all() {
+                for time in [
+                    Time::Relative(Relative::Today(Today::from_language(language))),
+                    Time::Relative(Relative::Yesterday(Yesterday::from_language(language))),
+                ] {
+                    let max = time.clone().to_chrono_max(relative_to);
+                    let from_max = Time::from_max_chrono(max, Some(relative_to), language);
+
+                    assert_eq!(from_max, time);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn exact_values_are_valid() {
+        assert!(
+            exact_dates()
+                .into_iter()
+                .all(|date| date.validate().is_ok())
+        );
+        assert!(
+            exact_times()
+                .into_iter()
+                .all(|time| time.validate().is_ok())
+        );
+    }
+}
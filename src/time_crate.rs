@@ -0,0 +1,245 @@
+//! Conversions to and from the `time` crate, for callers using it instead of chrono.
+//!
+//! Values resolve on the wall clock of the offset the reference time is given in, as with the
+//! `_tz` conversions in [`crate::tz`], and come back in that offset. Timestamps beyond the years
+//! `time` represents are clamped to its range.
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, Utc};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+use crate::{
+    Time,
+    exact::{ExactDate, ExactDateTime, ExactTime, Zone},
+    language::Language,
+    relative::Relative,
+};
+
+/// The instant as a chrono timestamp in its offset.
+fn to_chrono(x: OffsetDateTime) -> DateTime<FixedOffset> {
+    let offset = FixedOffset::east_opt(x.offset().whole_seconds()).unwrap();
+
+    DateTime::from_timestamp(x.unix_timestamp(), x.nanosecond())
+        .unwrap()
+        .with_timezone(&offset)
+}
+
+/// The instant in the offset, clamped to the range `time` represents.
+fn from_chrono<Tz: chrono::TimeZone>(x: DateTime<Tz>, offset: UtcOffset) -> OffsetDateTime {
+    let clamped = |x: PrimitiveDateTime| {
+        x.assume_utc()
+            .checked_to_offset(offset)
+            .unwrap_or(x.assume_offset(offset))
+    };
+
+    match x.timestamp_nanos_opt().map(i128::from) {
+        Some(nanos) => OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .unwrap()
+            .to_offset(offset),
+        None if x.timestamp() < 0 => clamped(PrimitiveDateTime::MIN),
+        None => clamped(PrimitiveDateTime::MAX),
+    }
+}
+
+/// The instant showing the same wall clock time in UTC, for conversions only reading the date.
+fn wall_clock(x: OffsetDateTime) -> DateTime<Utc> {
+    to_chrono(x.replace_offset(UtcOffset::UTC)).to_utc()
+}
+
+fn to_date(x: NaiveDate) -> Date {
+    Date::from_ordinal_date(x.year(), x.ordinal() as u16).unwrap_or(if x.year() < 0 {
+        Date::MIN
+    } else {
+        Date::MAX
+    })
+}
+
+fn from_date(x: Date) -> NaiveDate {
+    NaiveDate::from_yo_opt(x.year(), x.ordinal().into()).unwrap()
+}
+
+fn from_time(x: time::Time) -> NaiveTime {
+    let (h, m, s, nano) = x.as_hms_nano();
+
+    NaiveTime::from_hms_nano_opt(h.into(), m.into(), s.into(), nano).unwrap()
+}
+
+impl Time {
+    /// Converts to the earliest possible timestamp, relative to the given time.
+    pub fn to_time_min(self, relative_to: OffsetDateTime) -> OffsetDateTime {
+        from_chrono(
+            self.to_chrono_min_tz(to_chrono(relative_to)),
+            relative_to.offset(),
+        )
+    }
+
+    /// Converts to the latest possible timestamp, relative to the given time.
+    pub fn to_time_max(self, relative_to: OffsetDateTime) -> OffsetDateTime {
+        from_chrono(
+            self.to_chrono_max_tz(to_chrono(relative_to)),
+            relative_to.offset(),
+        )
+    }
+
+    /// Converts a timestamp to the most natural time representation, like
+    /// [`Time::from_max_chrono`].
+    pub fn from_time_offset_date_time(
+        date_time: OffsetDateTime,
+        relative_to: Option<OffsetDateTime>,
+        language: Language,
+    ) -> Time {
+        Time::from_max_chrono(
+            to_chrono(date_time).to_utc(),
+            relative_to.map(|x| to_chrono(x).to_utc()),
+            language,
+        )
+    }
+}
+
+impl ExactDate {
+    /// Converts to the date in the reference year, relative to the date the given time shows.
+    pub fn to_time_min(&self, relative_to: OffsetDateTime) -> Date {
+        to_date(self.to_chrono_min(wall_clock(relative_to)))
+    }
+
+    /// Converts to the next occurrence of the date, relative to the date the given time shows.
+    pub fn to_time_max(&self, relative_to: OffsetDateTime) -> Date {
+        to_date(self.to_chrono_max(wall_clock(relative_to)))
+    }
+
+    /// The date the timestamp shows in its offset.
+    pub fn from_time_offset_date_time(date_time: OffsetDateTime) -> Self {
+        Self::from_chrono(from_date(date_time.date()))
+    }
+}
+
+impl ExactTime {
+    /// Converts to the time of day on the reference date, like [`Relative::Time`].
+    pub fn to_time_min(self, relative_to: OffsetDateTime) -> OffsetDateTime {
+        Time::Relative(Relative::Time(self)).to_time_min(relative_to)
+    }
+
+    /// Converts to the next occurrence of the time of day, like [`Relative::Time`].
+    pub fn to_time_max(self, relative_to: OffsetDateTime) -> OffsetDateTime {
+        Time::Relative(Relative::Time(self)).to_time_max(relative_to)
+    }
+
+    /// The time of day the timestamp shows, in its offset.
+    pub fn from_time_offset_date_time(date_time: OffsetDateTime) -> Self {
+        Self::from_chrono(from_time(date_time.time()))
+            .with_zone(Zone::Offset(date_time.offset().whole_seconds()))
+    }
+}
+
+impl ExactDateTime {
+    /// Converts to the timestamp in the reference year, relative to the given time.
+    pub fn to_time_min(self, relative_to: OffsetDateTime) -> OffsetDateTime {
+        Time::Exact(self).to_time_min(relative_to)
+    }
+
+    /// Converts to the next occurrence's timestamp, relative to the given time.
+    pub fn to_time_max(self, relative_to: OffsetDateTime) -> OffsetDateTime {
+        Time::Exact(self).to_time_max(relative_to)
+    }
+
+    /// The date and time of day the timestamp shows, in its offset.
+    pub fn from_time_offset_date_time(date_time: OffsetDateTime) -> Self {
+        Self::new(
+            ExactDate::from_time_offset_date_time(date_time),
+            ExactTime::from_time_offset_date_time(date_time),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+    use crate::weekday::Weekday;
+
+    fn at(year: i32, month: Month, day: u8, hour: u8, minute: u8, second: u8) -> OffsetDateTime {
+        OffsetDateTime::new_in_offset(
+            Date::from_calendar_date(year, month, day).unwrap(),
+            time::Time::from_hms(hour, minute, second).unwrap(),
+            UtcOffset::from_hms(2, 0, 0).unwrap(),
+        )
+    }
+
+    // Tuesday July 29th, 2025 at 10:30:05 in Stockholm
+    fn relative_to() -> OffsetDateTime {
+        at(2025, Month::July, 29, 10, 30, 5)
+    }
+
+    #[test]
+    fn matches_chrono() {
+        let chrono_relative_to = to_chrono(relative_to());
+        let times = [
+            Time::Relative(Relative::today()),
+            Time::Relative(Relative::next_week()),
+            Time::Weekday(Weekday::friday()),
+            Time::Exact(ExactDateTime::new(
+                ExactDate::new(None, 12, 24),
+                ExactTime::new(15, 0, None),
+            )),
+        ];
+
+        for time in times {
+            assert_eq!(
+                time.clone().to_time_min(relative_to()),
+                from_chrono(
+                    time.clone().to_chrono_min_tz(chrono_relative_to),
+                    relative_to().offset()
+                ),
+            );
+            assert_eq!(
+                to_chrono(time.clone().to_time_max(relative_to())),
+                time.to_chrono_max_tz(chrono_relative_to),
+            );
+        }
+
+        assert_eq!(
+            Time::Relative(Relative::today()).to_time_min(relative_to()),
+            at(2025, Month::July, 29, 0, 0, 0)
+        );
+        assert_eq!(
+            from_chrono(DateTime::<Utc>::MIN_UTC, UtcOffset::UTC),
+            PrimitiveDateTime::MIN.assume_utc()
+        );
+    }
+
+    #[test]
+    fn round_trips_exact_values() {
+        let date_time = relative_to();
+
+        let date = ExactDate::from_time_offset_date_time(date_time);
+        assert_eq!(date, ExactDate::new(Some(2025), 7, 29));
+        assert_eq!(date.to_time_min(date_time), date_time.date());
+        assert_eq!(date.to_time_max(date_time), date_time.date());
+
+        let time = ExactTime::from_time_offset_date_time(date_time);
+        assert_eq!(time.zone(), Some(Zone::Offset(7200)));
+        assert_eq!(time.to_time_min(date_time), date_time);
+        assert_eq!(time.to_time_max(date_time), date_time);
+
+        let exact = ExactDateTime::from_time_offset_date_time(date_time);
+        assert_eq!(exact.to_time_min(date_time), date_time);
+        assert_eq!(
+            to_chrono(exact.to_time_max(date_time)),
+            exact.to_chrono_max(to_chrono(date_time).to_utc())
+        );
+    }
+
+    #[test]
+    fn classifies_like_chrono() {
+        let tomorrow = at(2025, Month::July, 31, 0, 0, 0);
+
+        assert_eq!(
+            Time::from_time_offset_date_time(tomorrow, Some(relative_to()), Language::default()),
+            Time::from_max_chrono(
+                to_chrono(tomorrow).to_utc(),
+                Some(to_chrono(relative_to()).to_utc()),
+                Language::default()
+            ),
+        );
+    }
+}
@@ -5,6 +5,19 @@ use crate::language::Language;
 /// Converts a time type to a specific language representation.
 pub trait WithLanguage {
     fn with_language(&self, language: Language) -> Self;
+
+    /// The language the value is spelled in, e.g. Swedish for a deserialised `"Måndag"`.
+    /// Values spelled the same in every language, such as exact dates, report the default
+    /// language.
+    fn language(&self) -> Language
+    where
+        Self: PartialEq + Sized,
+    {
+        Language::all()
+            .into_iter()
+            .find(|language| self.with_language(*language) == *self)
+            .unwrap_or_default()
+    }
 }
 
 /// Creates a time type in a specific language from its default representation.
@@ -0,0 +1,310 @@
+//! Conversions relative to a time in any time zone rather than UTC.
+//!
+//! Windows like `Today` or `ThisWeek` are resolved on the wall clock of the zone the reference
+//! time is given in, so "today" in Stockholm starts at local midnight. Times of day annotated
+//! with [`Zone::Utc`] stay in UTC, and timestamps stay the instants they are.
+
+use chrono::{DateTime, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
+
+use crate::{
+    Boundary, Time,
+    exact::{ExactDateTime, Zone},
+    month::Month,
+    relative::Relative,
+    weekday::{Weekday, WeekdayTime},
+};
+
+/// Runs a UTC conversion on the wall clock of the reference time's zone.
+pub(crate) fn on_wall_clock<Tz: TimeZone>(
+    relative_to: &DateTime<Tz>,
+    convert: impl FnOnce(DateTime<Utc>) -> DateTime<Utc>,
+) -> DateTime<Tz> {
+    let wall_clock = convert(relative_to.naive_local().and_utc());
+
+    from_wall_clock(&relative_to.timezone(), wall_clock.naive_utc())
+}
+
+/// The instant showing the wall clock time in the zone.
+///
+/// Ambiguous times, repeated when clocks go back, resolve to the earlier instant. Skipped times,
+/// when clocks go forward, resolve as if the clocks hadn't changed yet, landing after the gap.
+fn from_wall_clock<Tz: TimeZone>(tz: &Tz, wall_clock: NaiveDateTime) -> DateTime<Tz> {
+    match tz.from_local_datetime(&wall_clock) {
+        LocalResult::Single(instant) | LocalResult::Ambiguous(instant, _) => instant,
+        LocalResult::None => {
+            let before = tz.offset_from_utc_datetime(&(wall_clock - chrono::Duration::days(1)));
+            let offset = chrono::Duration::seconds(before.fix().local_minus_utc().into());
+
+            tz.from_utc_datetime(&(wall_clock - offset))
+        }
+    }
+}
+
+/// Runs a conversion in UTC when pinned there or to a fixed offset or named zone, which the
+/// conversion applies itself, otherwise on the wall clock.
+fn in_zone<Tz: TimeZone>(
+    relative_to: DateTime<Tz>,
+    zone: Option<Zone>,
+    convert: impl FnOnce(DateTime<Utc>) -> DateTime<Utc>,
+) -> DateTime<Tz> {
+    match zone {
+        Some(Zone::Local) | None => on_wall_clock(&relative_to, convert),
+        Some(_) => convert(relative_to.to_utc()).with_timezone(&relative_to.timezone()),
+    }
+}
+
+impl Time {
+    /// Converts to the earliest possible timestamp, relative to a time in any zone.
+    pub fn to_chrono_min_tz<Tz: TimeZone>(self, relative_to: DateTime<Tz>) -> DateTime<Tz> {
+        match self {
+            Time::Relative(relative) => relative.to_chrono_min_tz(relative_to),
+            Time::WeekdayTime(weekday_time) => weekday_time.to_chrono_min_tz(relative_to),
+            Time::Exact(exact) => exact.to_chrono_min_tz(relative_to),
+            Time::DateTime(date_time) => date_time.with_timezone(&relative_to.timezone()),
+            Time::Boundary(boundary) => boundary.to_chrono_tz(relative_to),
+            Time::At(at) => in_zone(relative_to, at.time().zone(), |relative_to| {
+                Time::At(at).to_chrono_min(relative_to)
+            }),
+            time => on_wall_clock(&relative_to, |relative_to| time.to_chrono_min(relative_to)),
+        }
+    }
+
+    /// Converts to the latest possible timestamp, relative to a time in any zone.
+    pub fn to_chrono_max_tz<Tz: TimeZone>(self, relative_to: DateTime<Tz>) -> DateTime<Tz> {
+        match self {
+            Time::Relative(relative) => relative.to_chrono_max_tz(relative_to),
+            Time::WeekdayTime(weekday_time) => weekday_time.to_chrono_max_tz(relative_to),
+            Time::Exact(exact) => exact.to_chrono_max_tz(relative_to),
+            Time::DateTime(date_time) => date_time.with_timezone(&relative_to.timezone()),
+            Time::Boundary(boundary) => boundary.to_chrono_tz(relative_to),
+            Time::At(at) => in_zone(relative_to, at.time().zone(), |relative_to| {
+                Time::At(at).to_chrono_max(relative_to)
+            }),
+            time => on_wall_clock(&relative_to, |relative_to| time.to_chrono_max(relative_to)),
+        }
+    }
+}
+
+impl Boundary {
+    /// Converts to the boundary instant, relative to a time in any zone.
+    pub fn to_chrono_tz<Tz: TimeZone>(self, relative_to: DateTime<Tz>) -> DateTime<Tz> {
+        match self {
+            Boundary::StartOf(time) => time.to_chrono_min_tz(relative_to),
+            Boundary::EndOf(time) => time.to_chrono_max_tz(relative_to),
+        }
+    }
+}
+
+impl Relative {
+    fn zone(&self) -> Option<Zone> {
+        match self {
+            Relative::Time(time) => time.zone(),
+            Relative::DateTime(date_time) => date_time.time().zone(),
+            _ => None,
+        }
+    }
+
+    /// Converts to the earliest possible timestamp, relative to a time in any zone.
+    pub fn to_chrono_min_tz<Tz: TimeZone>(self, relative_to: DateTime<Tz>) -> DateTime<Tz> {
+        in_zone(relative_to, self.zone(), |relative_to| {
+            self.to_chrono_min(relative_to)
+        })
+    }
+
+    /// Converts to the latest possible timestamp, relative to a time in any zone.
+    pub fn to_chrono_max_tz<Tz: TimeZone>(self, relative_to: DateTime<Tz>) -> DateTime<Tz> {
+        in_zone(relative_to, self.zone(), |relative_to| {
+            self.to_chrono_max(relative_to)
+        })
+    }
+}
+
+impl Weekday {
+    /// Converts to the earliest timestamp for this weekday, relative to a time in any zone.
+    pub fn to_chrono_min_tz<Tz: TimeZone>(
+        self,
+        relative_to: DateTime<Tz>,
+        skip_self: bool,
+    ) -> DateTime<Tz> {
+        on_wall_clock(&relative_to, |relative_to| {
+            self.to_chrono_min(relative_to, skip_self)
+        })
+    }
+
+    /// Converts to midnight after this weekday, relative to a time in any zone.
+    pub fn to_chrono_max_tz<Tz: TimeZone>(
+        self,
+        relative_to: DateTime<Tz>,
+        skip_self: bool,
+    ) -> DateTime<Tz> {
+        on_wall_clock(&relative_to, |relative_to| {
+            self.to_chrono_max(relative_to, skip_self)
+        })
+    }
+}
+
+impl WeekdayTime {
+    /// Converts to the next occurrence, relative to a time in any zone.
+    pub fn to_chrono_min_tz<Tz: TimeZone>(self, relative_to: DateTime<Tz>) -> DateTime<Tz> {
+        in_zone(relative_to, self.time().zone(), |relative_to| {
+            self.to_chrono_min(relative_to)
+        })
+    }
+
+    /// Converts to the next occurrence, relative to a time in any zone.
+    pub fn to_chrono_max_tz<Tz: TimeZone>(self, relative_to: DateTime<Tz>) -> DateTime<Tz> {
+        in_zone(relative_to, self.time().zone(), |relative_to| {
+            self.to_chrono_max(relative_to)
+        })
+    }
+}
+
+impl Month {
+    /// Converts to midnight starting the month, relative to a time in any zone.
+    pub fn to_chrono_min_tz<Tz: TimeZone>(
+        self,
+        relative_to: DateTime<Tz>,
+        skip_self: bool,
+    ) -> DateTime<Tz> {
+        on_wall_clock(&relative_to, |relative_to| {
+            self.to_chrono_min(relative_to, skip_self)
+        })
+    }
+
+    /// Converts to midnight after the month, relative to a time in any zone.
+    pub fn to_chrono_max_tz<Tz: TimeZone>(
+        self,
+        relative_to: DateTime<Tz>,
+        skip_self: bool,
+    ) -> DateTime<Tz> {
+        on_wall_clock(&relative_to, |relative_to| {
+            self.to_chrono_max(relative_to, skip_self)
+        })
+    }
+}
+
+impl ExactDateTime {
+    /// Converts to the timestamp in the reference year, relative to a time in any zone.
+    pub fn to_chrono_min_tz<Tz: TimeZone>(self, relative_to: DateTime<Tz>) -> DateTime<Tz> {
+        in_zone(relative_to, self.time().zone(), |relative_to| {
+            self.to_chrono_min(relative_to)
+        })
+    }
+
+    /// Converts to the next occurrence's timestamp, relative to a time in any zone.
+    pub fn to_chrono_max_tz<Tz: TimeZone>(self, relative_to: DateTime<Tz>) -> DateTime<Tz> {
+        in_zone(relative_to, self.time().zone(), |relative_to| {
+            self.to_chrono_max(relative_to)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+
+    use super::*;
+    use crate::exact::{ExactDate, ExactTime};
+
+    fn stockholm(s: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn resolves_on_wall_clock() {
+        // 00:30 on Wednesday in Stockholm, still Tuesday in UTC
+        let relative_to = stockholm("2025-07-30T00:30:00+02:00");
+
+        let today = Time::Relative(Relative::today());
+        assert_eq!(
+            today.clone().to_chrono_min_tz(relative_to),
+            stockholm("2025-07-30T00:00:00+02:00")
+        );
+        assert_eq!(
+            today.to_chrono_max_tz(relative_to),
+            stockholm("2025-07-31T00:00:00+02:00")
+        );
+
+        let friday = Time::Weekday(Weekday::friday());
+        assert_eq!(
+            friday.to_chrono_max_tz(relative_to),
+            stockholm("2025-08-02T00:00:00+02:00")
+        );
+    }
+
+    #[test]
+    fn keeps_utc_and_instants() {
+        let relative_to = stockholm("2025-07-30T00:30:00+02:00");
+
+        let nine_utc = Time::Relative(Relative::Time(
+            ExactTime::new(9, 0, None).with_zone(Zone::Utc),
+        ));
+        assert_eq!(
+            nine_utc.to_chrono_min_tz(relative_to),
+            stockholm("2025-07-29T11:00:00+02:00")
+        );
+
+        let nine_local = Time::Exact(ExactDateTime::new(
+            ExactDate::new(Some(2025), 7, 30),
+            ExactTime::new(9, 0, None).with_zone(Zone::Local),
+        ));
+        assert_eq!(
+            nine_local.to_chrono_min_tz(relative_to),
+            stockholm("2025-07-30T09:00:00+02:00")
+        );
+
+        let instant = Time::DateTime(relative_to.to_utc());
+        assert_eq!(instant.to_chrono_min_tz(relative_to), relative_to);
+    }
+
+    #[test]
+    fn keeps_fixed_offsets() {
+        let relative_to = stockholm("2025-07-30T00:30:00+02:00");
+        let new_york = Zone::from_offset(FixedOffset::west_opt(4 * 3600).unwrap());
+
+        let nine_in_new_york = Time::Exact(ExactDateTime::new(
+            ExactDate::new(Some(2025), 7, 30),
+            ExactTime::new(9, 0, None).with_zone(new_york),
+        ));
+        assert_eq!(
+            nine_in_new_york.clone().to_chrono_min_tz(relative_to),
+            stockholm("2025-07-30T15:00:00+02:00")
+        );
+        assert_eq!(
+            nine_in_new_york.to_chrono_max(relative_to.to_utc()),
+            stockholm("2025-07-30T13:00:00Z")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz")]
+    fn keeps_named_zones() {
+        use alloc::string::ToString;
+
+        let relative_to = stockholm("2025-07-30T00:30:00+02:00");
+        let at = |s: &str| s.parse::<Time>().unwrap();
+
+        // Summer and winter offsets follow the zone's rules
+        assert_eq!(
+            at("2025-07-29 10:30 Europe/Stockholm").to_chrono_min(relative_to.to_utc()),
+            stockholm("2025-07-29T08:30:00Z")
+        );
+        assert_eq!(
+            at("2025-01-15 10:30 europe/stockholm").to_chrono_min(relative_to.to_utc()),
+            stockholm("2025-01-15T09:30:00Z")
+        );
+
+        // Still Tuesday evening in New York, so 21:00 there is later today
+        let nine_pm = at("21:00 America/New_York");
+        assert_eq!(
+            nine_pm.clone().to_chrono_min_tz(relative_to),
+            stockholm("2025-07-30T03:00:00+02:00")
+        );
+        assert_eq!(nine_pm.to_string(), "21:00 America/New_York");
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&nine_pm).unwrap();
+            assert_eq!(serde_json::from_str::<Time>(&json).unwrap(), nine_pm);
+        }
+    }
+}
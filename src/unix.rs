@@ -0,0 +1,92 @@
+//! Conversions through unix timestamps and `std::time::SystemTime`, for callers keeping chrono
+//! out of their own interfaces.
+
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+use chrono::{DateTime, Utc};
+
+use crate::{Time, language::Language};
+
+/// The instant the given number of seconds after the unix epoch, clamped to the range chrono
+/// represents.
+fn from_unix(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or(if secs < 0 {
+        DateTime::<Utc>::MIN_UTC
+    } else {
+        DateTime::<Utc>::MAX_UTC
+    })
+}
+
+impl Time {
+    /// Converts to the earliest possible timestamp in seconds since the unix epoch, relative to a
+    /// time given the same way.
+    pub fn to_unix_min(self, relative_to: i64) -> i64 {
+        self.to_chrono_min(from_unix(relative_to)).timestamp()
+    }
+
+    /// Converts to the latest possible timestamp in seconds since the unix epoch, relative to a
+    /// time given the same way.
+    pub fn to_unix_max(self, relative_to: i64) -> i64 {
+        self.to_chrono_max(from_unix(relative_to)).timestamp()
+    }
+
+    /// Converts a timestamp in seconds since the unix epoch to the most natural time
+    /// representation, like [`Time::from_max_chrono`].
+    pub fn from_unix_max(secs: i64, relative_to: Option<i64>, language: Language) -> Time {
+        Time::from_max_chrono(from_unix(secs), relative_to.map(from_unix), language)
+    }
+
+    /// Converts to the earliest possible timestamp, relative to the given time.
+    #[cfg(feature = "std")]
+    pub fn to_system_time_min(self, relative_to: SystemTime) -> SystemTime {
+        self.to_chrono_min(relative_to.into()).into()
+    }
+
+    /// Converts to the latest possible timestamp, relative to the given time.
+    #[cfg(feature = "std")]
+    pub fn to_system_time_max(self, relative_to: SystemTime) -> SystemTime {
+        self.to_chrono_max(relative_to.into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relative::Relative;
+
+    // Tuesday July 29th, 2025 10:30:05 UTC
+    const RELATIVE_TO: i64 = 1_753_785_005;
+
+    #[test]
+    fn converts_unix_timestamps() {
+        let tomorrow = Time::Relative(Relative::tomorrow());
+
+        // Midnight starting and ending July 30th
+        assert_eq!(tomorrow.clone().to_unix_min(RELATIVE_TO), 1_753_833_600);
+        assert_eq!(tomorrow.clone().to_unix_max(RELATIVE_TO), 1_753_920_000);
+        assert_eq!(
+            Time::from_unix_max(1_753_920_000, Some(RELATIVE_TO), Language::default()),
+            tomorrow
+        );
+        assert_eq!(
+            Time::from_unix_max(1_753_920_000, None, Language::default()),
+            Time::DateTime(from_unix(1_753_920_000))
+        );
+        assert_eq!(from_unix(i64::MIN), DateTime::<Utc>::MIN_UTC);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn converts_system_times() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let relative_to = UNIX_EPOCH + Duration::from_secs(RELATIVE_TO as u64);
+        let tomorrow = Time::Relative(Relative::tomorrow());
+
+        assert_eq!(
+            tomorrow.to_system_time_min(relative_to),
+            UNIX_EPOCH + Duration::from_secs(1_753_833_600)
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Deserialisation of the untagged [`Time`] with descriptive errors.
+//!
+//! Serde reports untagged enums matching no variant as "data did not match any variant", so
+//! [`Time`] deserialises through a mirror with catch-all variants and describes the failure,
+//! suggesting the closest keyword when one is near. Objects and arrays are kept and deserialised
+//! again as the value their shape suggests, surfacing its error, e.g. "'30/2/2025' does not
+//! exist".
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{
+    Deserialize, Deserializer,
+    de::{
+        self, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+        value::{Error as ValueError, MapAccessDeserializer, MapDeserializer, SeqDeserializer},
+    },
+    forward_to_deserialize_any,
+};
+
+use crate::{
+    At, Boundary, Time,
+    exact::{ExactDate, ExactDateTime, ExactTime},
+    month::Month,
+    named::NamedTime,
+    ordinal::OrdinalWeekday,
+    parse::suggest,
+    relative::Relative,
+    weekday::{LastWeekday, Weekday, WeekdayTime},
+};
+
+/// Mirrors [`Time`], followed by variants catching anything else.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    Relative(Relative),
+    Weekday(Weekday),
+    WeekdayTime(WeekdayTime),
+    Month(Month),
+    Exact(ExactDateTime),
+    DateTime(DateTime<Utc>),
+    Named(NamedTime),
+    OrdinalWeekday(OrdinalWeekday),
+    Boundary(Boundary),
+    At(At),
+    LastWeekday(LastWeekday),
+    Text(String),
+    Other(Value),
+}
+
+/// Any self-describing value, kept to deserialise again when it matches no variant.
+#[derive(Clone)]
+enum Value {
+    Unit,
+    Bool(bool),
+    Signed(i64),
+    Unsigned(u64),
+    Float(f64),
+    Text(String),
+    Seq(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ValueVisitor;
+
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("any value")
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+                Ok(Value::Unit)
+            }
+
+            fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+                Ok(Value::Unit)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+                Value::deserialize(deserializer)
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<Value, E> {
+                Ok(Value::Bool(v))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Value, E> {
+                Ok(Value::Signed(v))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Value, E> {
+                Ok(Value::Unsigned(v))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Value, E> {
+                Ok(Value::Float(v))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Value, E> {
+                Ok(Value::Text(v.to_string()))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+                let mut items = Vec::new();
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+                Ok(Value::Seq(items))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(Value::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+impl<'de> IntoDeserializer<'de, ValueError> for Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for Value {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            Value::Unit => visitor.visit_unit(),
+            Value::Bool(v) => visitor.visit_bool(v),
+            Value::Signed(v) => visitor.visit_i64(v),
+            Value::Unsigned(v) => visitor.visit_u64(v),
+            Value::Float(v) => visitor.visit_f64(v),
+            Value::Text(v) => visitor.visit_string(v),
+            Value::Seq(items) => visitor.visit_seq(SeqDeserializer::new(items.into_iter())),
+            Value::Map(entries) => visitor.visit_map(MapDeserializer::new(entries.into_iter())),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            Value::Unit => visitor.visit_none(),
+            value => visitor.visit_some(value),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        match self {
+            Value::Text(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Value::Map(entries) => visitor.visit_enum(MapAccessDeserializer::new(
+                MapDeserializer::new(entries.into_iter()),
+            )),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Describes an object or array matching no variant by deserialising it again as the value its
+/// shape suggests, e.g. a date for `{"WithYear": [2025, 2, 30]}` or a time of day for `[25, 0]`.
+fn invalid(value: Value) -> String {
+    let error = match &value {
+        Value::Map(entries) => match entries.as_slice() {
+            [(Value::Text(key), _)] if key == "WithYear" || key == "WithoutYear" => {
+                ExactDate::deserialize(value).err()
+            }
+            [(Value::Text(key), _)] if key == "StartOf" || key == "EndOf" => {
+                Boundary::deserialize(value).err()
+            }
+            entries
+                if entries
+                    .iter()
+                    .any(|(key, _)| matches!(key, Value::Text(key) if key == "day")) =>
+            {
+                At::deserialize(value).err()
+            }
+            _ => None,
+        },
+        Value::Seq(items) => match items.first() {
+            Some(Value::Signed(_) | Value::Unsigned(_)) => ExactTime::deserialize(value).err(),
+            Some(Value::Map(_)) => ExactDateTime::deserialize(value).err(),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match error {
+        Some(error) => error.to_string(),
+        None => "expected a time value such as \"Today\", \"Monday\" or a timestamp".into(),
+    }
+}
+
+/// Describes a string matching no variant, e.g. "unknown weekday 'Tuesdy', did you mean
+/// 'Tuesday'?".
+fn unrecognised(text: &str) -> String {
+    let Some(suggestion) = suggest(text).into_iter().next() else {
+        return format!("unrecognised time value '{text}'");
+    };
+
+    let kind = match suggestion {
+        Time::Weekday(_) => "weekday",
+        Time::Month(_) => "month",
+        _ => "keyword",
+    };
+
+    format!("unknown {kind} '{text}', did you mean '{suggestion}'?")
+}
+
+impl<'de> Deserialize<'de> for Time {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Relative(x) => Time::Relative(x),
+            Repr::Weekday(x) => Time::Weekday(x),
+            Repr::WeekdayTime(x) => Time::WeekdayTime(x),
+            Repr::Month(x) => Time::Month(x),
+            Repr::Exact(x) => Time::Exact(x),
+            Repr::DateTime(x) => Time::DateTime(x),
+            Repr::Named(x) => Time::Named(x),
+            Repr::OrdinalWeekday(x) => Time::OrdinalWeekday(x),
+            Repr::Boundary(x) => Time::Boundary(x),
+            Repr::At(x) => Time::At(x),
+            Repr::LastWeekday(x) => Time::LastWeekday(x),
+            Repr::Text(text) => return Err(de::Error::custom(unrecognised(&text))),
+            Repr::Other(value) => return Err(de::Error::custom(invalid(value))),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn error(json: &str) -> String {
+        serde_json::from_str::<Time>(json).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn suggests_close_keywords() {
+        assert_eq!(
+            error(r#""Tuesdy""#),
+            "unknown weekday 'Tuesdy', did you mean 'Tuesday'?"
+        );
+        assert!(error(r#""Agust""#).starts_with("unknown month 'Agust', did you mean 'August'?"));
+        assert!(error(r#""Tomorow""#).starts_with("unknown keyword 'Tomorow'"));
+    }
+
+    #[test]
+    fn describes_other_failures() {
+        assert!(error(r#""Fortnight""#).starts_with("unrecognised time value 'Fortnight'"));
+        assert!(error("[]").starts_with("expected a time value"));
+        assert!(error(r#"{"WithYear": [2025, 2, 30]}"#).starts_with("'30/2/2025' does not exist"));
+        assert!(error("[25, 0]").starts_with("hour 25 is not between 0 and 23"));
+        assert!(error(r#"[{"WithoutYear": [13, 1]}, [9, 0]]"#).starts_with("month 13"));
+        assert!(error(r#"{"EndOf": [9, 61]}"#).starts_with("minute 61 is not between 0 and 59"));
+        assert!(
+            error(r#"{"day": "Tomorrow", "time": [9, 0, 60]}"#)
+                .starts_with("second 60 is not between 0 and 59")
+        );
+        assert_eq!(
+            serde_json::from_str::<Time>(r#""Tuesday""#).unwrap(),
+            Time::Weekday(Weekday::tuesday())
+        );
+    }
+}
@@ -0,0 +1,265 @@
+//! ISO week numbers, such as "week 32" or "vecka 32".
+
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::{fmt, str::FromStr};
+
+use chrono::{DateTime, Datelike, Days, NaiveDate, NaiveTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::parse::ParseError;
+
+/// Words introducing a week number, e.g. "week 32", "vecka 32" or "v. 32", longest first.
+const PREFIXES: &[&str] = &["vecka", "week", "v.", "w", "v"];
+
+/// An ISO week, Monday to Monday, optionally in a given week-numbering year.
+///
+/// Without a year, resolves to the current week when its number matches and the next week with
+/// the number otherwise, skipping years without a 53rd week. With a year lacking a 53rd week,
+/// week 53 is its last week. Serialises as `"W32"` or `"2025-W32"`, deserialises from
+/// `{"week": 32, "year": 2025}` too, and parses from e.g. `"week 32"` or `"vecka 32"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekNumber {
+    week: u8,
+    year: Option<i32>,
+}
+
+impl WeekNumber {
+    /// Creates a week, clamping the number to 1-53.
+    pub fn new(week: u8, year: Option<i32>) -> Self {
+        Self {
+            week: week.clamp(1, 53),
+            year,
+        }
+    }
+
+    pub fn week(&self) -> u8 {
+        self.week
+    }
+
+    pub fn year(&self) -> Option<i32> {
+        self.year
+    }
+
+    /// Parses a week written as `"2025-W32"`, or a number introduced by a word like `"week"`.
+    pub(crate) fn parse(input: &str) -> Option<Self> {
+        let input: String = input.split_whitespace().collect::<String>().to_lowercase();
+
+        let (year, rest) = match input.split_once("-w") {
+            Some((year, week)) if year.len() == 4 => (Some(year.parse().ok()?), week),
+            Some(_) => return None,
+            None => (None, input.as_str()),
+        };
+
+        let digits = match year {
+            Some(_) => rest,
+            None => PREFIXES
+                .iter()
+                .find_map(|prefix| rest.strip_prefix(prefix))?,
+        };
+
+        if digits.is_empty() || digits.len() > 2 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let week = digits.parse().ok().filter(|week| (1..=53).contains(week))?;
+
+        Some(Self { week, year })
+    }
+
+    /// The Monday starting the week, relative to the given date.
+    fn start(self, today: NaiveDate) -> NaiveDate {
+        let monday =
+            |year, week: u8| NaiveDate::from_isoywd_opt(year, week.into(), chrono::Weekday::Mon);
+
+        match self.year {
+            Some(year) => monday(year, self.week)
+                .or_else(|| monday(year, 52))
+                .unwrap(),
+            None => (today.iso_week().year()..)
+                .filter_map(|year| monday(year, self.week))
+                .find(|monday| *monday + Days::new(7) > today)
+                .unwrap(),
+        }
+    }
+
+    /// Converts to midnight starting the Monday of the week, relative to the given time.
+    pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.start(relative_to.date_naive())
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+    }
+
+    /// Converts to midnight starting the Monday after the week, relative to the given time.
+    pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_min(relative_to) + Days::new(7)
+    }
+}
+
+impl fmt::Display for WeekNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.year {
+            Some(year) => write!(f, "{year}-W{:02}", self.week),
+            None => write!(f, "W{}", self.week),
+        }
+    }
+}
+
+impl FromStr for WeekNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| ParseError::Unrecognised(s.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for WeekNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// The accepted shapes of a serialised [`WeekNumber`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr<'a> {
+    #[serde(borrow)]
+    Text(Cow<'a, str>),
+    Object {
+        week: u8,
+        #[serde(default)]
+        year: Option<i32>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for WeekNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Repr::deserialize(deserializer)? {
+            Repr::Text(s) => s.parse().map_err(de::Error::custom),
+            Repr::Object { week, year } if (1..=53).contains(&week) => Ok(Self { week, year }),
+            Repr::Object { week, .. } => Err(de::Error::custom(format_args!(
+                "week {week} is out of range 1-53"
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for WeekNumber {
+    fn schema_name() -> Cow<'static, str> {
+        "WeekNumber".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "An ISO week, optionally in a given year, e.g. \"W32\" or \"2025-W32\".",
+            "anyOf": [
+                {
+                    "type": "string",
+                    "pattern": "^([0-9]{4}-W[0-9]{2}|W[0-9]{1,2})$",
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "week": { "type": "integer", "minimum": 1, "maximum": 53 },
+                        "year": { "type": "integer" },
+                    },
+                    "required": ["week"],
+                },
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn resolves_weeks() {
+        // Tuesday July 29th, 2025, in week 31
+        let relative_to = at("2025-07-29T10:30:05Z");
+        let window = |week: WeekNumber| {
+            (
+                week.to_chrono_min(relative_to),
+                week.to_chrono_max(relative_to),
+            )
+        };
+
+        assert_eq!(
+            window(WeekNumber::new(31, None)),
+            (at("2025-07-28T00:00:00Z"), at("2025-08-04T00:00:00Z"))
+        );
+        assert_eq!(
+            window(WeekNumber::new(32, None)),
+            (at("2025-08-04T00:00:00Z"), at("2025-08-11T00:00:00Z"))
+        );
+        assert_eq!(
+            WeekNumber::new(30, None).to_chrono_min(relative_to),
+            at("2026-07-20T00:00:00Z")
+        );
+        assert_eq!(
+            WeekNumber::new(1, Some(2026)).to_chrono_min(relative_to),
+            at("2025-12-29T00:00:00Z")
+        );
+
+        // 2025 has no week 53, 2026 does
+        assert_eq!(
+            WeekNumber::new(53, None).to_chrono_min(relative_to),
+            at("2026-12-28T00:00:00Z")
+        );
+        assert_eq!(
+            WeekNumber::new(53, Some(2025)).to_chrono_min(relative_to),
+            at("2025-12-22T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn formats_and_parses() {
+        let week = WeekNumber::new(32, None);
+        let with_year = WeekNumber::new(5, Some(2025));
+
+        assert_eq!(week.to_string(), "W32");
+        assert_eq!(with_year.to_string(), "2025-W05");
+        assert_eq!("W32".parse(), Ok(week));
+        assert_eq!("week 32".parse(), Ok(week));
+        assert_eq!("vecka 32".parse(), Ok(week));
+        assert_eq!("v.32".parse(), Ok(week));
+        assert_eq!("2025-W05".parse(), Ok(with_year));
+        assert!("week 54".parse::<WeekNumber>().is_err());
+        assert!("weekend".parse::<WeekNumber>().is_err());
+        assert!("2025-W31-2".parse::<WeekNumber>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serializes() {
+        let week = WeekNumber::new(32, Some(2025));
+
+        assert_eq!(serde_json::to_string(&week).unwrap(), r#""2025-W32""#);
+        assert_eq!(
+            serde_json::from_str::<WeekNumber>(r#""2025-W32""#).unwrap(),
+            week
+        );
+        assert_eq!(
+            serde_json::from_str::<WeekNumber>(r#"{"week":32,"year":2025}"#).unwrap(),
+            week
+        );
+        assert_eq!(
+            serde_json::from_str::<WeekNumber>(r#"{"week":32}"#).unwrap(),
+            WeekNumber::new(32, None)
+        );
+        assert!(serde_json::from_str::<WeekNumber>(r#"{"week":0}"#).is_err());
+    }
+}
@@ -1,158 +1,75 @@
 //! Weekday representations with language support.
 
+#[cfg(feature = "serde")]
+use alloc::borrow::Cow;
+#[cfg(feature = "schemars")]
+use alloc::{format, string::String};
+use alloc::{string::ToString, vec::Vec};
+use core::{fmt, str::FromStr};
+
 use chrono::{DateTime, Datelike, Days, NaiveTime, Utc};
 use derive_more::Display;
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
 use crate::{
+    OutOfRange, Time,
+    exact::{ExactTime, in_offset},
     language::Language,
+    macros::keywords,
+    parse::{ParseError, parse_last_weekday, parse_time, parse_zone, vocabulary},
     traits::{FromLanguage, WithLanguage},
 };
 
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum Monday {
-    #[default]
-    Monday,
-    #[cfg(feature = "swedish")]
-    Måndag,
-}
-
-impl WithLanguage for Monday {
-    fn with_language(&self, langue: Language) -> Self {
-        match langue {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Måndag,
-            Language::English(_) => Self::Monday,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum Tuesday {
-    #[default]
-    Tuesday,
-    #[cfg(feature = "swedish")]
-    Tisdag,
-}
-
-impl WithLanguage for Tuesday {
-    fn with_language(&self, langue: Language) -> Self {
-        match langue {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Tisdag,
-            Language::English(_) => Self::Tuesday,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum Wednesday {
-    #[default]
-    Wednesday,
-    #[cfg(feature = "swedish")]
-    Onsdag,
-}
-
-impl WithLanguage for Wednesday {
-    fn with_language(&self, langue: Language) -> Self {
-        match langue {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Onsdag,
-            Language::English(_) => Self::Wednesday,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum Thursday {
-    #[default]
-    Thursday,
-    #[cfg(feature = "swedish")]
-    Torsdag,
-}
-
-impl WithLanguage for Thursday {
-    fn with_language(&self, langue: Language) -> Self {
-        match langue {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Torsdag,
-            Language::English(_) => Self::Thursday,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum Friday {
-    #[default]
-    Friday,
-    #[cfg(feature = "swedish")]
-    Fredag,
-}
-
-impl WithLanguage for Friday {
-    fn with_language(&self, langue: Language) -> Self {
-        match langue {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Fredag,
-            Language::English(_) => Self::Friday,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum Saturday {
-    #[default]
-    Saturday,
-    #[cfg(feature = "swedish")]
-    Lördag,
-}
-
-impl WithLanguage for Saturday {
-    fn with_language(&self, langue: Language) -> Self {
-        match langue {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Lördag,
-            Language::English(_) => Self::Saturday,
-        }
-    }
-}
-
-#[derive(
-    Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display,
-)]
-pub enum Sunday {
-    #[default]
-    Sunday,
-    #[cfg(feature = "swedish")]
-    Söndag,
-}
-
-impl WithLanguage for Sunday {
-    fn with_language(&self, langue: Language) -> Self {
-        match langue {
-            #[cfg(feature = "swedish")]
-            Language::Swedish(_) => Self::Söndag,
-            Language::English(_) => Self::Sunday,
-        }
-    }
+keywords! {
+    Monday as "Mon" {
+        "swedish": Måndag as "Mån",
+        "norwegian" | "danish": Mandag as "Man",
+        "spanish": Lunes,
+        "french": Lundi,
+    },
+    Tuesday as "Tue" {
+        "swedish": Tisdag as "Tis",
+        "norwegian" | "danish": Tirsdag as "Tir",
+        "spanish": Martes,
+        "french": Mardi,
+    },
+    Wednesday as "Wed" {
+        "swedish" | "norwegian" | "danish": Onsdag as "Ons",
+        "spanish": Miércoles,
+        "french": Mercredi,
+    },
+    Thursday as "Thu" {
+        "swedish" | "norwegian" | "danish": Torsdag as "Tor",
+        "spanish": Jueves,
+        "french": Jeudi,
+    },
+    Friday as "Fri" {
+        "swedish" | "norwegian" | "danish": Fredag as "Fre",
+        "spanish": Viernes,
+        "french": Vendredi,
+    },
+    Saturday as "Sat" {
+        "swedish": Lördag as "Lör",
+        "norwegian" | "danish": Lørdag as "Lør",
+        "spanish": Sábado,
+        "french": Samedi,
+    },
+    Sunday as "Sun" {
+        "swedish": Söndag as "Sön",
+        "norwegian" | "danish": Søndag as "Søn",
+        "spanish": Domingo,
+        "french": Dimanche,
+    },
 }
 
 /// A weekday with language-specific representations.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Display)]
-#[serde(untagged)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Weekday {
     Monday(Monday),
     Tuesday(Tuesday),
@@ -178,26 +95,26 @@ impl WithLanguage for Weekday {
 }
 
 impl Weekday {
-    pub fn monday() -> Self {
-        Self::Monday(Monday::default())
+    pub const fn monday() -> Self {
+        Self::Monday(Monday::Monday)
     }
-    pub fn tuesday() -> Self {
-        Self::Tuesday(Tuesday::default())
+    pub const fn tuesday() -> Self {
+        Self::Tuesday(Tuesday::Tuesday)
     }
-    pub fn wednesday() -> Self {
-        Self::Wednesday(Wednesday::default())
+    pub const fn wednesday() -> Self {
+        Self::Wednesday(Wednesday::Wednesday)
     }
-    pub fn thursday() -> Self {
-        Self::Thursday(Thursday::default())
+    pub const fn thursday() -> Self {
+        Self::Thursday(Thursday::Thursday)
     }
-    pub fn friday() -> Self {
-        Self::Friday(Friday::default())
+    pub const fn friday() -> Self {
+        Self::Friday(Friday::Friday)
     }
-    pub fn saturday() -> Self {
-        Self::Saturday(Saturday::default())
+    pub const fn saturday() -> Self {
+        Self::Saturday(Saturday::Saturday)
     }
-    pub fn sunday() -> Self {
-        Self::Sunday(Sunday::default())
+    pub const fn sunday() -> Self {
+        Self::Sunday(Sunday::Sunday)
     }
     /// Converts to a chrono weekday.
     pub fn to_chrono(self) -> chrono::Weekday {
@@ -212,6 +129,37 @@ impl Weekday {
         }
     }
 
+    /// The number of the weekday, from 1 for Monday to 7 for Sunday.
+    pub const fn number_from_monday(self) -> u8 {
+        match self {
+            Weekday::Monday(_) => 1,
+            Weekday::Tuesday(_) => 2,
+            Weekday::Wednesday(_) => 3,
+            Weekday::Thursday(_) => 4,
+            Weekday::Friday(_) => 5,
+            Weekday::Saturday(_) => 6,
+            Weekday::Sunday(_) => 7,
+        }
+    }
+
+    /// The number of the weekday, from 1 for Sunday to 7 for Saturday.
+    pub const fn number_from_sunday(self) -> u8 {
+        self.number_from_monday() % 7 + 1
+    }
+
+    /// Converts from a chrono weekday in the specified language.
+    pub fn from_chrono_weekday(weekday: chrono::Weekday, language: Language) -> Self {
+        match weekday {
+            chrono::Weekday::Mon => Self::Monday(Monday::from_language(language)),
+            chrono::Weekday::Tue => Self::Tuesday(Tuesday::from_language(language)),
+            chrono::Weekday::Wed => Self::Wednesday(Wednesday::from_language(language)),
+            chrono::Weekday::Thu => Self::Thursday(Thursday::from_language(language)),
+            chrono::Weekday::Fri => Self::Friday(Friday::from_language(language)),
+            chrono::Weekday::Sat => Self::Saturday(Saturday::from_language(language)),
+            chrono::Weekday::Sun => Self::Sunday(Sunday::from_language(language)),
+        }
+    }
+
     /// Extracts the weekday from a timestamp in the specified language.
     ///
     /// When `midnight_means_day_before` is true, midnight timestamps are treated
@@ -278,4 +226,337 @@ impl Weekday {
             .with_time(NaiveTime::MIN)
             .unwrap()
     }
+
+    /// Midnight starting each occurrence of this weekday that has not ended by the given time, in
+    /// order.
+    ///
+    /// The reference day is included when it matches.
+    pub fn occurrences(self, relative_to: DateTime<Utc>) -> impl Iterator<Item = DateTime<Utc>> {
+        let first = self
+            .to_chrono_max(relative_to, false)
+            .checked_sub_days(Days::new(1));
+
+        core::iter::successors(first, |previous| previous.checked_add_days(Days::new(7)))
+    }
+
+    /// Converts to midnight starting the most recent occurrence of this weekday, relative to the
+    /// given time.
+    ///
+    /// When `skip_self` is true, finds the previous occurrence even if the current day matches.
+    pub fn to_chrono_min_past(self, relative_to: DateTime<Utc>, skip_self: bool) -> DateTime<Utc> {
+        let days_since = relative_to.weekday().days_since(self.to_chrono());
+        let days_to_subtract = if days_since == 0 && skip_self {
+            7
+        } else {
+            days_since
+        };
+
+        relative_to
+            .checked_sub_days(Days::new(days_to_subtract.into()))
+            .unwrap()
+            .with_time(NaiveTime::MIN)
+            .unwrap()
+    }
+
+    /// Converts to the latest timestamp of the most recent occurrence of this weekday, relative
+    /// to the given time.
+    ///
+    /// When `skip_self` is true, finds the previous occurrence even if the current day matches.
+    pub fn to_chrono_max_past(self, relative_to: DateTime<Utc>, skip_self: bool) -> DateTime<Utc> {
+        self.to_chrono_min_past(relative_to, skip_self)
+            .checked_add_days(Days::new(1))
+            .unwrap()
+            .min(relative_to)
+    }
+
+    /// Time left until this weekday next starts, as with [`Weekday::to_chrono_min`], negative once it
+    /// has started.
+    pub fn time_until(self, relative_to: DateTime<Utc>, skip_self: bool) -> chrono::Duration {
+        self.to_chrono_min(relative_to, skip_self) - relative_to
+    }
+
+    /// Time elapsed since this weekday last started, as with [`Weekday::to_chrono_min_past`].
+    pub fn time_since(self, relative_to: DateTime<Utc>, skip_self: bool) -> chrono::Duration {
+        relative_to - self.to_chrono_min_past(relative_to, skip_self)
+    }
+}
+
+impl From<chrono::Weekday> for Weekday {
+    fn from(weekday: chrono::Weekday) -> Self {
+        Self::from_chrono_weekday(weekday, Language::default())
+    }
+}
+
+impl From<Weekday> for chrono::Weekday {
+    fn from(weekday: Weekday) -> Self {
+        weekday.to_chrono()
+    }
+}
+
+impl From<Weekday> for u8 {
+    fn from(weekday: Weekday) -> Self {
+        weekday.number_from_monday()
+    }
+}
+
+/// Numbers weekdays from 1 for Monday to 7 for Sunday.
+impl TryFrom<u8> for Weekday {
+    type Error = OutOfRange;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        match number {
+            1 => Ok(Self::monday()),
+            2 => Ok(Self::tuesday()),
+            3 => Ok(Self::wednesday()),
+            4 => Ok(Self::thursday()),
+            5 => Ok(Self::friday()),
+            6 => Ok(Self::saturday()),
+            7 => Ok(Self::sunday()),
+            _ => Err(OutOfRange(number)),
+        }
+    }
+}
+
+/// A weekday at a time of day, e.g. `"Monday 14:00"` or `"Fredag 09:30"`.
+///
+/// Serialises as a single string in the weekday's language, with the time zero-padded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekdayTime(Weekday, ExactTime);
+
+impl WithLanguage for WeekdayTime {
+    fn with_language(&self, language: Language) -> Self {
+        Self(self.0.with_language(language), self.1)
+    }
+}
+
+impl WeekdayTime {
+    pub const fn new(weekday: Weekday, time: ExactTime) -> Self {
+        Self(weekday, time.validated())
+    }
+
+    pub const fn weekday(&self) -> Weekday {
+        self.0
+    }
+
+    pub const fn time(&self) -> ExactTime {
+        self.1
+    }
+
+    /// Converts to the next occurrence of the weekday at the time, relative to the given time.
+    ///
+    /// As with [`Time::Weekday`], the reference day is skipped even when it matches, so
+    /// "next tuesday at 3pm" on a Tuesday morning is a week away.
+    pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        in_offset(self.1.zone(), relative_to, |relative_to| {
+            self.0
+                .to_chrono_max(relative_to, true)
+                .checked_sub_days(Days::new(1))
+                .unwrap()
+                .with_time(self.1.to_chrono())
+                .unwrap()
+        })
+    }
+
+    /// Converts to the next occurrence of the weekday at the time, relative to the given time.
+    ///
+    /// Identical to [`WeekdayTime::to_chrono_min`], as the value is a single instant.
+    pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.to_chrono_min(relative_to)
+    }
+}
+
+impl FromStr for WeekdayTime {
+    type Err = ParseError;
+
+    /// Parses a weekday in any enabled language followed by `HH:MM` or `HH:MM:SS`, and
+    /// optionally a zone such as `UTC` or `Local`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unrecognised = || ParseError::Unrecognised(s.to_string());
+        let (weekday, time) = s.trim().split_once(' ').ok_or_else(unrecognised)?;
+
+        let weekday = Language::all()
+            .into_iter()
+            .flat_map(vocabulary)
+            .find_map(|time| match time {
+                Time::Weekday(candidate) if candidate.to_string() == weekday => Some(candidate),
+                _ => None,
+            })
+            .ok_or_else(unrecognised)?;
+        let time = match time.trim().split_once(' ') {
+            Some((time, zone)) => parse_time(time, false)
+                .zip(parse_zone(zone.trim()))
+                .map(|(time, zone)| time.with_zone(zone)),
+            None => parse_time(time.trim(), false),
+        };
+
+        Ok(Self(weekday, time.ok_or_else(unrecognised)?))
+    }
+}
+
+impl fmt::Display for WeekdayTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:02}:{:02}", self.0, self.1.hour(), self.1.minute())?;
+
+        if self.1.has_second() {
+            write!(f, ":{:02}", self.1.second())?;
+        }
+
+        match self.1.zone() {
+            Some(zone) => write!(f, " {zone}"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for WeekdayTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for WeekdayTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for WeekdayTime {
+    fn schema_name() -> Cow<'static, str> {
+        "WeekdayTime".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let weekdays: Vec<String> = Language::all()
+            .into_iter()
+            .flat_map(|language| {
+                [
+                    Weekday::monday(),
+                    Weekday::tuesday(),
+                    Weekday::wednesday(),
+                    Weekday::thursday(),
+                    Weekday::friday(),
+                    Weekday::saturday(),
+                    Weekday::sunday(),
+                ]
+                .map(|weekday| weekday.with_language(language).to_string())
+            })
+            .collect();
+
+        json_schema!({
+            "description": "A weekday at a time of day, e.g. \"Monday 14:00\".",
+            "type": "string",
+            "pattern": format!(
+                "^({}) ([01]?[0-9]|2[0-3]):[0-5][0-9](:[0-5][0-9])?( UTC| Local)?$",
+                weekdays.join("|")
+            ),
+        })
+    }
+}
+
+/// The word marking the most recent occurrence of a weekday in the given language, and whether it
+/// follows the weekday, e.g. "Last" in "Last Friday" or "Pasado" in "Viernes Pasado".
+pub(crate) fn last_word(language: Language) -> (&'static str, bool) {
+    match language {
+        #[cfg(feature = "swedish")]
+        Language::Swedish(_) => ("Förra", false),
+        #[cfg(feature = "norwegian")]
+        Language::Norwegian(_) => ("Forrige", false),
+        #[cfg(feature = "danish")]
+        Language::Danish(_) => ("Forrige", false),
+        #[cfg(feature = "spanish")]
+        Language::Spanish(_) => ("Pasado", true),
+        #[cfg(feature = "french")]
+        Language::French(_) => ("Dernier", true),
+        Language::English(_) => ("Last", false),
+    }
+}
+
+/// The most recent occurrence of a weekday before the reference day, e.g. `"Last Friday"` or
+/// `"Förra Fredag"`.
+///
+/// Resolves to the whole day, as with [`Weekday::to_chrono_min_past`], skipping the reference
+/// day. Serialises as a single string in the weekday's language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LastWeekday(Weekday);
+
+impl WithLanguage for LastWeekday {
+    fn with_language(&self, language: Language) -> Self {
+        Self(self.0.with_language(language))
+    }
+}
+
+impl LastWeekday {
+    pub const fn new(weekday: Weekday) -> Self {
+        Self(weekday)
+    }
+
+    pub const fn weekday(&self) -> Weekday {
+        self.0
+    }
+
+    /// Converts to midnight starting the most recent occurrence, relative to the given time.
+    pub fn to_chrono_min(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.0.to_chrono_min_past(relative_to, true)
+    }
+
+    /// Converts to midnight ending the most recent occurrence, relative to the given time.
+    pub fn to_chrono_max(self, relative_to: DateTime<Utc>) -> DateTime<Utc> {
+        self.0.to_chrono_max_past(relative_to, true)
+    }
+}
+
+impl FromStr for LastWeekday {
+    type Err = ParseError;
+
+    /// Parses a weekday with the word for "last" in any enabled language, e.g. `"last friday"`
+    /// or `"förra fredagen"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.to_lowercase();
+        let tokens: Vec<&str> = lowercase.split_whitespace().collect();
+
+        parse_last_weekday(&tokens, &Language::all())
+            .ok_or_else(|| ParseError::Unrecognised(s.to_string()))
+    }
+}
+
+impl fmt::Display for LastWeekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match last_word(self.0.language()) {
+            (word, false) => write!(f, "{word} {}", self.0),
+            (word, true) => write!(f, "{} {word}", self.0),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for LastWeekday {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for LastWeekday {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for LastWeekday {
+    fn schema_name() -> Cow<'static, str> {
+        "LastWeekday".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "The most recent occurrence of a weekday, e.g. \"Last Friday\".",
+            "type": "string",
+        })
+    }
 }
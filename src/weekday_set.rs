@@ -0,0 +1,288 @@
+//! Sets of weekdays, such as opening days or a recurring meeting's days.
+
+#[cfg(feature = "schemars")]
+use alloc::borrow::Cow;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt,
+    ops::{BitAnd, BitOr, Not, Sub},
+};
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{language::Language, weekday::Weekday};
+
+/// A set of weekdays, e.g. Monday, Wednesday and Friday.
+///
+/// Serialises as an array of day names in week order, e.g. `["Monday", "Wednesday", "Friday"]`,
+/// and deserialises from names in any enabled language.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekdaySet(u8);
+
+impl WeekdaySet {
+    pub const EMPTY: Self = Self(0);
+    pub const ALL: Self = Self(0b111_1111);
+    /// Monday to Friday.
+    pub const WORKDAYS: Self = Self(0b001_1111);
+    /// Saturday and Sunday.
+    pub const WEEKEND: Self = Self(0b110_0000);
+
+    fn bit(weekday: chrono::Weekday) -> u8 {
+        1 << weekday.num_days_from_monday()
+    }
+
+    pub fn contains(&self, weekday: chrono::Weekday) -> bool {
+        self.0 & Self::bit(weekday) != 0
+    }
+
+    /// Adds the weekday, returning whether it was absent.
+    pub fn insert(&mut self, weekday: chrono::Weekday) -> bool {
+        let absent = !self.contains(weekday);
+        self.0 |= Self::bit(weekday);
+        absent
+    }
+
+    /// Removes the weekday, returning whether it was present.
+    pub fn remove(&mut self, weekday: chrono::Weekday) -> bool {
+        let present = self.contains(weekday);
+        self.0 &= !Self::bit(weekday);
+        present
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Every weekday not in the set.
+    pub fn complement(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+
+    /// The weekdays in the set, Monday first.
+    pub fn iter(&self) -> impl Iterator<Item = chrono::Weekday> + use<> {
+        let set = *self;
+
+        (0..7)
+            .filter_map(|days| chrono::Weekday::try_from(days).ok())
+            .filter(move |weekday| set.contains(*weekday))
+    }
+
+    /// The weekdays in the set as named weekdays in the given language, Monday first.
+    pub fn to_weekdays(&self, language: Language) -> Vec<Weekday> {
+        self.iter()
+            .map(|weekday| Weekday::from_chrono_weekday(weekday, language))
+            .collect()
+    }
+
+    /// The weekday in the set occurring next, relative to the given time, or `None` when empty.
+    ///
+    /// When `skip_self` is true, the current day never matches.
+    pub fn next(&self, relative_to: DateTime<Utc>, skip_self: bool) -> Option<Weekday> {
+        self.to_weekdays(Language::default())
+            .into_iter()
+            .min_by_key(|weekday| weekday.to_chrono_max(relative_to, skip_self))
+    }
+
+    /// Converts to the earliest timestamp of the next occurring weekday in the set.
+    ///
+    /// See [`Weekday::to_chrono_min`]. Returns `None` when the set is empty.
+    pub fn to_chrono_min(
+        &self,
+        relative_to: DateTime<Utc>,
+        skip_self: bool,
+    ) -> Option<DateTime<Utc>> {
+        self.next(relative_to, skip_self)
+            .map(|weekday| weekday.to_chrono_min(relative_to, skip_self))
+    }
+
+    /// Converts to midnight after the next occurring weekday in the set.
+    ///
+    /// See [`Weekday::to_chrono_max`]. Returns `None` when the set is empty.
+    pub fn to_chrono_max(
+        &self,
+        relative_to: DateTime<Utc>,
+        skip_self: bool,
+    ) -> Option<DateTime<Utc>> {
+        self.next(relative_to, skip_self)
+            .map(|weekday| weekday.to_chrono_max(relative_to, skip_self))
+    }
+}
+
+impl From<chrono::Weekday> for WeekdaySet {
+    fn from(weekday: chrono::Weekday) -> Self {
+        Self(Self::bit(weekday))
+    }
+}
+
+impl From<Weekday> for WeekdaySet {
+    fn from(weekday: Weekday) -> Self {
+        weekday.to_chrono().into()
+    }
+}
+
+impl FromIterator<chrono::Weekday> for WeekdaySet {
+    fn from_iter<I: IntoIterator<Item = chrono::Weekday>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::EMPTY, |set, weekday| set | weekday.into())
+    }
+}
+
+impl BitOr for WeekdaySet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for WeekdaySet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for WeekdaySet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(rhs)
+    }
+}
+
+impl Not for WeekdaySet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+impl fmt::Display for WeekdaySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self
+            .to_weekdays(Language::default())
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        f.write_str(&names.join(", "))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for WeekdaySet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_weekdays(Language::default()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for WeekdaySet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let weekdays = Vec::<Weekday>::deserialize(deserializer)?;
+
+        Ok(weekdays.into_iter().map(Weekday::to_chrono).collect())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for WeekdaySet {
+    fn schema_name() -> Cow<'static, str> {
+        "WeekdaySet".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let weekday = generator.subschema_for::<Weekday>();
+
+        json_schema!({
+            "description": "A set of weekdays, e.g. [\"Monday\", \"Wednesday\", \"Friday\"].",
+            "type": "array",
+            "items": weekday,
+            "uniqueItems": true,
+            "maxItems": 7,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use chrono::Weekday::*;
+
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let set: WeekdaySet = [Mon, Wed, Fri].into_iter().collect();
+
+        assert!(set.contains(Wed));
+        assert!(!set.contains(Tue));
+        assert_eq!(set.len(), 3);
+        assert_eq!((set | Sat.into()).len(), 4);
+        assert_eq!(set & WeekdaySet::WEEKEND, WeekdaySet::EMPTY);
+        assert_eq!(
+            (WeekdaySet::WORKDAYS - set).iter().collect::<Vec<_>>(),
+            vec![Tue, Thu]
+        );
+        assert_eq!(!WeekdaySet::WORKDAYS, WeekdaySet::WEEKEND);
+        assert_eq!(set.to_string(), "Monday, Wednesday, Friday");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialises_day_names() {
+        let set: WeekdaySet = [Fri, Mon].into_iter().collect();
+        let json = serde_json::to_string(&set).unwrap();
+
+        assert_eq!(json, r#"["Monday","Friday"]"#);
+        assert_eq!(serde_json::from_str::<WeekdaySet>(&json).unwrap(), set);
+
+        #[cfg(feature = "swedish")]
+        assert_eq!(
+            serde_json::from_str::<WeekdaySet>(r#"["Måndag","Fredag"]"#).unwrap(),
+            set
+        );
+    }
+
+    #[test]
+    fn resolves_next_occurrence() {
+        // Tuesday July 29th, 2025
+        let tuesday = DateTime::parse_from_rfc3339("2025-07-29T10:30:05Z")
+            .unwrap()
+            .to_utc();
+        let set: WeekdaySet = [Mon, Tue, Fri].into_iter().collect();
+
+        assert_eq!(set.next(tuesday, false), Some(Weekday::tuesday()));
+        assert_eq!(set.next(tuesday, true), Some(Weekday::friday()));
+        assert_eq!(
+            set.to_chrono_max(tuesday, true).map(|max| max.to_rfc3339()),
+            Some("2025-08-02T00:00:00+00:00".to_string())
+        );
+        assert_eq!(WeekdaySet::EMPTY.next(tuesday, false), None);
+    }
+}
@@ -0,0 +1,94 @@
+//! JavaScript bindings for reltime.
+//!
+//! Exposes [`Time`] to JavaScript with parsing, conversions to `Date` and back, and the natural
+//! string form. Reference instants are `Date`s, defaulting to the current time when omitted.
+//!
+//! ```js
+//! import { Time } from "reltime-wasm";
+//!
+//! const friday = Time.parse("next friday");
+//! const start = friday.toChronoMin(new Date());
+//! const label = Time.fromMaxChrono(deadline, new Date(), "sv").toString();
+//! ```
+
+use chrono::{DateTime, Utc};
+use js_sys::Date;
+use reltime::language::{English, Language};
+use wasm_bindgen::prelude::*;
+
+/// A time value, such as `"Today"`, `"Monday"` or `"2025-07-29T10:30:05Z"`.
+#[wasm_bindgen]
+pub struct Time(reltime::Time);
+
+#[wasm_bindgen]
+impl Time {
+    /// Parses natural language in any enabled language, e.g. `"next friday"` or `"imorgon"`.
+    pub fn parse(input: &str) -> Result<Time, JsError> {
+        input
+            .parse()
+            .map(Time)
+            .map_err(|e: reltime::parse::ParseError| JsError::new(&e.to_string()))
+    }
+
+    /// Converts to the earliest possible `Date`, relative to the given one or now.
+    #[wasm_bindgen(js_name = toChronoMin)]
+    pub fn to_chrono_min(&self, relative_to: Option<Date>) -> Date {
+        to_date(self.0.clone().to_chrono_min(reference(relative_to)))
+    }
+
+    /// Converts to the latest possible `Date`, relative to the given one or now.
+    #[wasm_bindgen(js_name = toChronoMax)]
+    pub fn to_chrono_max(&self, relative_to: Option<Date>) -> Date {
+        to_date(self.0.clone().to_chrono_max(reference(relative_to)))
+    }
+
+    /// Converts a `Date` to the most natural time value, relative to the given one when present.
+    ///
+    /// The language is a code such as `"en"` or `"sv"`, English by default.
+    #[wasm_bindgen(js_name = fromMaxChrono)]
+    pub fn from_max_chrono(
+        date: Date,
+        relative_to: Option<Date>,
+        language: Option<String>,
+    ) -> Result<Time, JsError> {
+        let language = match language {
+            Some(code) => parse_language(&code)?,
+            None => Language::English(English::default()),
+        };
+
+        Ok(Time(reltime::Time::from_max_chrono(
+            from_date(&date),
+            relative_to.as_ref().map(from_date),
+            language,
+        )))
+    }
+
+    /// The natural string form, e.g. `"Tomorrow"`.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Matches a language by code or name, e.g. `"sv"`, `"swedish"` or `"svenska"`.
+fn parse_language(code: &str) -> Result<Language, JsError> {
+    let code = code.to_lowercase();
+
+    match code.as_str() {
+        "en" | "english" => Ok(Language::English(English::default())),
+        "sv" | "swedish" | "svenska" => Ok(Language::Swedish(Default::default())),
+        _ => Err(JsError::new(&format!("unknown language: {code}"))),
+    }
+}
+
+fn reference(relative_to: Option<Date>) -> DateTime<Utc> {
+    relative_to.as_ref().map_or_else(Utc::now, from_date)
+}
+
+fn from_date(date: &Date) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(date.get_time() as i64).unwrap_or_default()
+}
+
+fn to_date(date_time: DateTime<Utc>) -> Date {
+    Date::new(&JsValue::from_f64(date_time.timestamp_millis() as f64))
+}